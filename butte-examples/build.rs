@@ -1,5 +1,24 @@
 use anyhow::Result;
+use butte::Options;
 
 fn main() -> Result<()> {
-    butte::compile_fbs("fbs/greeter/greeter.fbs")
+    let object_api = Options {
+        object_api: true,
+        ..Options::default()
+    };
+    let serde = Options {
+        serde: true,
+        ..Options::default()
+    };
+    let object_api_and_serde = Options {
+        object_api: true,
+        serde: true,
+    };
+    butte::compile_fbs("fbs/greeter/greeter.fbs")?;
+    butte::compile_fbs_with_options("fbs/geometry/geometry.fbs", &[], &serde)?;
+    butte::compile_fbs_with_options("fbs/zoo/zoo.fbs", &[], &object_api_and_serde)?;
+    butte::compile_fbs_with_options("fbs/inventory/inventory.fbs", &[], &object_api_and_serde)?;
+    butte::compile_fbs_with_options("fbs/settings/settings.fbs", &[], &object_api)?;
+    butte::compile_fbs_with_options("fbs/access/access.fbs", &[], &serde)?;
+    butte::compile_fbs_with_include_dirs("fbs/library/library.fbs", &["fbs/common".into()])
 }
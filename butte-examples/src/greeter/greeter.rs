@@ -10,7 +10,7 @@ fn main() -> Result<()> {
     let mut builder = fb::FlatBufferBuilder::new();
     let raw_name = "A Name";
     let name = builder.create_string(raw_name);
-    let args = HelloRequestArgs { name };
+    let args = HelloRequestArgs { name: Some(name) };
     let req = HelloRequest::create(&mut builder, &args);
     builder.finish_minimal(req);
     let raw_bytes = builder.finished_data();
@@ -0,0 +1,99 @@
+use fb::Follow;
+use flatbuffers as fb;
+use std::mem::{align_of, size_of};
+
+butte::include_fbs!("geometry");
+
+use geometry::{Axis, Mat4, Triangle, Vec3};
+
+fn identity() -> Mat4 {
+    let mut m = [0.0; 16];
+    for i in 0..4 {
+        m[i * 5] = 1.0;
+    }
+    Mat4::new(m)
+}
+
+#[test]
+fn test_fixed_array_layout() {
+    assert_eq!(size_of::<Mat4>(), 64);
+    assert_eq!(align_of::<Mat4>(), 4);
+
+    // the axes are padded out to the alignment of the corners
+    assert_eq!(size_of::<Triangle>(), 40);
+    assert_eq!(align_of::<Triangle>(), 4);
+}
+
+#[test]
+fn test_fixed_array_of_scalars() {
+    let mat = identity();
+    let m = mat.m();
+    assert_eq!(m.len(), 16);
+    assert_eq!(m.get(0), 1.0);
+    assert_eq!(m.get(1), 0.0);
+    assert_eq!(m.iter().filter(|&x| x == 1.0).count(), 4);
+    assert_eq!(m.iter().next_back(), Some(1.0));
+}
+
+#[test]
+fn test_fixed_array_of_structs() {
+    let corners = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    ];
+    let triangle = Triangle::new(corners, [Axis::X, Axis::Z]);
+    assert_eq!(triangle.corners().safe_slice(), &corners[..]);
+    assert_eq!(triangle.corners().get(1).x(), 1.0);
+    assert_eq!(
+        triangle.axes().iter().collect::<Vec<_>>(),
+        vec![Axis::X, Axis::Z]
+    );
+}
+
+#[test]
+fn test_fixed_array_in_buffer() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let mats = builder.create_vector_direct(&[identity(), identity()]);
+    builder.finish_minimal(mats);
+
+    let mats = fb::root::<fb::Vector<Mat4>>(builder.finished_data()).unwrap();
+    assert_eq!(mats.len(), 2);
+    assert_eq!(mats.get(1).m().get(15), 1.0);
+
+    // arrays can also be read straight out of a buffer
+    let m = fb::Array::<f32, 16>::follow(builder.finished_data(), 8);
+    assert_eq!(
+        m.iter().collect::<Vec<_>>(),
+        identity().m().iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_fixed_array_elements_are_verified() {
+    let triangle = Triangle::new([Vec3::new(0.0, 0.0, 0.0); 3], [Axis::X, Axis::Y]);
+    let mut builder = fb::FlatBufferBuilder::new();
+    let triangles = builder.create_vector_direct(&[triangle]);
+    builder.finish_minimal(triangles);
+    let mut buf = builder.finished_data().to_vec();
+    assert!(fb::root::<fb::Vector<Triangle>>(&buf).is_ok());
+
+    // the second axis follows the corners and the first axis, after the root offset and length
+    let position = 8 + 36 + 1;
+    assert_eq!(buf[position], Axis::Y.0 as u8);
+    buf[position] = 9;
+    let triangles = fb::root::<fb::Vector<Triangle>>(&buf).unwrap();
+    assert_eq!(triangles.get(0).axes().get(1), Axis(9));
+
+    let opts = fb::VerifierOptions {
+        reject_unknown_enum_values: true,
+        ..fb::VerifierOptions::default()
+    };
+    assert_eq!(
+        fb::root_with_opts::<fb::Vector<Triangle>>(&opts, &buf).map(|_| ()),
+        Err(fb::InvalidFlatbuffer::UnknownEnumValue {
+            enum_name: "Axis",
+            position,
+        })
+    );
+}
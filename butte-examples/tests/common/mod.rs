@@ -0,0 +1,22 @@
+//! Helpers shared by the example tests, each of which includes the zoo schema at its root.
+
+// every test uses only some of these
+#![allow(dead_code)]
+
+use flatbuffers as fb;
+
+use crate::zoo::PenT;
+
+/// Pack `pen` as the root of a new buffer.
+pub fn pack_pen(pen: &PenT) -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = pen.pack(&mut builder);
+    builder.finish_minimal(root);
+    builder.finished_data().to_vec()
+}
+
+/// The position of the field stored at `slot` in the root table of `buf`.
+pub fn root_field_position(buf: &[u8], slot: fb::VOffsetT) -> usize {
+    let table = fb::get_root::<fb::Table>(buf);
+    table.loc + table.vtable().get(slot) as usize
+}
@@ -0,0 +1,91 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("access");
+butte::include_fbs!("inventory");
+butte::include_fbs!("zoo");
+
+mod common;
+
+use access::{root_as_entry, Entry, EntryArgs, Grant, Permission};
+use common::pack_pen;
+use inventory::{root_as_inventory, Color, InventoryT, ItemT, Point};
+use zoo::{root_as_pen, AnimalT, DogT, PenT};
+
+#[test]
+fn test_debug_union() {
+    let buf = pack_pen(&PenT {
+        animal: AnimalT::Dog(Box::new(DogT {
+            name: Some("Rex".to_string()),
+        })),
+    });
+    assert_eq!(
+        format!("{:?}", root_as_pen(&buf).unwrap()),
+        r#"Pen { animal_type: Dog, animal: Dog { name: "Rex" } }"#
+    );
+
+    let buf = pack_pen(&PenT::default());
+    assert_eq!(
+        format!("{:?}", root_as_pen(&buf).unwrap()),
+        "Pen { animal_type: NONE }"
+    );
+}
+
+#[test]
+fn test_debug_vectors() {
+    let inventory = InventoryT {
+        names: Some(vec!["apple".to_string(), "pear".to_string()]),
+        colors: Some(vec![Color::Blue, Color(7)]),
+        points: Some(vec![Point::new(1, 2)]),
+        items: Some(vec![
+            ItemT {
+                name: Some("hammer".to_string()),
+            },
+            ItemT { name: None },
+        ]),
+        ..InventoryT::default()
+    };
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = inventory.pack(&mut builder);
+    builder.finish_minimal(root);
+    let reader = root_as_inventory(builder.finished_data()).unwrap();
+    assert_eq!(
+        format!("{:?}", reader),
+        "Inventory { \
+         names: [\"apple\", \"pear\"], \
+         colors: [Blue, Color(7)], \
+         points: [Point { x: 1, y: 2 }], \
+         items: [Item { name: \"hammer\" }, Item] \
+         }"
+    );
+}
+
+#[test]
+fn test_debug_defaults_and_flags() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let masks = builder.create_vector(&[Permission::empty(), Permission::from_bits_retain(0x8010)]);
+    let args = EntryArgs {
+        mode: Permission::Read | Permission::Write,
+        owner: Some(Grant::new(7, Permission::Execute)),
+        masks: Some(masks),
+        ..EntryArgs::default()
+    };
+    let entry = Entry::create(&mut builder, &args);
+    builder.finish_minimal(entry);
+    assert_eq!(
+        format!("{:?}", root_as_entry(builder.finished_data()).unwrap()),
+        "Entry { \
+         mode: Permission(Read | Write), \
+         owner: Grant { user: 7, permission: Permission(Execute) }, \
+         masks: [Permission(0x0), Permission(Admin | 0x10)] \
+         }"
+    );
+
+    // a field equal to its default isn't stored, but is still shown
+    let mut builder = fb::FlatBufferBuilder::new();
+    let entry = Entry::create(&mut builder, &EntryArgs::default());
+    builder.finish_minimal(entry);
+    assert_eq!(
+        format!("{:?}", root_as_entry(builder.finished_data()).unwrap()),
+        "Entry { mode: Permission(Read) }"
+    );
+}
@@ -0,0 +1,100 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("settings");
+
+use settings::{finish_window_buffer, root_as_window, Level, Size, Window, WindowArgs};
+
+fn window(args: impl FnOnce(fb::WIPOffset<&str>) -> WindowArgs) -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let title = builder.create_string("main");
+    let window = Window::create(&mut builder, &args(title));
+    finish_window_buffer(&mut builder, window);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn test_default_args() {
+    let args = WindowArgs::default();
+    assert_eq!(args.opacity, 0.75);
+    assert!(args.visible);
+    assert_eq!(args.level, Level::High);
+    assert_eq!(args.priority, Level::Medium);
+    assert_eq!(args.monitor, None);
+    assert!(args.scale.is_infinite());
+    assert!(args.title.is_none());
+    assert!(args.tags.is_none());
+    assert_eq!(args.fallback, Level::Low);
+}
+
+#[test]
+fn test_defaults_are_read_back() {
+    let buf = window(|title| WindowArgs {
+        title: Some(title),
+        size: Some(Size::new(640, 480)),
+        ..WindowArgs::default()
+    });
+    let window = root_as_window(&buf).unwrap();
+    assert_eq!(window.title(), "main");
+    assert_eq!(window.size(), Size::new(640, 480));
+    assert_eq!(window.opacity(), 0.75);
+    assert!(window.visible());
+    assert_eq!(window.level(), Level::High);
+    assert_eq!(window.priority(), Level::Medium);
+    assert_eq!(window.monitor(), None);
+    assert!(window.scale().is_infinite());
+    assert!(window.tags().is_none());
+    assert_eq!(window.fallback(), Level::Low);
+
+    // fields equal to their default aren't stored
+    let table = fb::get_root::<fb::Table>(&buf);
+    assert_eq!(table.vtable().get(Window::VT_OPACITY), 0);
+    assert_eq!(table.vtable().get(Window::VT_LEVEL), 0);
+    assert_eq!(table.vtable().get(Window::VT_FALLBACK), 0);
+}
+
+#[test]
+fn test_values_are_read_back() {
+    let buf = window(|title| WindowArgs {
+        title: Some(title),
+        size: Some(Size::new(1, 2)),
+        opacity: 0.5,
+        visible: false,
+        level: Level::Low,
+        priority: Level::High,
+        monitor: Some(0),
+        scale: 2.0,
+        tags: None,
+        fallback: Level::Medium,
+    });
+    let window = root_as_window(&buf).unwrap();
+    assert_eq!(window.opacity(), 0.5);
+    assert!(!window.visible());
+    assert_eq!(window.level(), Level::Low);
+    assert_eq!(window.priority(), Level::High);
+    assert_eq!(window.monitor(), Some(0));
+    assert_eq!(window.scale(), 2.0);
+    assert_eq!(window.fallback(), Level::Medium);
+}
+
+#[test]
+#[should_panic(expected = "title")]
+fn test_missing_required_field() {
+    window(|_| WindowArgs {
+        size: Some(Size::new(1, 2)),
+        ..WindowArgs::default()
+    });
+}
+
+#[test]
+fn test_missing_required_field_is_rejected() {
+    // build the table by hand, leaving out the required title
+    let mut builder = fb::FlatBufferBuilder::new();
+    let start = builder.start_table();
+    builder.push_slot_always(Window::VT_SIZE, Size::new(1, 2));
+    let window = builder.end_table(start);
+    builder.finish_minimal(window);
+    assert_eq!(
+        root_as_window(builder.finished_data()).map(|_| ()),
+        Err(fb::InvalidFlatbuffer::MissingRequiredField { required: "title" })
+    );
+}
@@ -0,0 +1,42 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("settings");
+
+use settings::{Level, Theme, ThemeArgs};
+
+#[test]
+fn test_deprecated_field_keeps_its_slot() {
+    assert_eq!(Theme::VT_NAME, fb::field_index_to_field_offset(0));
+    assert_eq!(Theme::VT_ACCENT, fb::field_index_to_field_offset(2));
+}
+
+#[test]
+fn test_deprecated_field_is_skipped() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let name = builder.create_string("night");
+    let args = ThemeArgs {
+        name: Some(name),
+        accent: Level::High,
+    };
+    let theme = Theme::create(&mut builder, &args);
+    builder.finish_minimal(theme);
+
+    let theme = fb::root::<Theme>(builder.finished_data()).unwrap();
+    assert_eq!(theme.name(), Some("night"));
+    assert_eq!(theme.accent(), Level::High);
+}
+
+#[test]
+fn test_old_buffers_can_still_be_read() {
+    // write the deprecated field, like an older version of the schema would have
+    let mut builder = fb::FlatBufferBuilder::new();
+    let start = builder.start_table();
+    builder.push_slot_always(fb::field_index_to_field_offset(1), true);
+    builder.push_slot_always(Theme::VT_ACCENT, Level::Low);
+    let theme = builder.end_table(start);
+    builder.finish_minimal(theme);
+
+    let theme = fb::root::<Theme>(builder.finished_data()).unwrap();
+    assert_eq!(theme.name(), None);
+    assert_eq!(theme.accent(), Level::Low);
+}
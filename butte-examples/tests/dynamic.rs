@@ -0,0 +1,355 @@
+use butte::{parser::parse_schema, reflection::binary_schema};
+use fb::{DynSchema, DynSchemaError, DynValue};
+use flatbuffers as fb;
+
+butte::include_fbs!("access");
+butte::include_fbs!("geometry");
+butte::include_fbs!("inventory");
+butte::include_fbs!("settings");
+butte::include_fbs!("zoo");
+
+mod common;
+
+use access::{Entry, EntryArgs, Grant, Permission};
+use common::{pack_pen, root_field_position};
+use geometry::{Axis, Edges, Triangle, Vec3};
+use inventory::{Color, Inventory, InventoryArgs, InventoryT, ItemT, Point};
+use settings::{Size, Window, WindowArgs};
+use zoo::{AnimalT, DogT, Pen, PenT};
+
+const ACCESS: &str = include_str!("../fbs/access/access.fbs");
+const GEOMETRY: &str = include_str!("../fbs/geometry/geometry.fbs");
+const INVENTORY: &str = include_str!("../fbs/inventory/inventory.fbs");
+const SETTINGS: &str = include_str!("../fbs/settings/settings.fbs");
+const ZOO: &str = include_str!("../fbs/zoo/zoo.fbs");
+const REFLECTION: &str = include_str!("../../butte-flatbuffers/fbs/reflection.fbs");
+
+fn to_bfbs(schema: &str) -> Vec<u8> {
+    binary_schema(&[parse_schema(schema).unwrap()]).unwrap()
+}
+
+#[test]
+fn test_dynamic_union() {
+    let bfbs = to_bfbs(ZOO);
+    let schema = DynSchema::from_bfbs(&bfbs, "zoo.Pen").unwrap();
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = PenT {
+        animal: AnimalT::Dog(Box::new(DogT {
+            name: Some("Rex".to_string()),
+        })),
+    }
+    .pack(&mut builder);
+    builder.finish_minimal(pen);
+    let pen = schema.root(builder.finished_data()).unwrap();
+
+    assert_eq!(pen.get("animal_type"), Some(DynValue::UByte(2)));
+    let animal = pen.get("animal").unwrap().as_union().unwrap();
+    assert_eq!(animal.variant_name(), "Dog");
+    assert_eq!(animal.table().object().name(), "zoo.Dog");
+    assert_eq!(
+        animal.table().get("name").and_then(|name| name.as_str()),
+        Some("Rex")
+    );
+    assert_eq!(
+        format!("{:?}", pen),
+        r#"Pen { animal_type: 2, animal: Dog { name: "Rex" } }"#
+    );
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = PenT::default().pack(&mut builder);
+    builder.finish_minimal(pen);
+    let pen = schema.root(builder.finished_data()).unwrap();
+    assert_eq!(pen.get("animal"), None);
+    assert_eq!(pen.get("animal_type"), Some(DynValue::UByte(0)));
+}
+
+#[test]
+fn test_dynamic_vectors() {
+    let bfbs = to_bfbs(INVENTORY);
+    let schema = DynSchema::from_bfbs(&bfbs, "inventory.Inventory").unwrap();
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let inventory = InventoryT {
+        counts: Some(vec![3, -1]),
+        names: Some(vec!["apple".to_string(), "pear".to_string()]),
+        colors: Some(vec![Color::Blue]),
+        points: Some(vec![Point::new(1, 2), Point::new(3, 4)]),
+        items: Some(vec![ItemT {
+            name: Some("hammer".to_string()),
+        }]),
+        ..InventoryT::default()
+    }
+    .pack(&mut builder);
+    builder.finish_minimal(inventory);
+    let inventory = schema.root(builder.finished_data()).unwrap();
+
+    let counts = inventory.get("counts").unwrap().as_vector().unwrap();
+    assert_eq!(counts.element_type(), fb::reflection::BaseType::Int);
+    assert_eq!(
+        counts.iter().collect::<Vec<_>>(),
+        [DynValue::Int(3), DynValue::Int(-1)]
+    );
+    let points = inventory.get("points").unwrap().as_vector().unwrap();
+    assert_eq!(points.len(), 2);
+    let point = points.get(1).unwrap().as_struct().unwrap();
+    assert_eq!(point.get("y"), Some(DynValue::Int(4)));
+    assert_eq!(points.get(2), None);
+    let items = inventory.get("items").unwrap().as_vector().unwrap();
+    let item = items.get(0).unwrap().as_table().unwrap();
+    assert_eq!(item.get("name").unwrap().as_str(), Some("hammer"));
+    assert_eq!(inventory.get("bytes"), None);
+
+    assert_eq!(
+        format!("{:?}", inventory),
+        "Inventory { \
+         counts: [3, -1], \
+         names: [\"apple\", \"pear\"], \
+         colors: [2], \
+         points: [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }], \
+         items: [Item { name: \"hammer\" }] \
+         }"
+    );
+}
+
+#[test]
+fn test_dynamic_structs_and_defaults() {
+    let bfbs = to_bfbs(ACCESS);
+    let schema = DynSchema::from_bfbs(&bfbs, "access.Entry").unwrap();
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let args = EntryArgs {
+        owner: Some(Grant::new(7, Permission::Write)),
+        ..EntryArgs::default()
+    };
+    let entry = Entry::create(&mut builder, &args);
+    builder.finish_minimal(entry);
+    let entry = schema.root(builder.finished_data()).unwrap();
+
+    // a field equal to its default isn't stored, but is still read
+    assert_eq!(entry.get("mode"), Some(DynValue::UShort(1)));
+    assert_eq!(entry.get("path"), None);
+    assert_eq!(entry.get("nothing"), None);
+    let owner = entry.get("owner").unwrap().as_struct().unwrap();
+    assert_eq!(owner.object().bytesize(), 8);
+    assert_eq!(owner.get("user").and_then(|user| user.as_i64()), Some(7));
+    assert_eq!(owner.get("permission"), Some(DynValue::UShort(2)));
+}
+
+#[test]
+fn test_dynamic_arrays() {
+    let bfbs = to_bfbs(&format!(
+        "{}\ntable Shape {{ triangle: Triangle; }}",
+        GEOMETRY
+    ));
+    let schema = DynSchema::from_bfbs(&bfbs, "geometry.Shape").unwrap();
+
+    let triangle = Triangle::new(
+        [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ],
+        [Axis::X, Axis::Z],
+    );
+    let mut builder = fb::FlatBufferBuilder::new();
+    let start = builder.start_table();
+    builder.push_slot_always(fb::field_index_to_field_offset(0), triangle);
+    let shape = builder.end_table(start);
+    builder.finish_minimal(shape);
+    let shape = schema.root(builder.finished_data()).unwrap();
+
+    let triangle = shape.get("triangle").unwrap().as_struct().unwrap();
+    let corners = triangle.get("corners").unwrap().as_vector().unwrap();
+    assert_eq!(corners.len(), 3);
+    let corner = corners.get(1).unwrap().as_struct().unwrap();
+    assert_eq!(corner.get("x"), Some(DynValue::Float(1.0)));
+    assert_eq!(format!("{:?}", triangle.get("axes").unwrap()), "[0, 2]");
+}
+
+#[test]
+fn test_dynamic_schema_errors() {
+    let bfbs = to_bfbs(ZOO);
+    assert_eq!(
+        DynSchema::from_bfbs(&bfbs, "zoo.Zebra").unwrap_err(),
+        DynSchemaError::UnknownTable("zoo.Zebra".to_string())
+    );
+    assert!(matches!(
+        DynSchema::from_bfbs(&bfbs[..bfbs.len() / 2], "zoo.Pen"),
+        Err(DynSchemaError::InvalidSchema(_))
+    ));
+
+    let bfbs = to_bfbs(ACCESS);
+    assert!(DynSchema::from_bfbs(&bfbs, "access.Grant").is_err());
+}
+
+#[test]
+fn test_dynamic_verifies_buffers() {
+    let bfbs = to_bfbs(ZOO);
+    let schema = DynSchema::from_bfbs(&bfbs, "zoo.Pen").unwrap();
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = PenT {
+        animal: AnimalT::Dog(Box::new(DogT {
+            name: Some("Rex".to_string()),
+        })),
+    }
+    .pack(&mut builder);
+    builder.finish_minimal(pen);
+    let buf = builder.finished_data();
+    for len in 0..buf.len() - 2 {
+        assert!(schema.root(&buf[..len]).is_err(), "length {}", len);
+    }
+}
+
+#[test]
+fn test_dynamic_verifies_bools() {
+    let bfbs = to_bfbs(SETTINGS);
+    let schema = DynSchema::from_bfbs(&bfbs, "settings.Window").unwrap();
+    let mut builder = fb::FlatBufferBuilder::new();
+    let title = builder.create_string("main");
+    let args = WindowArgs {
+        title: Some(title),
+        size: Some(Size::new(640, 480)),
+        visible: false,
+        ..WindowArgs::default()
+    };
+    let window = Window::create(&mut builder, &args);
+    builder.finish_minimal(window);
+    let mut buf = builder.finished_data().to_vec();
+    let pos = root_field_position(&buf, Window::VT_VISIBLE);
+    buf[pos] = 2;
+    assert_eq!(
+        schema.root(&buf).map(|_| ()),
+        Err(fb::InvalidFlatbuffer::InvalidBool { position: pos })
+    );
+    // an unverified buffer reads any other byte as true
+    assert_eq!(
+        schema.get_root(&buf).get("visible"),
+        Some(DynValue::Bool(true))
+    );
+
+    let bfbs = to_bfbs(INVENTORY);
+    let schema = DynSchema::from_bfbs(&bfbs, "inventory.Inventory").unwrap();
+    let mut builder = fb::FlatBufferBuilder::new();
+    let flags = builder.create_vector(&[true, false, true]);
+    let args = InventoryArgs {
+        flags: Some(flags),
+        ..InventoryArgs::default()
+    };
+    let inventory = Inventory::create(&mut builder, &args);
+    builder.finish_minimal(inventory);
+    let mut buf = builder.finished_data().to_vec();
+    assert!(schema.root(&buf).is_ok());
+    let field = root_field_position(&buf, Inventory::VT_FLAGS);
+    let pos = field + fb::read_scalar_at::<fb::UOffsetT>(&buf, field) as usize + fb::SIZE_UOFFSET;
+    buf[pos + 1] = 255;
+    assert_eq!(
+        schema.root(&buf).map(|_| ()),
+        Err(fb::InvalidFlatbuffer::InvalidBool { position: pos + 1 })
+    );
+}
+
+#[test]
+fn test_dynamic_verifies_bools_in_structs() {
+    let bfbs = to_bfbs(&format!(
+        "{}\ntable Shape {{ edges: Edges; sides: [Edges]; }}",
+        GEOMETRY
+    ));
+    let schema = DynSchema::from_bfbs(&bfbs, "geometry.Shape").unwrap();
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let edges = Edges::new(true, [false, true, false]);
+    let sides = builder.create_vector(&[edges, edges]);
+    let start = builder.start_table();
+    builder.push_slot_always(fb::field_index_to_field_offset(0), edges);
+    builder.push_slot_always(fb::field_index_to_field_offset(1), sides);
+    let shape = builder.end_table(start);
+    builder.finish_minimal(shape);
+    let buf = builder.finished_data().to_vec();
+    let shape = schema.root(&buf).unwrap();
+    let drawn = shape
+        .get("edges")
+        .unwrap()
+        .as_struct()
+        .unwrap()
+        .get("drawn");
+    assert_eq!(format!("{:?}", drawn.unwrap()), "[false, true, false]");
+
+    // the bool of the struct, one in its array, and one in an array of a struct in a vector
+    let edges = root_field_position(&buf, fb::field_index_to_field_offset(0));
+    let field = root_field_position(&buf, fb::field_index_to_field_offset(1));
+    let sides = field + fb::read_scalar_at::<fb::UOffsetT>(&buf, field) as usize + fb::SIZE_UOFFSET;
+    for &pos in &[edges, edges + 2, sides + 4 + 3] {
+        let mut buf = buf.clone();
+        buf[pos] = 2;
+        assert_eq!(
+            schema.root(&buf).map(|_| ()),
+            Err(fb::InvalidFlatbuffer::InvalidBool { position: pos })
+        );
+    }
+}
+
+#[test]
+fn test_dynamic_binary_schema() {
+    // the reflection schema describes binary schemas, including its own
+    let bfbs = to_bfbs(REFLECTION);
+    let schema = DynSchema::from_bfbs(&bfbs, "reflection.Schema").unwrap();
+    let zoo = to_bfbs(ZOO);
+    let reflected = schema.root(&zoo).unwrap();
+
+    let typed = fb::reflection::root_as_schema(&zoo).unwrap();
+    let objects = reflected.get("objects").unwrap().as_vector().unwrap();
+    assert_eq!(objects.len(), typed.objects().len());
+    let names = objects
+        .iter()
+        .map(|object| object.as_table().unwrap().get("name").unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        typed
+            .objects()
+            .iter()
+            .map(|object| DynValue::String(object.name()))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        reflected.get("file_ident").and_then(|ident| ident.as_str()),
+        Some("ZOOP")
+    );
+}
+
+#[test]
+fn test_dynamic_union_with_the_first_id() {
+    // a binary schema can give a union the first id, which leaves no slot before it for its type
+    let mut schema = fb::reflection::root_as_schema(&to_bfbs(ZOO))
+        .unwrap()
+        .unpack();
+    let pen = schema
+        .objects
+        .iter_mut()
+        .find(|object| object.name == "zoo.Pen")
+        .unwrap();
+    for field in &mut pen.fields {
+        if field.name == "animal" {
+            field.id = 0;
+        }
+    }
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = schema.pack(&mut builder);
+    fb::reflection::finish_schema_buffer(&mut builder, root);
+    let bfbs = builder.finished_data().to_vec();
+    let schema = DynSchema::from_bfbs(&bfbs, "zoo.Pen").unwrap();
+
+    let buf = pack_pen(&PenT {
+        animal: AnimalT::Dog(Box::new(DogT {
+            name: Some("Rex".to_string()),
+        })),
+    });
+    let pos = root_field_position(&buf, Pen::VT_ANIMAL);
+    assert_eq!(
+        schema.root(&buf).map(|_| ()),
+        Err(fb::InvalidFlatbuffer::UnionWithoutTypeSlot { position: pos })
+    );
+    assert_eq!(schema.get_root(&buf).get("animal"), None);
+}
@@ -0,0 +1,51 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("settings");
+butte::include_fbs!("zoo");
+
+use settings::{enum_name_level, Level};
+use zoo::AnimalType;
+
+#[test]
+fn test_enum_constants() {
+    assert_eq!(Level::ENUM_MIN, -1);
+    assert_eq!(Level::ENUM_MAX, 1);
+    assert_eq!(
+        Level::ENUM_VALUES,
+        &[Level::Low, Level::Medium, Level::High]
+    );
+
+    assert_eq!(AnimalType::ENUM_MIN, 0);
+    assert_eq!(AnimalType::ENUM_VALUES[0], AnimalType::NONE);
+}
+
+#[test]
+fn test_variant_names() {
+    assert_eq!(Level::Medium.variant_name(), Some("Medium"));
+    assert_eq!(Level(5).variant_name(), None);
+    assert_eq!(enum_name_level(Level::High), "High");
+    assert_eq!(enum_name_level(Level(5)), "");
+
+    assert_eq!(Level::Low.to_string(), "Low");
+    assert_eq!(Level(5).to_string(), "5");
+}
+
+#[test]
+fn test_parse_variant_names() {
+    assert_eq!("High".parse::<Level>(), Ok(Level::High));
+    assert_eq!("Cat".parse::<AnimalType>(), Ok(AnimalType::Cat));
+
+    let error = "high".parse::<Level>().unwrap_err();
+    assert_eq!(
+        error,
+        fb::UnknownVariant {
+            enum_name: "Level",
+            name: "high".to_string(),
+        }
+    );
+    assert_eq!(error.to_string(), "`high` is not a variant of `Level`");
+
+    for &level in Level::ENUM_VALUES {
+        assert_eq!(level.to_string().parse::<Level>(), Ok(level));
+    }
+}
@@ -0,0 +1,89 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("access");
+
+use access::{finish_entry_buffer, root_as_entry, Entry, EntryArgs, Grant, Permission};
+
+fn entry(args: EntryArgs) -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let entry = Entry::create(&mut builder, &args);
+    finish_entry_buffer(&mut builder, entry);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn test_flag_values() {
+    assert_eq!(Permission::Read.bits(), 1);
+    assert_eq!(Permission::Write.bits(), 2);
+    assert_eq!(Permission::Execute.bits(), 4);
+    assert_eq!(Permission::Admin.bits(), 1 << 15);
+    assert_eq!(Permission::all().bits(), 0x8007);
+    assert_eq!(Permission::default(), Permission::empty());
+}
+
+#[test]
+fn test_flag_operations() {
+    let mut mode = Permission::Read | Permission::Write;
+    assert!(mode.contains(Permission::Read));
+    assert!(mode.contains(Permission::Read | Permission::Write));
+    assert!(!mode.contains(Permission::Read | Permission::Execute));
+    assert!(mode.intersects(Permission::Read | Permission::Execute));
+    assert_eq!(mode & Permission::Write, Permission::Write);
+    assert_eq!(!mode, Permission::Execute | Permission::Admin);
+
+    mode.remove(Permission::Read);
+    assert_eq!(mode, Permission::Write);
+    mode |= Permission::Execute;
+    mode &= Permission::Execute;
+    assert_eq!(mode, Permission::Execute);
+    mode.insert(Permission::Admin);
+    assert_eq!(mode.bits(), 0x8004);
+
+    assert_eq!(
+        Permission::from_bits(3),
+        Some(Permission::Read | Permission::Write)
+    );
+    assert_eq!(Permission::from_bits(8), None);
+    assert_eq!(Permission::from_bits_truncate(9), Permission::Read);
+    assert_eq!(Permission::from_bits_retain(9).bits(), 9);
+}
+
+#[test]
+fn test_flags_are_read_back() {
+    let buf = entry(EntryArgs::default());
+    let entry = root_as_entry(&buf).unwrap();
+    assert_eq!(entry.mode(), Permission::Read);
+
+    let mode = Permission::Read | Permission::Execute | Permission::Admin;
+    let mut builder = fb::FlatBufferBuilder::new();
+    let masks = builder.create_vector(&[Permission::Write, Permission::all()]);
+    let args = EntryArgs {
+        mode,
+        owner: Some(Grant::new(7, Permission::Admin)),
+        masks: Some(masks),
+        ..EntryArgs::default()
+    };
+    let entry = Entry::create(&mut builder, &args);
+    finish_entry_buffer(&mut builder, entry);
+    let entry = root_as_entry(builder.finished_data()).unwrap();
+    assert_eq!(entry.mode(), mode);
+    assert_eq!(
+        entry.owner().map(|owner| owner.permission()),
+        Some(Permission::Admin)
+    );
+    assert_eq!(
+        entry.masks().unwrap().iter().collect::<Vec<_>>(),
+        vec![Permission::Write, Permission::all()]
+    );
+}
+
+#[test]
+fn test_unknown_bits_are_kept() {
+    let buf = entry(EntryArgs {
+        mode: Permission::from_bits_retain(0x0108),
+        ..EntryArgs::default()
+    });
+    let entry = root_as_entry(&buf).unwrap();
+    assert_eq!(entry.mode().bits(), 0x0108);
+    assert_eq!(Permission::from_bits(entry.mode().bits()), None);
+}
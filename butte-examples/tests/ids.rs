@@ -0,0 +1,41 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("zoo");
+
+use zoo::{AnimalType, Cat, CatArgs, Keeper, KeeperArgs};
+
+#[test]
+fn test_ids_give_vtable_slots() {
+    assert_eq!(Keeper::VT_AGE, fb::field_index_to_field_offset(0));
+    assert_eq!(Keeper::VT_PET_TYPE, fb::field_index_to_field_offset(1));
+    assert_eq!(Keeper::VT_PET, fb::field_index_to_field_offset(2));
+    assert_eq!(Keeper::VT_NAME, fb::field_index_to_field_offset(3));
+}
+
+#[test]
+fn test_fields_with_ids() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let name = builder.create_string("Joe");
+    let cat = Cat::create(&mut builder, &CatArgs { lives: 7 });
+    let args = KeeperArgs {
+        name: Some(name),
+        pet_type: AnimalType::Cat,
+        pet: Some(cat.as_union_value()),
+        age: 42,
+    };
+    let keeper = Keeper::create(&mut builder, &args);
+    builder.finish_minimal(keeper);
+
+    let keeper = fb::root::<Keeper>(builder.finished_data()).unwrap();
+    assert_eq!(keeper.name(), Some("Joe"));
+    assert_eq!(keeper.age(), 42);
+    assert_eq!(keeper.pet_as_cat().map(|cat| cat.lives()), Some(7));
+
+    // the age comes first in the vtable, even though it's declared last
+    let table = fb::get_root::<fb::Table>(builder.finished_data());
+    assert_eq!(table.vtable().num_fields(), 4);
+    assert_eq!(
+        fb::read_scalar_at::<i32>(table.buf, table.loc + table.vtable().get(4) as usize),
+        42
+    );
+}
@@ -0,0 +1,56 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("library");
+
+use library::{stats::Count, stats::CountArgs, Author, AuthorArgs, Book, BookArgs, Genre};
+
+#[test]
+fn test_types_from_included_files() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let title = builder.create_string("Leaves of Grass");
+    let book = Book::create(
+        &mut builder,
+        &BookArgs {
+            title: Some(title),
+            genre: Genre::Poetry,
+        },
+    );
+    builder.finish_minimal(book);
+
+    let root = fb::get_root::<Book>(builder.finished_data());
+    assert_eq!(root.title(), Some("Leaves of Grass"));
+    assert_eq!(root.genre(), Genre::Poetry);
+}
+
+#[test]
+fn test_diamond_include_is_compiled_once() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let name = builder.create_string("Walt Whitman");
+    let author = Author::create(
+        &mut builder,
+        &AuthorArgs {
+            name: Some(name),
+            genre: Genre::Poetry,
+        },
+    );
+    builder.finish_minimal(author);
+
+    let root = fb::get_root::<Author>(builder.finished_data());
+    assert_eq!(root.name(), Some("Walt Whitman"));
+    assert_eq!(root.genre(), Genre::Poetry);
+}
+
+#[test]
+fn test_nested_namespace() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let args = CountArgs {
+        books: 3,
+        genre: Genre::History,
+    };
+    let count = Count::create(&mut builder, &args);
+    builder.finish_minimal(count);
+
+    let root = fb::get_root::<Count>(builder.finished_data());
+    assert_eq!(root.books(), 3);
+    assert_eq!(root.genre(), Genre::History);
+}
@@ -0,0 +1,87 @@
+use butte::{json::Converter, parser::parse_schema};
+use flatbuffers as fb;
+
+butte::include_fbs!("access");
+butte::include_fbs!("zoo");
+
+use access::{root_as_entry, Entry, EntryArgs, Grant, Permission};
+use zoo::{pen_buffer_has_identifier, root_as_pen, AnimalT, AnimalType, CatT, PenT};
+
+const ACCESS: &str = include_str!("../fbs/access/access.fbs");
+const ZOO: &str = include_str!("../fbs/zoo/zoo.fbs");
+
+#[test]
+fn test_to_json() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let path = builder.create_string("/etc/hosts");
+    let masks = builder.create_vector(&[Permission::Write | Permission::Admin]);
+    let args = EntryArgs {
+        path: Some(path),
+        mode: Permission::Read | Permission::Execute,
+        owner: Some(Grant::new(7, Permission::empty())),
+        masks: Some(masks),
+    };
+    let entry = Entry::create(&mut builder, &args);
+    builder.finish_minimal(entry);
+
+    let schemas = [parse_schema(ACCESS).unwrap()];
+    let converter = Converter::new(&schemas).unwrap();
+    assert_eq!(
+        converter.to_json(builder.finished_data()).unwrap(),
+        r#"{
+  "path": "/etc/hosts",
+  "mode": "Read Execute",
+  "owner": {
+    "user": 7,
+    "permission": 0
+  },
+  "masks": [
+    "Write Admin"
+  ]
+}
+"#
+    );
+}
+
+#[test]
+fn test_from_json() {
+    let schemas = [parse_schema(ACCESS).unwrap()];
+    let converter = Converter::new(&schemas).unwrap();
+    let buf = converter
+        .from_json(r#"{ "mode": "Write", "owner": { "user": 1, "permission": "Admin" } }"#)
+        .unwrap();
+    let entry = root_as_entry(&buf).unwrap();
+    assert_eq!(entry.path(), None);
+    assert_eq!(entry.mode(), Permission::Write);
+    assert_eq!(entry.owner(), Some(Grant::new(1, Permission::Admin)));
+    assert!(entry.masks().is_none());
+}
+
+#[test]
+fn test_unions() {
+    let schemas = [parse_schema(ZOO).unwrap()];
+    let converter = Converter::new(&schemas).unwrap();
+    let buf = converter
+        .from_json(r#"{ animal_type: "Cat", animal: { lives: 9 } }"#)
+        .unwrap();
+    assert!(pen_buffer_has_identifier(&buf));
+    let pen = root_as_pen(&buf).unwrap();
+    assert_eq!(pen.animal_type(), AnimalType::Cat);
+    assert_eq!(pen.animal_as_cat().map(|cat| cat.lives()), Some(9));
+
+    let pen = PenT {
+        animal: AnimalT::Cat(Box::new(CatT { lives: 3 })),
+    };
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = pen.pack(&mut builder);
+    builder.finish_minimal(root);
+    assert_eq!(
+        converter.to_json(builder.finished_data()).unwrap(),
+        "{\n  \"animal_type\": \"Cat\",\n  \"animal\": {\n    \"lives\": 3\n  }\n}\n"
+    );
+
+    let keeper = Converter::with_root(&schemas, "zoo.Keeper").unwrap();
+    let json = "{\n  \"age\": 40,\n  \"pet_type\": \"Dog\",\n  \"pet\": {\n    \"name\": \"Rex\"\n  },\n  \"name\": \"Sam\"\n}\n";
+    let buf = keeper.from_json(json).unwrap();
+    assert_eq!(keeper.to_json(&buf).unwrap(), json);
+}
@@ -0,0 +1,66 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("inventory");
+
+use inventory::{
+    finish_inventory_buffer, root_as_inventory, Inventory, InventoryArgs, Item, ItemArgs, Stock,
+    StockArgs,
+};
+
+#[test]
+fn test_lookup_by_string_key() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let mut items = ["saw", "hammer", "wrench", "drill"]
+        .iter()
+        .map(|name| {
+            let name = builder.create_string(name);
+            Item::create(&mut builder, &ItemArgs { name: Some(name) })
+        })
+        .collect::<Vec<_>>();
+    let items = builder.create_vector_of_sorted_tables(&mut items);
+    let args = InventoryArgs {
+        items: Some(items),
+        ..InventoryArgs::default()
+    };
+    let inventory = Inventory::create(&mut builder, &args);
+    finish_inventory_buffer(&mut builder, inventory);
+
+    let items = root_as_inventory(builder.finished_data())
+        .unwrap()
+        .items()
+        .unwrap();
+    assert_eq!(
+        items.iter().map(|item| item.name()).collect::<Vec<_>>(),
+        vec![Some("drill"), Some("hammer"), Some("saw"), Some("wrench")]
+    );
+    for name in &["drill", "hammer", "saw", "wrench"] {
+        let item = items.lookup_by_key(name, |item, key| item.key_compare_with_value(key));
+        assert_eq!(item.and_then(|item| item.name()), Some(*name));
+    }
+    assert!(items
+        .lookup_by_key("axe", |item, key| item.key_compare_with_value(key))
+        .is_none());
+    assert!(items.get(0).key_compare_less_than(&items.get(1)));
+}
+
+#[test]
+fn test_lookup_by_scalar_key() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let mut stock = [(30, 1), (10, 2), (20, 3)]
+        .iter()
+        .map(|&(sku, count)| Stock::create(&mut builder, &StockArgs { sku, count }))
+        .collect::<Vec<_>>();
+    let stock = builder.create_vector_of_sorted_tables(&mut stock);
+    builder.finish_minimal(stock);
+
+    let stock = fb::get_root::<fb::Vector<fb::ForwardsUOffset<Stock>>>(builder.finished_data());
+    assert_eq!(
+        stock.iter().map(|stock| stock.sku()).collect::<Vec<_>>(),
+        vec![10, 20, 30]
+    );
+    let found = stock.lookup_by_key(20, |stock, &key| stock.key_compare_with_value(key));
+    assert_eq!(found.map(|stock| stock.count()), Some(3));
+    assert!(stock
+        .lookup_by_key(25, |stock, &key| stock.key_compare_with_value(key))
+        .is_none());
+}
@@ -0,0 +1,109 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("inventory");
+butte::include_fbs!("settings");
+butte::include_fbs!("zoo");
+
+mod common;
+
+use common::pack_pen;
+use inventory::{root_as_inventory, Color, InventoryT, ItemT, Point};
+use settings::{root_as_window, Level, Size, WindowT};
+use zoo::{root_as_pen, AnimalT, AnimalType, CatT, DogT, PenT};
+
+#[test]
+fn test_union_round_trip() {
+    let pen = PenT {
+        animal: AnimalT::Dog(Box::new(DogT {
+            name: Some("Rex".to_string()),
+        })),
+    };
+    let buf = pack_pen(&pen);
+    let reader = root_as_pen(&buf).unwrap();
+    assert_eq!(reader.animal_type(), AnimalType::Dog);
+    assert_eq!(
+        reader.animal_as_dog().and_then(|dog| dog.name()),
+        Some("Rex")
+    );
+    assert_eq!(reader.unpack(), pen);
+
+    let mut pen = reader.unpack();
+    pen.animal = AnimalT::Cat(Box::new(CatT { lives: 9 }));
+    assert_eq!(pen.animal.animal_type(), AnimalType::Cat);
+    let buf = pack_pen(&pen);
+    let reader = root_as_pen(&buf).unwrap();
+    assert_eq!(reader.animal_as_cat().map(|cat| cat.lives()), Some(9));
+}
+
+#[test]
+fn test_empty_union() {
+    let pen = PenT::default();
+    assert_eq!(pen.animal, AnimalT::NONE);
+    let buf = pack_pen(&pen);
+    let reader = root_as_pen(&buf).unwrap();
+    assert_eq!(reader.animal_type(), AnimalType::NONE);
+    assert_eq!(reader.unpack(), pen);
+}
+
+#[test]
+fn test_vectors_round_trip() {
+    let inventory = InventoryT {
+        bytes: Some(vec![1, 2, 3]),
+        flags: Some(vec![true, false]),
+        counts: Some(vec![-1, 0, 1]),
+        weights: Some(vec![0.5]),
+        names: Some(vec!["apple".to_string(), "pear".to_string()]),
+        colors: Some(vec![Color::Blue, Color::Red]),
+        points: Some(vec![Point::new(1, 2)]),
+        items: Some(vec![
+            ItemT {
+                name: Some("hammer".to_string()),
+            },
+            ItemT { name: None },
+        ]),
+    };
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = inventory.pack(&mut builder);
+    builder.finish_minimal(root);
+    let reader = root_as_inventory(builder.finished_data()).unwrap();
+    assert_eq!(
+        reader.names().unwrap().iter().collect::<Vec<_>>(),
+        vec!["apple", "pear"]
+    );
+    assert_eq!(reader.unpack(), inventory);
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = InventoryT::default().pack(&mut builder);
+    builder.finish_minimal(root);
+    let reader = root_as_inventory(builder.finished_data()).unwrap();
+    assert!(reader.items().is_none());
+    assert_eq!(reader.unpack(), InventoryT::default());
+}
+
+#[test]
+fn test_defaults_and_required_fields() {
+    let window = WindowT::default();
+    assert_eq!(window.title, "");
+    assert_eq!(window.size, Size::default());
+    assert_eq!(window.opacity, 0.75);
+    assert_eq!(window.level, Level::High);
+    assert_eq!(window.monitor, None);
+
+    let mut window = WindowT {
+        title: "main".to_string(),
+        size: Size::new(640, 480),
+        monitor: Some(2),
+        tags: Some(vec!["a".to_string()]),
+        ..WindowT::default()
+    };
+    window.level = Level::Low;
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = window.pack(&mut builder);
+    builder.finish_minimal(root);
+    let reader = root_as_window(builder.finished_data()).unwrap();
+    assert_eq!(reader.title(), "main");
+    assert_eq!(reader.size(), Size::new(640, 480));
+    assert_eq!(reader.level(), Level::Low);
+    assert_eq!(reader.monitor(), Some(2));
+    assert_eq!(reader.unpack(), window);
+}
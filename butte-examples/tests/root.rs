@@ -0,0 +1,63 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("zoo");
+
+use zoo::{
+    finish_pen_buffer, finish_size_prefixed_pen_buffer, get_root_as_pen,
+    get_size_prefixed_root_as_pen, pen_buffer_has_identifier,
+    pen_size_prefixed_buffer_has_identifier, AnimalType, Cat, CatArgs, Pen, PenArgs, PEN_EXTENSION,
+    PEN_IDENTIFIER,
+};
+
+fn create_pen<'a>(builder: &mut fb::FlatBufferBuilder<'a>) -> fb::WIPOffset<Pen<'a>> {
+    let cat = Cat::create(builder, &CatArgs { lives: 9 });
+    let args = PenArgs {
+        animal_type: AnimalType::Cat,
+        animal: Some(cat.as_union_value()),
+    };
+    Pen::create(builder, &args)
+}
+
+#[test]
+fn test_root_functions() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = create_pen(&mut builder);
+    finish_pen_buffer(&mut builder, pen);
+
+    let buf = builder.finished_data();
+    assert!(pen_buffer_has_identifier(buf));
+    let root = get_root_as_pen(buf);
+    assert_eq!(root.animal_as_cat().map(|cat| cat.lives()), Some(9));
+}
+
+#[test]
+fn test_size_prefixed_root_functions() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = create_pen(&mut builder);
+    finish_size_prefixed_pen_buffer(&mut builder, pen);
+
+    let buf = builder.finished_data();
+    assert!(pen_size_prefixed_buffer_has_identifier(buf));
+    assert!(!pen_buffer_has_identifier(buf));
+    let root = get_size_prefixed_root_as_pen(buf);
+    assert_eq!(root.animal_type(), AnimalType::Cat);
+}
+
+#[test]
+fn test_foreign_buffer_is_rejected() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = create_pen(&mut builder);
+    builder.finish(pen, Some("LION"));
+    assert!(!pen_buffer_has_identifier(builder.finished_data()));
+
+    let mut builder = fb::FlatBufferBuilder::new();
+    let pen = create_pen(&mut builder);
+    builder.finish_minimal(pen);
+    assert!(!pen_buffer_has_identifier(builder.finished_data()));
+}
+
+#[test]
+fn test_identifier_and_extension() {
+    assert_eq!(PEN_IDENTIFIER, "ZOOP");
+    assert_eq!(PEN_EXTENSION, "zoo");
+}
@@ -0,0 +1,148 @@
+use flatbuffers as fb;
+use serde_json::json;
+
+butte::include_fbs!("access");
+butte::include_fbs!("geometry");
+butte::include_fbs!("inventory");
+butte::include_fbs!("zoo");
+
+mod common;
+
+use access::{root_as_entry, Entry, EntryArgs, Grant, Permission};
+use common::pack_pen;
+use geometry::{Axis, Triangle, Vec3};
+use inventory::{root_as_inventory, Color, InventoryT, ItemT, Point};
+use zoo::{root_as_pen, AnimalT, AnimalType, DogT, PenT};
+
+#[test]
+fn test_serialize_union() {
+    let buf = pack_pen(&PenT {
+        animal: AnimalT::Dog(Box::new(DogT {
+            name: Some("Rex".to_string()),
+        })),
+    });
+    let pen = root_as_pen(&buf).unwrap();
+    let value = serde_json::to_value(pen).unwrap();
+    assert_eq!(value, json!({ "animal": { "Dog": { "name": "Rex" } } }));
+
+    // the table read from the buffer deserializes as its object type
+    let object: PenT = serde_json::from_value(value).unwrap();
+    assert_eq!(object, pen.unpack());
+
+    let buf = pack_pen(&PenT::default());
+    let value = serde_json::to_value(root_as_pen(&buf).unwrap()).unwrap();
+    assert_eq!(value, json!({}));
+    assert_eq!(
+        serde_json::from_value::<PenT>(value).unwrap(),
+        PenT::default()
+    );
+
+    assert_eq!(serde_json::to_value(AnimalType::Cat).unwrap(), json!("Cat"));
+}
+
+#[test]
+fn test_serialize_vectors() {
+    let inventory = InventoryT {
+        bytes: Some(vec![1, 2]),
+        names: Some(vec!["apple".to_string()]),
+        colors: Some(vec![Color::Blue, Color(7)]),
+        points: Some(vec![Point::new(1, 2)]),
+        items: Some(vec![ItemT {
+            name: Some("hammer".to_string()),
+        }]),
+        ..InventoryT::default()
+    };
+    let mut builder = fb::FlatBufferBuilder::new();
+    let root = inventory.pack(&mut builder);
+    builder.finish_minimal(root);
+    let reader = root_as_inventory(builder.finished_data()).unwrap();
+
+    let value = serde_json::to_value(reader).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "bytes": [1, 2],
+            "names": ["apple"],
+            "colors": ["Blue", 7],
+            "points": [{ "x": 1, "y": 2 }],
+            "items": [{ "name": "hammer" }],
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<InventoryT>(value).unwrap(),
+        inventory
+    );
+}
+
+#[test]
+fn test_deserialize_enums() {
+    assert_eq!(
+        serde_json::from_str::<Color>("\"Green\"").unwrap(),
+        Color::Green
+    );
+    assert_eq!(serde_json::from_str::<Color>("9").unwrap(), Color(9));
+    assert!(serde_json::from_str::<Color>("\"Purple\"").is_err());
+
+    let flags = serde_json::from_str::<Permission>("\"Write Admin\"").unwrap();
+    assert_eq!(flags, Permission::Write | Permission::Admin);
+    assert_eq!(
+        serde_json::from_str::<Permission>("\"\"").unwrap(),
+        Permission::empty()
+    );
+    assert_eq!(
+        serde_json::from_str::<Permission>("16").unwrap(),
+        Permission::from_bits_retain(16)
+    );
+    assert!(serde_json::from_str::<Permission>("\"Read Delete\"").is_err());
+}
+
+#[test]
+fn test_serialize_flags() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let masks = builder.create_vector(&[Permission::empty(), Permission::from_bits_retain(0x8010)]);
+    let args = EntryArgs {
+        mode: Permission::Read | Permission::Write,
+        owner: Some(Grant::new(7, Permission::Execute)),
+        masks: Some(masks),
+        ..EntryArgs::default()
+    };
+    let entry = Entry::create(&mut builder, &args);
+    builder.finish_minimal(entry);
+    let entry = root_as_entry(builder.finished_data()).unwrap();
+    assert_eq!(
+        serde_json::to_value(entry).unwrap(),
+        json!({
+            "mode": "Read Write",
+            "owner": { "user": 7, "permission": "Execute" },
+            "masks": [0, 0x8010],
+        })
+    );
+}
+
+#[test]
+fn test_serialize_structs() {
+    let triangle = Triangle::new(
+        [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ],
+        [Axis::X, Axis::Z],
+    );
+    let value = serde_json::to_value(triangle).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "corners": [
+                { "x": 0.0, "y": 0.0, "z": 0.0 },
+                { "x": 1.0, "y": 0.0, "z": 0.0 },
+                { "x": 0.0, "y": 1.0, "z": 0.0 },
+            ],
+            "axes": ["X", "Z"],
+        })
+    );
+    assert_eq!(serde_json::from_value::<Triangle>(value).unwrap(), triangle);
+
+    // a struct has no defaults, so every field must be given
+    assert!(serde_json::from_value::<Vec3>(json!({ "x": 1.0, "y": 2.0 })).is_err());
+}
@@ -0,0 +1,60 @@
+use flatbuffers as fb;
+use std::mem::{align_of, size_of};
+
+butte::include_fbs!("geometry");
+
+use geometry::{Aligned, Axis, Ray, Scene, SceneArgs, Vec3};
+
+#[test]
+fn test_struct_layout() {
+    assert_eq!(size_of::<Vec3>(), 12);
+    assert_eq!(align_of::<Vec3>(), 4);
+
+    // the axis is padded out to the alignment of the following double
+    assert_eq!(size_of::<Ray>(), 24);
+    assert_eq!(align_of::<Ray>(), 8);
+
+    assert_eq!(size_of::<Aligned>(), 16);
+    assert_eq!(align_of::<Aligned>(), 16);
+}
+
+#[test]
+fn test_struct_accessors() {
+    let origin = Vec3::new(1.0, 2.0, 3.0);
+    let ray = Ray::new(origin, Axis::Z, 4.5);
+    assert_eq!(ray.origin(), origin);
+    assert_eq!(ray.origin().y(), 2.0);
+    assert_eq!(ray.axis(), Axis::Z);
+    assert_eq!(ray.length(), 4.5);
+}
+
+#[test]
+fn test_struct_in_table() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let camera = Vec3::new(1.0, 2.0, 3.0);
+    let ray = Ray::new(Vec3::new(-1.0, 0.0, 1.0), Axis::Y, 10.0);
+    let args = SceneArgs {
+        camera: Some(camera),
+        ray: Some(ray),
+    };
+    let scene = Scene::create(&mut builder, &args);
+    builder.finish_minimal(scene);
+
+    let root = fb::get_root::<Scene>(builder.finished_data());
+    assert_eq!(root.camera(), Some(camera));
+    assert_eq!(root.ray(), Some(ray));
+    assert_eq!(root.ray().map(|ray| ray.axis()), Some(Axis::Y));
+}
+
+#[test]
+fn test_vector_of_structs() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let points = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+    let vector = builder.create_vector_direct(&points);
+    builder.finish_minimal(vector);
+
+    let root = fb::get_root::<fb::Vector<Vec3>>(builder.finished_data());
+    assert_eq!(root.len(), 2);
+    assert_eq!(root.get(1), points[1]);
+    assert_eq!(root.safe_slice(), &points[..]);
+}
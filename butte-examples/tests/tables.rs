@@ -0,0 +1,42 @@
+use flatbuffers as fb;
+
+pub mod greeter {
+    butte::include_fbs!("greeter");
+}
+
+use greeter::foo::bar::{Greeting, GreetingArgs, HelloReply, HelloReplyArgs};
+
+fn reply(inner: Option<&str>) -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let inner = inner.map(|text| {
+        let text = builder.create_string(text);
+        Greeting::create(&mut builder, &GreetingArgs { text: Some(text) })
+    });
+    let message = builder.create_string("hi");
+    let reply = HelloReply::create(
+        &mut builder,
+        &HelloReplyArgs {
+            message: Some(message),
+            inner,
+        },
+    );
+    builder.finish_minimal(reply);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn test_nested_table() {
+    let buf = reply(Some("hello there"));
+    let reply = fb::root::<HelloReply>(&buf).unwrap();
+    assert_eq!(reply.message(), Some("hi"));
+    let inner = reply.inner().unwrap();
+    assert_eq!(inner.text(), Some("hello there"));
+}
+
+#[test]
+fn test_missing_nested_table() {
+    let buf = reply(None);
+    let reply = fb::root::<HelloReply>(&buf).unwrap();
+    assert_eq!(reply.message(), Some("hi"));
+    assert!(reply.inner().is_none());
+}
@@ -0,0 +1,58 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("zoo");
+
+use zoo::{AnimalType, Cat, CatArgs, Dog, DogArgs, Pen, PenArgs};
+
+#[test]
+fn test_union_of_tables() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let name = builder.create_string("Rex");
+    let dog = Dog::create(&mut builder, &DogArgs { name: Some(name) });
+    let args = PenArgs {
+        animal_type: AnimalType::Dog,
+        animal: Some(dog.as_union_value()),
+    };
+    let pen = Pen::create(&mut builder, &args);
+    builder.finish_minimal(pen);
+
+    let root = fb::get_root::<Pen>(builder.finished_data());
+    assert_eq!(root.animal_type(), AnimalType::Dog);
+    assert!(root.animal().is_some());
+    assert_eq!(root.animal_as_dog().and_then(|dog| dog.name()), Some("Rex"));
+    assert_eq!(root.animal_as_cat(), None);
+}
+
+#[test]
+fn test_union_variants_are_distinguished() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let cat = Cat::create(&mut builder, &CatArgs { lives: 9 });
+    let args = PenArgs {
+        animal_type: AnimalType::Cat,
+        animal: Some(cat.as_union_value()),
+    };
+    let pen = Pen::create(&mut builder, &args);
+    builder.finish_minimal(pen);
+
+    let root = fb::get_root::<Pen>(builder.finished_data());
+    assert_eq!(root.animal_type(), AnimalType::Cat);
+    assert_eq!(root.animal_as_cat().map(|cat| cat.lives()), Some(9));
+    assert_eq!(root.animal_as_dog(), None);
+}
+
+#[test]
+fn test_empty_union() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let args = PenArgs {
+        animal_type: AnimalType::NONE,
+        animal: None,
+    };
+    let pen = Pen::create(&mut builder, &args);
+    builder.finish_minimal(pen);
+
+    let root = fb::get_root::<Pen>(builder.finished_data());
+    assert_eq!(root.animal_type(), AnimalType::NONE);
+    assert!(root.animal().is_none());
+    assert_eq!(root.animal_as_cat(), None);
+    assert_eq!(root.animal_as_dog(), None);
+}
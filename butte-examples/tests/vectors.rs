@@ -0,0 +1,127 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("inventory");
+
+use inventory::{
+    finish_inventory_buffer, root_as_inventory, root_as_inventory_with_opts, Color, Inventory,
+    InventoryArgs, Item, ItemArgs, Point,
+};
+
+fn inventory() -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let bytes = builder.create_vector(&[1u8, 2, 3]);
+    let flags = builder.create_vector(&[true, false]);
+    let counts = builder.create_vector(&[-1, 0, 1]);
+    let weights = builder.create_vector(&[0.5, 1.5]);
+    let names = builder.create_vector_of_strings(&["apple", "pear"]);
+    let colors = builder.create_vector(&[Color::Blue, Color::Red]);
+    let points = builder.create_vector(&[Point::new(1, 2), Point::new(3, 4)]);
+    let items = ["hammer", "saw"]
+        .iter()
+        .map(|name| {
+            let name = builder.create_string(name);
+            Item::create(&mut builder, &ItemArgs { name: Some(name) })
+        })
+        .collect::<Vec<_>>();
+    let items = builder.create_vector(&items);
+    let args = InventoryArgs {
+        bytes: Some(bytes),
+        flags: Some(flags),
+        counts: Some(counts),
+        weights: Some(weights),
+        names: Some(names),
+        colors: Some(colors),
+        points: Some(points),
+        items: Some(items),
+    };
+    let inventory = Inventory::create(&mut builder, &args);
+    finish_inventory_buffer(&mut builder, inventory);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn test_scalar_vectors() {
+    let buf = inventory();
+    let inventory = root_as_inventory(&buf).unwrap();
+    assert_eq!(inventory.bytes(), Some(&[1u8, 2, 3][..]));
+    assert_eq!(inventory.flags(), Some(&[true, false][..]));
+    assert_eq!(
+        inventory
+            .counts()
+            .map(|counts| counts.iter().collect::<Vec<_>>()),
+        Some(vec![-1, 0, 1])
+    );
+    let weights = inventory.weights().unwrap();
+    assert_eq!(weights.len(), 2);
+    assert_eq!(weights.get(1), 1.5);
+}
+
+#[test]
+fn test_string_vectors() {
+    let buf = inventory();
+    let inventory = root_as_inventory(&buf).unwrap();
+    let names = inventory.names().unwrap();
+    assert_eq!(names.iter().collect::<Vec<_>>(), vec!["apple", "pear"]);
+}
+
+#[test]
+fn test_enum_vectors() {
+    let buf = inventory();
+    let inventory = root_as_inventory(&buf).unwrap();
+    let colors = inventory.colors().unwrap();
+    assert_eq!(
+        colors.iter().collect::<Vec<_>>(),
+        vec![Color::Blue, Color::Red]
+    );
+}
+
+#[test]
+fn test_struct_vectors() {
+    let buf = inventory();
+    let inventory = root_as_inventory(&buf).unwrap();
+    let points = inventory.points().unwrap();
+    assert_eq!(points, &[Point::new(1, 2), Point::new(3, 4)][..]);
+    assert_eq!(points[1].y(), 4);
+}
+
+#[test]
+fn test_table_vectors() {
+    let buf = inventory();
+    let inventory = root_as_inventory(&buf).unwrap();
+    let items = inventory.items().unwrap();
+    assert_eq!(
+        items.iter().map(|item| item.name()).collect::<Vec<_>>(),
+        vec![Some("hammer"), Some("saw")]
+    );
+}
+
+#[test]
+fn test_vector_elements_are_verified() {
+    let mut buf = inventory();
+
+    // find the first color by following the offset to the vector of colors
+    let table = fb::get_root::<fb::Table>(&buf);
+    let field = table.loc + table.vtable().get(Inventory::VT_COLORS) as usize;
+    let vector = field + fb::read_scalar_at::<u32>(&buf, field) as usize;
+    let first = vector + fb::SIZE_UOFFSET;
+    assert_eq!(buf[first], Color::Blue.0);
+
+    // a color added by a newer version of the schema can still be read
+    buf[first] = 7;
+    let colors = root_as_inventory(&buf).unwrap().colors().unwrap();
+    assert_eq!(colors.get(0), Color(7));
+    assert_eq!(format!("{:?}", colors.get(0)), "Color(7)");
+    assert_eq!(format!("{:?}", colors.get(1)), "Red");
+
+    let opts = fb::VerifierOptions {
+        reject_unknown_enum_values: true,
+        ..fb::VerifierOptions::default()
+    };
+    assert_eq!(
+        root_as_inventory_with_opts(&opts, &buf).map(|_| ()),
+        Err(fb::InvalidFlatbuffer::UnknownEnumValue {
+            enum_name: "Color",
+            position: first,
+        })
+    );
+}
@@ -0,0 +1,274 @@
+use flatbuffers as fb;
+
+butte::include_fbs!("geometry");
+butte::include_fbs!("zoo");
+
+mod common;
+
+use common::root_field_position;
+use fb::{InvalidFlatbuffer, Verifiable, Verifier, VerifierOptions};
+use geometry::{Axis, Edges, Ray};
+use zoo::{
+    finish_pen_buffer, finish_size_prefixed_pen_buffer, root_as_pen, root_as_pen_with_opts,
+    size_prefixed_root_as_pen, AnimalType, Cat, CatArgs, Dog, DogArgs, Pen, PenArgs,
+};
+
+fn cat_pen() -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let cat = Cat::create(&mut builder, &CatArgs { lives: 9 });
+    let args = PenArgs {
+        animal_type: AnimalType::Cat,
+        animal: Some(cat.as_union_value()),
+    };
+    let pen = Pen::create(&mut builder, &args);
+    finish_pen_buffer(&mut builder, pen);
+    builder.finished_data().to_vec()
+}
+
+fn dog_pen(name: &str) -> Vec<u8> {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let name = builder.create_string(name);
+    let dog = Dog::create(&mut builder, &DogArgs { name: Some(name) });
+    let args = PenArgs {
+        animal_type: AnimalType::Dog,
+        animal: Some(dog.as_union_value()),
+    };
+    let pen = Pen::create(&mut builder, &args);
+    finish_pen_buffer(&mut builder, pen);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn test_valid_buffer() {
+    let buf = cat_pen();
+    let pen = root_as_pen(&buf).unwrap();
+    assert_eq!(pen.animal_as_cat().map(|cat| cat.lives()), Some(9));
+
+    let buf = dog_pen("Rex");
+    let pen = root_as_pen(&buf).unwrap();
+    assert_eq!(pen.animal_as_dog().and_then(|dog| dog.name()), Some("Rex"));
+}
+
+#[test]
+fn test_valid_size_prefixed_buffer() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let cat = Cat::create(&mut builder, &CatArgs { lives: 3 });
+    let args = PenArgs {
+        animal_type: AnimalType::Cat,
+        animal: Some(cat.as_union_value()),
+    };
+    let pen = Pen::create(&mut builder, &args);
+    finish_size_prefixed_pen_buffer(&mut builder, pen);
+    let pen = size_prefixed_root_as_pen(builder.finished_data()).unwrap();
+    assert_eq!(pen.animal_type(), AnimalType::Cat);
+}
+
+#[test]
+fn test_truncated_buffer() {
+    let buf = cat_pen();
+    for len in 0..buf.len() {
+        assert!(root_as_pen(&buf[..len]).is_err(), "length {}", len);
+    }
+}
+
+#[test]
+fn test_out_of_bounds_root_offset() {
+    let mut buf = cat_pen();
+    buf[..4].copy_from_slice(&1000u32.to_le_bytes());
+    match root_as_pen(&buf) {
+        Err(InvalidFlatbuffer::RangeOutOfBounds { .. }) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_unknown_union_type() {
+    let mut buf = cat_pen();
+    let table = fb::get_root::<fb::Table>(&buf);
+    let position = table.loc + table.vtable().get(Pen::VT_ANIMAL_TYPE) as usize;
+    buf[position] = 42;
+
+    // the value of a variant added by a newer version of the schema can't be verified, or read
+    let pen = root_as_pen(&buf).unwrap();
+    assert_eq!(pen.animal_type(), AnimalType(42));
+    assert!(pen.animal_as_cat().is_none());
+    assert!(pen.animal_as_dog().is_none());
+
+    let opts = VerifierOptions {
+        reject_unknown_enum_values: true,
+        ..VerifierOptions::default()
+    };
+    assert_eq!(
+        root_as_pen_with_opts(&opts, &buf).map(|_| ()),
+        Err(InvalidFlatbuffer::UnknownEnumValue {
+            enum_name: "AnimalType",
+            position,
+        })
+    );
+}
+
+#[test]
+fn test_invalid_utf8() {
+    let mut buf = dog_pen("Rex");
+    let start = buf.windows(3).position(|window| window == b"Rex").unwrap();
+    buf[start] = 0xff;
+    match root_as_pen(&buf) {
+        Err(InvalidFlatbuffer::Utf8Error { range }) => assert_eq!(range, start..start + 3),
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_missing_null_terminator() {
+    let mut buf = dog_pen("Rex");
+    let end = buf.windows(3).position(|window| window == b"Rex").unwrap() + 3;
+    buf[end] = b'!';
+    match root_as_pen(&buf) {
+        Err(InvalidFlatbuffer::MissingNullTerminator { .. }) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+
+    let opts = VerifierOptions {
+        ignore_missing_null_terminator: true,
+        ..VerifierOptions::default()
+    };
+    assert!(root_as_pen_with_opts(&opts, &buf).is_ok());
+}
+
+#[test]
+fn test_limits() {
+    let buf = cat_pen();
+
+    let opts = VerifierOptions {
+        max_depth: 1,
+        ..VerifierOptions::default()
+    };
+    assert_eq!(
+        root_as_pen_with_opts(&opts, &buf).map(|_| ()),
+        Err(InvalidFlatbuffer::DepthLimitReached)
+    );
+
+    let opts = VerifierOptions {
+        max_tables: 1,
+        ..VerifierOptions::default()
+    };
+    assert_eq!(
+        root_as_pen_with_opts(&opts, &buf).map(|_| ()),
+        Err(InvalidFlatbuffer::TooManyTables)
+    );
+
+    let opts = VerifierOptions {
+        max_apparent_size: 8,
+        ..VerifierOptions::default()
+    };
+    assert_eq!(
+        root_as_pen_with_opts(&opts, &buf).map(|_| ()),
+        Err(InvalidFlatbuffer::ApparentSizeTooLarge)
+    );
+}
+
+#[test]
+fn test_struct_with_enum_field() {
+    // the axis follows the three floats of the origin, and is padded out to the double after it
+    let mut buf = [1.0f32, 2.0, 3.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes().to_vec())
+        .collect::<Vec<_>>();
+    buf.extend_from_slice(&[Axis::Y.0 as u8, 0, 0, 0]);
+    buf.extend_from_slice(&4.0f64.to_le_bytes());
+    assert_eq!(buf.len(), std::mem::size_of::<Ray>());
+
+    let opts = VerifierOptions::default();
+    assert_eq!(
+        Ray::run_verifier(&mut Verifier::new(&opts, &buf), 0),
+        Ok(())
+    );
+
+    buf[12] = 3;
+    assert_eq!(
+        Ray::run_verifier(&mut Verifier::new(&opts, &buf), 0),
+        Ok(())
+    );
+    let opts = VerifierOptions {
+        reject_unknown_enum_values: true,
+        ..VerifierOptions::default()
+    };
+    assert_eq!(
+        Ray::run_verifier(&mut Verifier::new(&opts, &buf), 0),
+        Err(InvalidFlatbuffer::UnknownEnumValue {
+            enum_name: "Axis",
+            position: 12,
+        })
+    );
+}
+
+/// Verify the root table of `buf`, which has a bool as its only field.
+fn verify_bool_table(buf: &[u8]) -> Result<(), InvalidFlatbuffer> {
+    let opts = VerifierOptions::default();
+    let mut v = Verifier::new(&opts, buf);
+    let pos = v.deref_uoffset(0)?;
+    v.visit_table(pos)?
+        .visit_field::<bool>("visible", fb::field_index_to_field_offset(0), false)?
+        .finish();
+    Ok(())
+}
+
+#[test]
+fn test_invalid_bool_field() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let start = builder.start_table();
+    builder.push_slot_always(fb::field_index_to_field_offset(0), false);
+    let table = builder.end_table(start);
+    builder.finish_minimal(table);
+    let mut buf = builder.finished_data().to_vec();
+    assert_eq!(verify_bool_table(&buf), Ok(()));
+
+    let pos = root_field_position(&buf, fb::field_index_to_field_offset(0));
+    buf[pos] = 1;
+    assert_eq!(verify_bool_table(&buf), Ok(()));
+    buf[pos] = 2;
+    assert_eq!(
+        verify_bool_table(&buf),
+        Err(InvalidFlatbuffer::InvalidBool { position: pos })
+    );
+}
+
+#[test]
+fn test_invalid_bool_vector() {
+    let mut builder = fb::FlatBufferBuilder::new();
+    let flags = builder.create_vector(&[true, false, true]);
+    builder.finish_minimal(flags);
+    let mut buf = builder.finished_data().to_vec();
+    assert!(fb::root::<fb::Vector<bool>>(&buf).is_ok());
+
+    let vector = fb::read_scalar_at::<fb::UOffsetT>(&buf, 0) as usize;
+    let pos = vector + fb::SIZE_UOFFSET + 1;
+    buf[pos] = 255;
+    assert_eq!(
+        fb::root::<fb::Vector<bool>>(&buf).map(|_| ()),
+        Err(InvalidFlatbuffer::InvalidBool { position: pos })
+    );
+}
+
+#[test]
+fn test_struct_with_bool_fields() {
+    let opts = VerifierOptions::default();
+    let mut buf = [1, 0, 1, 1];
+    assert_eq!(std::mem::size_of::<Edges>(), buf.len());
+    assert_eq!(
+        Edges::run_verifier(&mut Verifier::new(&opts, &buf), 0),
+        Ok(())
+    );
+
+    buf[0] = 2;
+    assert_eq!(
+        Edges::run_verifier(&mut Verifier::new(&opts, &buf), 0),
+        Err(InvalidFlatbuffer::InvalidBool { position: 0 })
+    );
+    buf[0] = 0;
+    buf[3] = 2;
+    assert_eq!(
+        Edges::run_verifier(&mut Verifier::new(&opts, &buf), 0),
+        Err(InvalidFlatbuffer::InvalidBool { position: 3 })
+    );
+}
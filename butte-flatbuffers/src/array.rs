@@ -0,0 +1,134 @@
+/*
+ * Copyright 2018 Google Inc. All rights reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::{DoubleEndedIterator, ExactSizeIterator},
+    marker::PhantomData,
+    mem::size_of,
+    slice::from_raw_parts,
+};
+
+use crate::{endian_scalar::EndianScalar, follow::Follow, vector::SafeSliceAccess};
+
+/// A fixed-length array of `N` elements, `[T:N]` in a schema, stored inline in a struct.
+///
+/// Like the elements of a `Vector`, the elements are stored in little-endian byte order and
+/// converted to the host's byte order when they're read.
+pub struct Array<'a, T: 'a, const N: usize>(&'a [u8], PhantomData<T>);
+
+// As for `Vector`, deriving these would require `T: Copy` and `T: Clone`.
+impl<'a, T, const N: usize> Copy for Array<'a, T, N> {}
+impl<'a, T, const N: usize> Clone for Array<'a, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a, const N: usize> Array<'a, T, N> {
+    /// View the first `N` elements of `T` in `buf` as an array.
+    #[inline(always)]
+    pub fn new(buf: &'a [u8]) -> Self {
+        assert!(buf.len() >= N * size_of::<T>());
+        Array(&buf[..N * size_of::<T>()], PhantomData)
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<'a, T: Follow<'a> + 'a, const N: usize> Array<'a, T, N> {
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> T::Inner {
+        assert!(idx < N);
+        T::follow(self.0, size_of::<T>() * idx)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T::Inner> + ExactSizeIterator + 'a {
+        let array = *self;
+        (0..N).map(move |idx| array.get(idx))
+    }
+}
+
+impl<'a, T: Follow<'a> + 'a, const N: usize> Debug for Array<'a, T, N>
+where
+    T::Inner: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: Follow<'a> + 'a, const N: usize> serde::Serialize for Array<'a, T, N>
+where
+    T::Inner: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'a, T: SafeSliceAccess + 'a, const N: usize> Array<'a, T, N> {
+    pub fn safe_slice(self) -> &'a [T] {
+        let ptr = self.0.as_ptr() as *const T;
+        unsafe { from_raw_parts(ptr, N) }
+    }
+}
+
+impl<'a, T: Follow<'a> + 'a, const N: usize> Follow<'a> for Array<'a, T, N> {
+    type Inner = Self;
+
+    #[inline(always)]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Array::new(&buf[loc..])
+    }
+}
+
+impl<'a, T: EndianScalar + 'a, const N: usize> From<&'a [T; N]> for Array<'a, T, N> {
+    /// View an array that's stored in little-endian byte order, such as a field of a struct.
+    #[inline(always)]
+    fn from(array: &'a [T; N]) -> Self {
+        let ptr = array.as_ptr() as *const u8;
+        Array::new(unsafe { from_raw_parts(ptr, N * size_of::<T>()) })
+    }
+}
+
+impl<T: EndianScalar, const N: usize> EndianScalar for [T; N] {
+    #[inline]
+    fn to_little_endian(mut self) -> Self {
+        for x in self.iter_mut() {
+            *x = x.to_little_endian();
+        }
+        self
+    }
+
+    #[inline]
+    fn from_little_endian(mut self) -> Self {
+        for x in self.iter_mut() {
+            *x = x.from_little_endian();
+        }
+        self
+    }
+}
@@ -16,13 +16,18 @@
 
 extern crate smallvec;
 
-use std::{cmp::max, marker::PhantomData, ptr::write_bytes, slice::from_raw_parts};
+use std::{
+    cmp::{max, Ordering},
+    marker::PhantomData,
+    ptr::write_bytes,
+    slice::from_raw_parts,
+};
 
 use crate::{
     endian_scalar::{emplace_scalar, read_scalar_at},
     primitives::*,
     push::{Push, PushAlignment},
-    table::Table,
+    table::{KeyedTable, Table},
     vector::{SafeSliceAccess, Vector},
     vtable::{field_index_to_field_offset, VTable},
     vtable_writer::VTableWriter,
@@ -162,6 +167,20 @@ impl<'fbb> FlatBufferBuilder<'fbb> {
         self.track_field(slotoff, off.value());
     }
 
+    /// Push `bytes`, aligned to `alignment`, onto the front of the in-progress data, and store a
+    /// reference to them in the in-progress vtable.
+    ///
+    /// This is for values whose layout is only known at runtime, such as a struct described by a
+    /// schema rather than by generated code. The bytes must already be in little-endian order.
+    #[inline]
+    pub fn push_slot_bytes(&mut self, slotoff: VOffsetT, bytes: &[u8], alignment: usize) {
+        self.assert_nested("push_slot_bytes");
+        self.align(bytes.len(), PushAlignment::new(alignment));
+        self.push_bytes_unprefixed(bytes);
+        let off = self.used_space() as UOffsetT;
+        self.track_field(slotoff, off);
+    }
+
     /// Retrieve the number of vtables that have been serialized into the
     /// FlatBuffer. This is primarily used to check vtable deduplication.
     #[inline]
@@ -283,6 +302,31 @@ impl<'fbb> FlatBufferBuilder<'fbb> {
         WIPOffset::new(self.used_space() as UOffsetT)
     }
 
+    /// Create a vector of `num_items` elements laid out one after the other in `bytes`, aligned to
+    /// `alignment`.
+    ///
+    /// Like `push_slot_bytes`, this is for elements whose layout is only known at runtime, so the
+    /// vector is typed as a vector of bytes. The elements must already be in little-endian order.
+    #[inline]
+    pub fn create_vector_from_bytes(
+        &mut self,
+        bytes: &[u8],
+        num_items: usize,
+        alignment: usize,
+    ) -> WIPOffset<Vector<'fbb, u8>> {
+        self.assert_not_nested(
+            "create_vector_from_bytes can not be called when a table or vector is under construction",
+        );
+        self.align(
+            bytes.len(),
+            PushAlignment::new(alignment).max_of(SIZE_UOFFSET),
+        );
+        self.push_bytes_unprefixed(bytes);
+        self.push(num_items as UOffsetT);
+
+        WIPOffset::new(self.used_space() as UOffsetT)
+    }
+
     /// Create a vector of strings.
     ///
     /// Speed-sensitive users may wish to reduce memory usage by creating the
@@ -309,6 +353,31 @@ impl<'fbb> FlatBufferBuilder<'fbb> {
         self.create_vector(&offsets[..])
     }
 
+    /// Create a vector of tables, sorted by their key so that the vector can be searched with
+    /// `Vector::lookup_by_key`.
+    ///
+    /// `items` is sorted in place, and must only contain tables that have been finished.
+    #[inline]
+    pub fn create_vector_of_sorted_tables<T: KeyedTable>(
+        &mut self,
+        items: &mut [WIPOffset<T>],
+    ) -> WIPOffset<Vector<'fbb, ForwardsUOffset<T>>> {
+        {
+            let buf = self.unfinished_data();
+            let table = |item: &WIPOffset<T>| Table::new(buf, buf.len() - item.value() as usize);
+            items.sort_by(|a, b| {
+                if T::key_less_than(table(a), table(b)) {
+                    Ordering::Less
+                } else if T::key_less_than(table(b), table(a)) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            });
+        }
+        self.create_vector(items)
+    }
+
     /// Create a vector of Push-able objects.
     ///
     /// Speed-sensitive users may wish to reduce memory usage by creating the
@@ -0,0 +1,770 @@
+//! Reading buffers without generated code, by following a binary schema.
+//!
+//! A binary schema (a `.bfbs` file) describes the types of a schema as `reflection.fbs` tables.
+//! `flatc --binary --schema` writes one, as does `butte --binary-schema`, and
+//! `butte::reflection::binary_schema` builds one from schemas parsed at runtime.
+//!
+//! The schema is trusted: reading a buffer with a schema that isn't consistent may panic. Buffers
+//! are verified against the schema before they're read, unless they're read with
+//! [`DynSchema::get_root`].
+
+use std::fmt;
+
+use crate::{
+    endian_scalar::read_scalar_at,
+    follow::Follow,
+    primitives::*,
+    reflection::{self, BaseType},
+    table::Table,
+    verifier::{InvalidFlatbuffer, Verifiable, Verifier, VerifierOptions},
+    vtable::field_index_to_field_offset,
+};
+
+/// The error returned when a binary schema can't be used to read buffers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynSchemaError {
+    /// The binary schema isn't a valid `reflection.Schema`.
+    InvalidSchema(InvalidFlatbuffer),
+
+    /// The schema has no table with the name given as the root table.
+    UnknownTable(String),
+}
+
+impl fmt::Display for DynSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DynSchemaError::InvalidSchema(error) => write!(f, "invalid binary schema: {}", error),
+            DynSchemaError::UnknownTable(name) => write!(f, "the schema has no table `{}`", name),
+        }
+    }
+}
+
+impl std::error::Error for DynSchemaError {}
+
+/// A binary schema along with the table at the root of the buffers it reads.
+#[derive(Clone, Copy, Debug)]
+pub struct DynSchema<'s> {
+    schema: reflection::Schema<'s>,
+    root_table: reflection::Object<'s>,
+}
+
+impl<'s> DynSchema<'s> {
+    /// Read buffers whose root is the table named `root_table`, such as `zoo.Pen`, with the
+    /// binary schema `bfbs`.
+    pub fn from_bfbs(bfbs: &'s [u8], root_table: &str) -> Result<Self, DynSchemaError> {
+        let schema = reflection::root_as_schema(bfbs).map_err(DynSchemaError::InvalidSchema)?;
+        Self::new(schema, root_table)
+    }
+
+    /// Read buffers whose root is the table named `root_table`, such as `zoo.Pen`, with `schema`.
+    pub fn new(schema: reflection::Schema<'s>, root_table: &str) -> Result<Self, DynSchemaError> {
+        let root_table = schema
+            .objects()
+            .lookup_by_key(root_table, |object, name| {
+                object.key_compare_with_value(name)
+            })
+            .filter(|object| !object.is_struct())
+            .ok_or_else(|| DynSchemaError::UnknownTable(root_table.to_string()))?;
+        Ok(Self { schema, root_table })
+    }
+
+    /// The binary schema.
+    pub fn schema(&self) -> reflection::Schema<'s> {
+        self.schema
+    }
+
+    /// The table at the root of buffers.
+    pub fn root_table(&self) -> reflection::Object<'s> {
+        self.root_table
+    }
+
+    /// Verify that `buf` holds a valid root table, and read it.
+    pub fn root<'b>(&self, buf: &'b [u8]) -> Result<DynTable<'s, 'b>, InvalidFlatbuffer> {
+        self.root_with_opts(&VerifierOptions::default(), buf)
+    }
+
+    /// Verify that `buf` holds a valid root table within the limits of `opts`, and read it.
+    pub fn root_with_opts<'b>(
+        &self,
+        opts: &VerifierOptions,
+        buf: &'b [u8],
+    ) -> Result<DynTable<'s, 'b>, InvalidFlatbuffer> {
+        let mut v = Verifier::new(opts, buf);
+        let pos = v.deref_uoffset(0)?;
+        verify_table(&mut v, self.schema, self.root_table, pos)?;
+        Ok(self.get_root(buf))
+    }
+
+    /// Read the root table of `buf` without verifying it, which panics if the buffer isn't valid.
+    pub fn get_root<'b>(&self, buf: &'b [u8]) -> DynTable<'s, 'b> {
+        DynTable {
+            schema: self.schema,
+            object: self.root_table,
+            table: <ForwardsUOffset<Table<'b>>>::follow(buf, 0),
+        }
+    }
+}
+
+/// A value read from a buffer.
+///
+/// Enums are their underlying integers, and the type of a union is a `UByte`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum DynValue<'s, 'b> {
+    Bool(bool),
+    Byte(i8),
+    UByte(u8),
+    Short(i16),
+    UShort(u16),
+    Int(i32),
+    UInt(u32),
+    Long(i64),
+    ULong(u64),
+    Float(f32),
+    Double(f64),
+    String(&'b str),
+    Vector(DynVector<'s, 'b>),
+    Table(DynTable<'s, 'b>),
+    Struct(DynStruct<'s, 'b>),
+    Union(DynUnion<'s, 'b>),
+}
+
+impl<'s, 'b> DynValue<'s, 'b> {
+    /// The value as an integer, if it's a bool or an integer that fits in an `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        Some(match *self {
+            DynValue::Bool(x) => x as i64,
+            DynValue::Byte(x) => x as i64,
+            DynValue::UByte(x) => x as i64,
+            DynValue::Short(x) => x as i64,
+            DynValue::UShort(x) => x as i64,
+            DynValue::Int(x) => x as i64,
+            DynValue::UInt(x) => x as i64,
+            DynValue::Long(x) => x,
+            DynValue::ULong(x) if x <= i64::MAX as u64 => x as i64,
+            _ => return None,
+        })
+    }
+
+    /// The value as a float, if it's a float or an integer.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            DynValue::Float(x) => Some(x as f64),
+            DynValue::Double(x) => Some(x),
+            DynValue::ULong(x) => Some(x as f64),
+            _ => self.as_i64().map(|x| x as f64),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'b str> {
+        match *self {
+            DynValue::String(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_vector(&self) -> Option<DynVector<'s, 'b>> {
+        match *self {
+            DynValue::Vector(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The value as a table, which is the table a union holds for a union.
+    pub fn as_table(&self) -> Option<DynTable<'s, 'b>> {
+        match *self {
+            DynValue::Table(x) => Some(x),
+            DynValue::Union(x) => Some(x.table),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<DynStruct<'s, 'b>> {
+        match *self {
+            DynValue::Struct(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_union(&self) -> Option<DynUnion<'s, 'b>> {
+        match *self {
+            DynValue::Union(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+/// A value is shown as what it holds, like the fields of generated code.
+impl fmt::Debug for DynValue<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DynValue::Bool(x) => x.fmt(f),
+            DynValue::Byte(x) => x.fmt(f),
+            DynValue::UByte(x) => x.fmt(f),
+            DynValue::Short(x) => x.fmt(f),
+            DynValue::UShort(x) => x.fmt(f),
+            DynValue::Int(x) => x.fmt(f),
+            DynValue::UInt(x) => x.fmt(f),
+            DynValue::Long(x) => x.fmt(f),
+            DynValue::ULong(x) => x.fmt(f),
+            DynValue::Float(x) => x.fmt(f),
+            DynValue::Double(x) => x.fmt(f),
+            DynValue::String(x) => x.fmt(f),
+            DynValue::Vector(x) => x.fmt(f),
+            DynValue::Table(x) => x.fmt(f),
+            DynValue::Struct(x) => x.fmt(f),
+            DynValue::Union(x) => x.fmt(f),
+        }
+    }
+}
+
+/// A table read from a buffer.
+#[derive(Clone, Copy)]
+pub struct DynTable<'s, 'b> {
+    schema: reflection::Schema<'s>,
+    object: reflection::Object<'s>,
+    table: Table<'b>,
+}
+
+impl<'s, 'b> DynTable<'s, 'b> {
+    /// The type of the table.
+    pub fn object(&self) -> reflection::Object<'s> {
+        self.object
+    }
+
+    /// The table, to be read as the type it is.
+    pub fn table(&self) -> Table<'b> {
+        self.table
+    }
+
+    /// The field of the table named `name`, if it has one.
+    pub fn field(&self, name: &str) -> Option<reflection::Field<'s>> {
+        find_field(self.object, name)
+    }
+
+    /// The value of the field named `name`.
+    ///
+    /// A scalar missing from the buffer has its default value, unless it's optional. This is
+    /// `None` if the table has no such field, if the field has no value, or if it's a union
+    /// holding a table the schema doesn't know about.
+    pub fn get(&self, name: &str) -> Option<DynValue<'s, 'b>> {
+        self.get_field(self.field(name)?)
+    }
+
+    /// The value of `field`, which is a field of the table.
+    pub fn get_field(&self, field: reflection::Field<'s>) -> Option<DynValue<'s, 'b>> {
+        let ty = field.r#type();
+        let pos = match self.table.vtable().get(field.offset() as VOffsetT) {
+            0 if field.optional() => return None,
+            0 => return default_value(ty.base_type(), &field),
+            offset => self.table.loc + offset as usize,
+        };
+        if ty.base_type() == BaseType::Union {
+            // the type of the union is stored in the slot before its value
+            let type_offset = field_index_to_field_offset(field.id().checked_sub(1)? as VOffsetT);
+            let variant = self.table.get::<u8>(type_offset, Some(0))?;
+            let (value, object) = union_variant(self.schema, ty.index(), variant)?;
+            return Some(DynValue::Union(DynUnion {
+                variant: value,
+                table: DynTable {
+                    schema: self.schema,
+                    object,
+                    table: <ForwardsUOffset<Table<'b>>>::follow(self.table.buf, pos),
+                },
+            }));
+        }
+        if ty.base_type() == BaseType::Vector {
+            let vector = read_vector(self.schema, ty, self.table.buf, pos);
+            return Some(DynValue::Vector(vector));
+        }
+        read_value(self.schema, ty.base_type(), ty.index(), self.table.buf, pos)
+    }
+
+    /// The fields of the table that have values, in the order of their ids, which is the order
+    /// they're declared in unless they're given explicit ids.
+    pub fn values(&self) -> Vec<(reflection::Field<'s>, DynValue<'s, 'b>)> {
+        let mut fields = self.object.fields().iter().collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.id());
+        fields
+            .into_iter()
+            .filter(|field| !field.deprecated())
+            .filter_map(|field| Some((field, self.get_field(field)?)))
+            .collect()
+    }
+}
+
+impl PartialEq for DynTable<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object && self.table == other.table
+    }
+}
+
+/// A table is shown with the fields that have values, like the tables of generated code.
+impl fmt::Debug for DynTable<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct(short_name(self.object.name()));
+        for (field, value) in self.values() {
+            s.field(field.name(), &value);
+        }
+        s.finish()
+    }
+}
+
+/// A struct read from a buffer.
+#[derive(Clone, Copy)]
+pub struct DynStruct<'s, 'b> {
+    schema: reflection::Schema<'s>,
+    object: reflection::Object<'s>,
+    buf: &'b [u8],
+    loc: usize,
+}
+
+impl<'s, 'b> DynStruct<'s, 'b> {
+    /// The type of the struct.
+    pub fn object(&self) -> reflection::Object<'s> {
+        self.object
+    }
+
+    /// The bytes of the struct.
+    pub fn bytes(&self) -> &'b [u8] {
+        &self.buf[self.loc..self.loc + self.object.bytesize() as usize]
+    }
+
+    /// The field of the struct named `name`, if it has one.
+    pub fn field(&self, name: &str) -> Option<reflection::Field<'s>> {
+        find_field(self.object, name)
+    }
+
+    /// The value of the field named `name`, or `None` if the struct has no such field.
+    pub fn get(&self, name: &str) -> Option<DynValue<'s, 'b>> {
+        self.get_field(self.field(name)?)
+    }
+
+    /// The value of `field`, which is a field of the struct.
+    pub fn get_field(&self, field: reflection::Field<'s>) -> Option<DynValue<'s, 'b>> {
+        let ty = field.r#type();
+        let pos = self.loc + field.offset() as usize;
+        if ty.base_type() == BaseType::Array {
+            let element_size = inline_size(self.schema, ty.element(), ty.index());
+            return Some(DynValue::Vector(DynVector {
+                schema: self.schema,
+                element: ty.element(),
+                index: ty.index(),
+                element_size,
+                buf: self.buf,
+                loc: pos,
+                len: ty.fixed_length() as usize,
+            }));
+        }
+        read_value(self.schema, ty.base_type(), ty.index(), self.buf, pos)
+    }
+
+    /// The fields of the struct, in the order they're declared in.
+    pub fn values(&self) -> Vec<(reflection::Field<'s>, DynValue<'s, 'b>)> {
+        let mut fields = self.object.fields().iter().collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.id());
+        fields
+            .into_iter()
+            .filter_map(|field| Some((field, self.get_field(field)?)))
+            .collect()
+    }
+}
+
+impl PartialEq for DynStruct<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object && self.bytes() == other.bytes()
+    }
+}
+
+impl fmt::Debug for DynStruct<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct(short_name(self.object.name()));
+        for (field, value) in self.values() {
+            s.field(field.name(), &value);
+        }
+        s.finish()
+    }
+}
+
+/// A vector, or a fixed-length array in a struct, read from a buffer.
+#[derive(Clone, Copy)]
+pub struct DynVector<'s, 'b> {
+    schema: reflection::Schema<'s>,
+    element: BaseType,
+    index: i32,
+    element_size: usize,
+    buf: &'b [u8],
+    /// The position of the first element.
+    loc: usize,
+    len: usize,
+}
+
+impl<'s, 'b> DynVector<'s, 'b> {
+    /// The type of the elements.
+    pub fn element_type(&self) -> BaseType {
+        self.element
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The element at `idx`, or `None` if `idx` is out of bounds or the elements are unions.
+    pub fn get(&self, idx: usize) -> Option<DynValue<'s, 'b>> {
+        if idx >= self.len {
+            return None;
+        }
+        let pos = self.loc + idx * self.element_size;
+        read_value(self.schema, self.element, self.index, self.buf, pos)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = DynValue<'s, 'b>> + '_ {
+        (0..self.len).filter_map(move |idx| self.get(idx))
+    }
+}
+
+impl PartialEq for DynVector<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl fmt::Debug for DynVector<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// The table held by a union, along with the variant of the union it is.
+#[derive(Clone, Copy, PartialEq)]
+pub struct DynUnion<'s, 'b> {
+    variant: reflection::EnumVal<'s>,
+    table: DynTable<'s, 'b>,
+}
+
+impl<'s, 'b> DynUnion<'s, 'b> {
+    /// The variant of the union.
+    pub fn variant(&self) -> reflection::EnumVal<'s> {
+        self.variant
+    }
+
+    /// The name of the variant of the union.
+    pub fn variant_name(&self) -> &'s str {
+        self.variant.name()
+    }
+
+    /// The table the union holds.
+    pub fn table(&self) -> DynTable<'s, 'b> {
+        self.table
+    }
+}
+
+/// A union is shown as the table it holds.
+impl fmt::Debug for DynUnion<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.table.fmt(f)
+    }
+}
+
+fn find_field<'s>(object: reflection::Object<'s>, name: &str) -> Option<reflection::Field<'s>> {
+    object
+        .fields()
+        .lookup_by_key(name, |field, name| field.key_compare_with_value(name))
+}
+
+/// The variant of the union `index` with the value `variant`, along with the table it holds, or
+/// `None` for `NONE` and variants the schema doesn't know about.
+fn union_variant(
+    schema: reflection::Schema,
+    index: i32,
+    variant: u8,
+) -> Option<(reflection::EnumVal, reflection::Object)> {
+    let value = schema
+        .enums()
+        .get(index as usize)
+        .values()
+        .lookup_by_key(variant as i64, |value, variant| {
+            value.key_compare_with_value(*variant)
+        })?;
+    let ty = value.union_type()?;
+    if ty.base_type() != BaseType::Obj {
+        return None;
+    }
+    Some((value, schema.objects().get(ty.index() as usize)))
+}
+
+/// The name of a type without its namespace.
+fn short_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+/// The size of a scalar, or of the offset to anything else.
+fn scalar_size(base_type: BaseType) -> usize {
+    match base_type {
+        BaseType::UType | BaseType::Bool | BaseType::Byte | BaseType::UByte => 1,
+        BaseType::Short | BaseType::UShort => 2,
+        BaseType::Long | BaseType::ULong | BaseType::Double => 8,
+        _ => 4,
+    }
+}
+
+/// The size of a value of `base_type` stored inline, where `index` is the type of an object.
+fn inline_size(schema: reflection::Schema, base_type: BaseType, index: i32) -> usize {
+    match base_type {
+        BaseType::Obj => {
+            let object = schema.objects().get(index as usize);
+            if object.is_struct() {
+                object.bytesize() as usize
+            } else {
+                SIZE_UOFFSET
+            }
+        }
+        _ => scalar_size(base_type),
+    }
+}
+
+/// The default value of a scalar field missing from a table, if it's a scalar.
+fn default_value<'s, 'b>(
+    base_type: BaseType,
+    field: &reflection::Field,
+) -> Option<DynValue<'s, 'b>> {
+    let integer = field.default_integer();
+    let real = field.default_real();
+    Some(match base_type {
+        BaseType::Bool => DynValue::Bool(integer != 0),
+        BaseType::Byte => DynValue::Byte(integer as i8),
+        BaseType::UType | BaseType::UByte => DynValue::UByte(integer as u8),
+        BaseType::Short => DynValue::Short(integer as i16),
+        BaseType::UShort => DynValue::UShort(integer as u16),
+        BaseType::Int => DynValue::Int(integer as i32),
+        BaseType::UInt => DynValue::UInt(integer as u32),
+        BaseType::Long => DynValue::Long(integer),
+        BaseType::ULong => DynValue::ULong(integer as u64),
+        BaseType::Float => DynValue::Float(real as f32),
+        BaseType::Double => DynValue::Double(real),
+        _ => return None,
+    })
+}
+
+/// Read the value of type `base_type` stored inline at `pos`, where `index` is the object it is,
+/// if any. Vectors and unions need more of their type to be read, and aren't read here.
+fn read_value<'s, 'b>(
+    schema: reflection::Schema<'s>,
+    base_type: BaseType,
+    index: i32,
+    buf: &'b [u8],
+    pos: usize,
+) -> Option<DynValue<'s, 'b>> {
+    Some(match base_type {
+        // read as a byte, as an unverified buffer may hold something other than 0 or 1
+        BaseType::Bool => DynValue::Bool(read_scalar_at::<u8>(buf, pos) != 0),
+        BaseType::Byte => DynValue::Byte(read_scalar_at::<i8>(buf, pos)),
+        BaseType::UType | BaseType::UByte => DynValue::UByte(read_scalar_at::<u8>(buf, pos)),
+        BaseType::Short => DynValue::Short(read_scalar_at::<i16>(buf, pos)),
+        BaseType::UShort => DynValue::UShort(read_scalar_at::<u16>(buf, pos)),
+        BaseType::Int => DynValue::Int(read_scalar_at::<i32>(buf, pos)),
+        BaseType::UInt => DynValue::UInt(read_scalar_at::<u32>(buf, pos)),
+        BaseType::Long => DynValue::Long(read_scalar_at::<i64>(buf, pos)),
+        BaseType::ULong => DynValue::ULong(read_scalar_at::<u64>(buf, pos)),
+        BaseType::Float => DynValue::Float(read_scalar_at::<f32>(buf, pos)),
+        BaseType::Double => DynValue::Double(read_scalar_at::<f64>(buf, pos)),
+        BaseType::String => DynValue::String(<ForwardsUOffset<&str>>::follow(buf, pos)),
+        BaseType::Obj => {
+            let object = schema.objects().get(index as usize);
+            if object.is_struct() {
+                DynValue::Struct(DynStruct {
+                    schema,
+                    object,
+                    buf,
+                    loc: pos,
+                })
+            } else {
+                DynValue::Table(DynTable {
+                    schema,
+                    object,
+                    table: <ForwardsUOffset<Table<'b>>>::follow(buf, pos),
+                })
+            }
+        }
+        _ => return None,
+    })
+}
+
+/// Read the vector of type `ty` the offset at `pos` points to.
+fn read_vector<'s, 'b>(
+    schema: reflection::Schema<'s>,
+    ty: reflection::Type<'s>,
+    buf: &'b [u8],
+    pos: usize,
+) -> DynVector<'s, 'b> {
+    let loc = pos + read_scalar_at::<UOffsetT>(buf, pos) as usize;
+    DynVector {
+        schema,
+        element: ty.element(),
+        index: ty.index(),
+        element_size: inline_size(schema, ty.element(), ty.index()),
+        buf,
+        loc: loc + SIZE_UOFFSET,
+        len: read_scalar_at::<UOffsetT>(buf, loc) as usize,
+    }
+}
+
+/// The alignment of a value of `base_type` stored inline, where `index` is the type of an object.
+fn inline_align(schema: reflection::Schema, base_type: BaseType, index: i32) -> usize {
+    match base_type {
+        BaseType::Obj => {
+            let object = schema.objects().get(index as usize);
+            if object.is_struct() {
+                object.minalign() as usize
+            } else {
+                SIZE_UOFFSET
+            }
+        }
+        _ => scalar_size(base_type),
+    }
+}
+
+fn verify_aligned(v: &Verifier, pos: usize, align: usize) -> Result<(), InvalidFlatbuffer> {
+    match align {
+        2 => v.is_aligned::<u16>(pos),
+        4 => v.is_aligned::<u32>(pos),
+        8 => v.is_aligned::<u64>(pos),
+        _ => Ok(()),
+    }
+}
+
+/// Verify the table of type `object` at `pos`.
+///
+/// Only what's needed to read the table safely is checked, so required fields may be missing,
+/// and a union may have a type without a value or a value without a type.
+fn verify_table(
+    v: &mut Verifier,
+    schema: reflection::Schema,
+    object: reflection::Object,
+    pos: usize,
+) -> Result<(), InvalidFlatbuffer> {
+    let mut table = v.visit_table(pos)?;
+    for field in object.fields() {
+        let pos = match table.deref(field.offset() as VOffsetT) {
+            Some(pos) => pos,
+            None => continue,
+        };
+        let ty = field.r#type();
+        match ty.base_type() {
+            BaseType::Union => {
+                let type_id = field
+                    .id()
+                    .checked_sub(1)
+                    .ok_or(InvalidFlatbuffer::UnionWithoutTypeSlot { position: pos })?;
+                let type_offset = field_index_to_field_offset(type_id as VOffsetT);
+                let variant = match table.deref(type_offset) {
+                    Some(type_pos) => table.verifier.get::<u8>(type_pos)?,
+                    None => continue,
+                };
+                // a table the schema doesn't know about isn't read, so it isn't verified
+                if let Some((_, object)) = union_variant(schema, ty.index(), variant) {
+                    let pos = table.verifier.deref_uoffset(pos)?;
+                    verify_table(table.verifier, schema, object, pos)?;
+                }
+            }
+            BaseType::Vector => verify_vector(table.verifier, schema, ty, pos)?,
+            base_type => verify_value(table.verifier, schema, base_type, ty.index(), pos)?,
+        }
+    }
+    table.finish();
+    Ok(())
+}
+
+/// Verify the vector of type `ty` the offset at `pos` points to.
+fn verify_vector(
+    v: &mut Verifier,
+    schema: reflection::Schema,
+    ty: reflection::Type,
+    pos: usize,
+) -> Result<(), InvalidFlatbuffer> {
+    let (element, index) = (ty.element(), ty.index());
+    let pos = v.deref_uoffset(pos)?;
+    let len = v.get::<UOffsetT>(pos)? as usize;
+    let start = pos + SIZE_UOFFSET;
+    let element_size = inline_size(schema, element, index);
+    let size = len
+        .checked_mul(element_size)
+        .ok_or(InvalidFlatbuffer::ApparentSizeTooLarge)?;
+    verify_aligned(v, start, inline_align(schema, element, index))?;
+    v.range_in_buffer(start, size)?;
+    // other scalars are covered by the range, but strings and tables are elsewhere, and bools and
+    // the bools in structs can only be 0 or 1
+    match element {
+        BaseType::Bool => bool::run_verifier_many(v, start, len),
+        BaseType::String | BaseType::Obj => (0..len)
+            .try_for_each(|i| verify_value(v, schema, element, index, start + i * element_size)),
+        _ => Ok(()),
+    }
+}
+
+/// Verify the value of type `base_type` stored inline at `pos`, where `index` is the object it is,
+/// if any.
+fn verify_value(
+    v: &mut Verifier,
+    schema: reflection::Schema,
+    base_type: BaseType,
+    index: i32,
+    pos: usize,
+) -> Result<(), InvalidFlatbuffer> {
+    match base_type {
+        BaseType::String => <ForwardsUOffset<&str>>::run_verifier(v, pos),
+        BaseType::Obj => {
+            let object = schema.objects().get(index as usize);
+            if object.is_struct() {
+                verify_aligned(v, pos, object.minalign() as usize)?;
+                v.range_in_buffer(pos, object.bytesize() as usize)?;
+                verify_struct_bools(v, schema, object, pos)
+            } else {
+                let pos = v.deref_uoffset(pos)?;
+                verify_table(v, schema, object, pos)
+            }
+        }
+        BaseType::None | BaseType::Vector | BaseType::Union | BaseType::Array => Ok(()),
+        BaseType::Bool => bool::run_verifier(v, pos),
+        scalar => {
+            let size = scalar_size(scalar);
+            verify_aligned(v, pos, size)?;
+            v.range_in_buffer(pos, size)
+        }
+    }
+}
+
+/// Verify that the bools of the struct of type `object` at `pos` are 0 or 1, including those in
+/// the structs and arrays it holds. The struct has already been checked to be in the buffer.
+fn verify_struct_bools(
+    v: &mut Verifier,
+    schema: reflection::Schema,
+    object: reflection::Object,
+    pos: usize,
+) -> Result<(), InvalidFlatbuffer> {
+    for field in object.fields() {
+        let ty = field.r#type();
+        let pos = pos + field.offset() as usize;
+        let length = match ty.base_type() {
+            BaseType::Array => ty.fixed_length() as usize,
+            _ => 1,
+        };
+        match (ty.base_type(), ty.element()) {
+            (BaseType::Bool, _) | (BaseType::Array, BaseType::Bool) => {
+                bool::run_verifier_many(v, pos, length)?
+            }
+            (BaseType::Obj, _) | (BaseType::Array, BaseType::Obj) => {
+                let object = schema.objects().get(ty.index() as usize);
+                for i in 0..length {
+                    let pos = pos + i * object.bytesize() as usize;
+                    verify_struct_bools(v, schema, object, pos)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// The error returned when parsing the name of a variant of an enum fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownVariant {
+    /// The name of the enum.
+    pub enum_name: &'static str,
+
+    /// The name that isn't a variant of it.
+    pub name: String,
+}
+
+impl fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a variant of `{}`",
+            self.name, self.enum_name
+        )
+    }
+}
+
+impl std::error::Error for UnknownVariant {}
+
+/// The value of a union read from a buffer, serialized as the variant of an enum named after the
+/// union, holding the table.
+///
+/// This is how the object type of the union is serialized, so the value can be deserialized as
+/// one.
+#[cfg(feature = "serde")]
+pub struct UnionValue<'a, T> {
+    /// The name of the union.
+    pub union_name: &'static str,
+
+    /// The position of the variant in the union, counting `NONE` as the first.
+    pub variant_index: u32,
+
+    /// The name of the variant.
+    pub variant_name: &'static str,
+
+    /// The table the union holds.
+    pub value: &'a T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for UnionValue<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_variant(
+            self.union_name,
+            self.variant_index,
+            self.variant_name,
+            self.value,
+        )
+    }
+}
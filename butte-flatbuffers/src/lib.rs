@@ -28,29 +28,55 @@
 //! At this time, to generate Rust code, you will need the latest `master` version of `flatc`, available from here: https://github.com/google/flatbuffers
 //! (On OSX, you can install FlatBuffers from `HEAD` with the Homebrew package manager.)
 
+mod array;
 mod builder;
+mod dynamic;
 mod endian_scalar;
+mod enums;
 mod follow;
 mod primitives;
 mod push;
+// Generated by butte, with the object API, from `fbs/reflection.fbs`. Regenerate it from the root
+// of the repository with:
+//
+//     cargo run -p butte -- --object-api \
+//         butte-flatbuffers/fbs/reflection.fbs butte-flatbuffers/src/reflection_generated.rs
+#[rustfmt::skip]
+#[allow(clippy::all)]
+mod reflection_generated;
 mod table;
 mod vector;
+mod verifier;
 mod vtable;
 mod vtable_writer;
 
+// The generated code refers to this crate by the name it's usually imported as.
+extern crate self as flatbuffers;
+
 pub use crate::{
+    array::Array,
     builder::FlatBufferBuilder,
+    dynamic::{DynSchema, DynSchemaError, DynStruct, DynTable, DynUnion, DynValue, DynVector},
     endian_scalar::{
         byte_swap_f32, byte_swap_f64, emplace_scalar, read_scalar, read_scalar_at, EndianScalar,
     },
+    enums::UnknownVariant,
     follow::{Follow, FollowStart},
     primitives::*,
     push::Push,
-    table::{buffer_has_identifier, get_root, get_size_prefixed_root, Table},
+    reflection_generated::reflection,
+    table::{buffer_has_identifier, get_root, get_size_prefixed_root, KeyedTable, Table},
     vector::{follow_cast_ref, SafeSliceAccess, Vector},
+    verifier::{
+        root, root_with_opts, size_prefixed_root, size_prefixed_root_with_opts, InvalidFlatbuffer,
+        TableVerifier, Verifiable, Verifier, VerifierOptions,
+    },
     vtable::field_index_to_field_offset,
 };
 
+#[cfg(feature = "serde")]
+pub use crate::enums::UnionValue;
+
 // TODO(rw): Unify `create_vector` and `create_vector_direct` by using
 //           `Into<Vector<...>>`.
 // TODO(rw): Split fill ops in builder into fill_small, fill_big like in C++.
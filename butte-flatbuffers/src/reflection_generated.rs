@@ -0,0 +1,3535 @@
+pub mod reflection {
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    pub struct BaseType(pub i8);
+    #[allow(non_upper_case_globals)]
+    impl BaseType {
+        pub const None: Self = Self(0_i8);
+        pub const UType: Self = Self(1_i8);
+        pub const Bool: Self = Self(2_i8);
+        pub const Byte: Self = Self(3_i8);
+        pub const UByte: Self = Self(4_i8);
+        pub const Short: Self = Self(5_i8);
+        pub const UShort: Self = Self(6_i8);
+        pub const Int: Self = Self(7_i8);
+        pub const UInt: Self = Self(8_i8);
+        pub const Long: Self = Self(9_i8);
+        pub const ULong: Self = Self(10_i8);
+        pub const Float: Self = Self(11_i8);
+        pub const Double: Self = Self(12_i8);
+        pub const String: Self = Self(13_i8);
+        pub const Vector: Self = Self(14_i8);
+        pub const Obj: Self = Self(15_i8);
+        pub const Union: Self = Self(16_i8);
+        pub const Array: Self = Self(17_i8);
+        pub const Vector64: Self = Self(18_i8);
+        pub const MaxBaseType: Self = Self(19_i8);
+        /// The smallest value of the variants.
+        pub const ENUM_MIN: i8 = 0_i8;
+        /// The largest value of the variants.
+        pub const ENUM_MAX: i8 = 19_i8;
+        /// Every variant, in the order they're declared.
+        pub const ENUM_VALUES: &'static [Self] = &[
+            Self::None,
+            Self::UType,
+            Self::Bool,
+            Self::Byte,
+            Self::UByte,
+            Self::Short,
+            Self::UShort,
+            Self::Int,
+            Self::UInt,
+            Self::Long,
+            Self::ULong,
+            Self::Float,
+            Self::Double,
+            Self::String,
+            Self::Vector,
+            Self::Obj,
+            Self::Union,
+            Self::Array,
+            Self::Vector64,
+            Self::MaxBaseType,
+        ];
+        /// The name of the variant, or `None` if the value isn't one of the variants.
+        pub fn variant_name(self) -> Option<&'static str> {
+            match self {
+                BaseType::None => Some("None"),
+                BaseType::UType => Some("UType"),
+                BaseType::Bool => Some("Bool"),
+                BaseType::Byte => Some("Byte"),
+                BaseType::UByte => Some("UByte"),
+                BaseType::Short => Some("Short"),
+                BaseType::UShort => Some("UShort"),
+                BaseType::Int => Some("Int"),
+                BaseType::UInt => Some("UInt"),
+                BaseType::Long => Some("Long"),
+                BaseType::ULong => Some("ULong"),
+                BaseType::Float => Some("Float"),
+                BaseType::Double => Some("Double"),
+                BaseType::String => Some("String"),
+                BaseType::Vector => Some("Vector"),
+                BaseType::Obj => Some("Obj"),
+                BaseType::Union => Some("Union"),
+                BaseType::Array => Some("Array"),
+                BaseType::Vector64 => Some("Vector64"),
+                BaseType::MaxBaseType => Some("MaxBaseType"),
+                _ => None,
+            }
+        }
+    }
+    impl std::fmt::Debug for BaseType {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self.variant_name() {
+                Some(name) => f.write_str(name),
+                None => f.debug_tuple("BaseType").field(&self.0).finish(),
+            }
+        }
+    }
+    impl std::fmt::Display for BaseType {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self.variant_name() {
+                Some(name) => f.write_str(name),
+                None => write!(f, "{}", self.0),
+            }
+        }
+    }
+    impl std::str::FromStr for BaseType {
+        type Err = flatbuffers::UnknownVariant;
+        /// Parse the name of a variant.
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "None" => Ok(BaseType::None),
+                "UType" => Ok(BaseType::UType),
+                "Bool" => Ok(BaseType::Bool),
+                "Byte" => Ok(BaseType::Byte),
+                "UByte" => Ok(BaseType::UByte),
+                "Short" => Ok(BaseType::Short),
+                "UShort" => Ok(BaseType::UShort),
+                "Int" => Ok(BaseType::Int),
+                "UInt" => Ok(BaseType::UInt),
+                "Long" => Ok(BaseType::Long),
+                "ULong" => Ok(BaseType::ULong),
+                "Float" => Ok(BaseType::Float),
+                "Double" => Ok(BaseType::Double),
+                "String" => Ok(BaseType::String),
+                "Vector" => Ok(BaseType::Vector),
+                "Obj" => Ok(BaseType::Obj),
+                "Union" => Ok(BaseType::Union),
+                "Array" => Ok(BaseType::Array),
+                "Vector64" => Ok(BaseType::Vector64),
+                "MaxBaseType" => Ok(BaseType::MaxBaseType),
+                _ => Err(flatbuffers::UnknownVariant {
+                    enum_name: "BaseType",
+                    name: s.to_string(),
+                }),
+            }
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for BaseType {
+        type Inner = Self;
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            flatbuffers::read_scalar_at::<Self>(buf, loc)
+        }
+    }
+    impl flatbuffers::EndianScalar for BaseType {
+        #[inline]
+        fn to_little_endian(self) -> Self {
+            Self(i8::to_le(self.0))
+        }
+        #[inline]
+        fn from_little_endian(self) -> Self {
+            Self(i8::from_le(self.0))
+        }
+    }
+    impl flatbuffers::Push for BaseType {
+        type Output = Self;
+        #[inline]
+        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+            flatbuffers::emplace_scalar::<Self>(dst, *self);
+        }
+    }
+    impl flatbuffers::Verifiable for BaseType {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            let value = v.get::<Self>(pos)?;
+            if Self::ENUM_VALUES.contains(&value) || !v.rejects_unknown_enum_values() {
+                Ok(())
+            } else {
+                Err(flatbuffers::InvalidFlatbuffer::UnknownEnumValue {
+                    enum_name: "BaseType",
+                    position: pos,
+                })
+            }
+        }
+    }
+    /// The name of the variant `e` is, or an empty string if it isn't one of the variants.
+    pub fn enum_name_base_type(e: BaseType) -> &'static str {
+        e.variant_name().unwrap_or("")
+    }
+    pub enum TypeOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct Type<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for Type<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> Type<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TypeArgs,
+        ) -> flatbuffers::WIPOffset<Type<'bldr>> {
+            let mut builder = TypeBuilder::new(fbb);
+            builder.add_base_type(args.base_type);
+            builder.add_element(args.element);
+            builder.add_index(args.index);
+            builder.add_fixed_length(args.fixed_length);
+            builder.add_base_size(args.base_size);
+            builder.add_element_size(args.element_size);
+            builder.finish()
+        }
+        pub const VT_BASE_TYPE: flatbuffers::VOffsetT = 4i16;
+        pub const VT_ELEMENT: flatbuffers::VOffsetT = 6i16;
+        pub const VT_INDEX: flatbuffers::VOffsetT = 8i16;
+        pub const VT_FIXED_LENGTH: flatbuffers::VOffsetT = 10i16;
+        pub const VT_BASE_SIZE: flatbuffers::VOffsetT = 12i16;
+        pub const VT_ELEMENT_SIZE: flatbuffers::VOffsetT = 14i16;
+        #[inline]
+        pub fn base_type(&self) -> BaseType {
+            self.table
+                .get::<BaseType>(Type::VT_BASE_TYPE, Some(BaseType::None))
+                .unwrap()
+        }
+        #[inline]
+        pub fn element(&self) -> BaseType {
+            self.table
+                .get::<BaseType>(Type::VT_ELEMENT, Some(BaseType::None))
+                .unwrap()
+        }
+        #[inline]
+        pub fn index(&self) -> i32 {
+            self.table.get::<i32>(Type::VT_INDEX, Some(-1_i32)).unwrap()
+        }
+        #[inline]
+        pub fn fixed_length(&self) -> u16 {
+            self.table
+                .get::<u16>(Type::VT_FIXED_LENGTH, Some(0_u16))
+                .unwrap()
+        }
+        #[inline]
+        pub fn base_size(&self) -> u32 {
+            self.table
+                .get::<u32>(Type::VT_BASE_SIZE, Some(4_u32))
+                .unwrap()
+        }
+        #[inline]
+        pub fn element_size(&self) -> u32 {
+            self.table
+                .get::<u32>(Type::VT_ELEMENT_SIZE, Some(0_u32))
+                .unwrap()
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for Type<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for Type<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Type");
+            ds.field("base_type", &self.base_type());
+            ds.field("element", &self.element());
+            ds.field("index", &self.index());
+            ds.field("fixed_length", &self.fixed_length());
+            ds.field("base_size", &self.base_size());
+            ds.field("element_size", &self.element_size());
+            ds.finish()
+        }
+    }
+    impl flatbuffers::Verifiable for Type<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<BaseType>("base_type", Self::VT_BASE_TYPE, false)?
+                .visit_field::<BaseType>("element", Self::VT_ELEMENT, false)?
+                .visit_field::<i32>("index", Self::VT_INDEX, false)?
+                .visit_field::<u16>("fixed_length", Self::VT_FIXED_LENGTH, false)?
+                .visit_field::<u32>("base_size", Self::VT_BASE_SIZE, false)?
+                .visit_field::<u32>("element_size", Self::VT_ELEMENT_SIZE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TypeArgs {
+        pub base_type: BaseType,
+        pub element: BaseType,
+        pub index: i32,
+        pub fixed_length: u16,
+        pub base_size: u32,
+        pub element_size: u32,
+    }
+    impl Default for TypeArgs {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                base_type: BaseType::None,
+                element: BaseType::None,
+                index: -1_i32,
+                fixed_length: 0_u16,
+                base_size: 4_u32,
+                element_size: 0_u32,
+            }
+        }
+    }
+    pub struct TypeBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TypeBuilder<'a, 'b> {
+        #[inline]
+        fn add_base_type(&mut self, base_type: BaseType) {
+            self.fbb
+                .push_slot::<BaseType>(Type::VT_BASE_TYPE, base_type, BaseType::None);
+        }
+        #[inline]
+        fn add_element(&mut self, element: BaseType) {
+            self.fbb
+                .push_slot::<BaseType>(Type::VT_ELEMENT, element, BaseType::None);
+        }
+        #[inline]
+        fn add_index(&mut self, index: i32) {
+            self.fbb.push_slot::<i32>(Type::VT_INDEX, index, -1_i32);
+        }
+        #[inline]
+        fn add_fixed_length(&mut self, fixed_length: u16) {
+            self.fbb
+                .push_slot::<u16>(Type::VT_FIXED_LENGTH, fixed_length, 0_u16);
+        }
+        #[inline]
+        fn add_base_size(&mut self, base_size: u32) {
+            self.fbb
+                .push_slot::<u32>(Type::VT_BASE_SIZE, base_size, 4_u32);
+        }
+        #[inline]
+        fn add_element_size(&mut self, element_size: u32) {
+            self.fbb
+                .push_slot::<u32>(Type::VT_ELEMENT_SIZE, element_size, 0_u32);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            TypeBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Type<'a>> {
+            let o = self.fbb.end_table(self.start);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TypeT {
+        pub base_type: BaseType,
+        pub element: BaseType,
+        pub index: i32,
+        pub fixed_length: u16,
+        pub base_size: u32,
+        pub element_size: u32,
+    }
+    impl Default for TypeT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                base_type: BaseType::None,
+                element: BaseType::None,
+                index: -1_i32,
+                fixed_length: 0_u16,
+                base_size: 4_u32,
+                element_size: 0_u32,
+            }
+        }
+    }
+    impl TypeT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<Type<'b>> {
+            Type::create(
+                fbb,
+                &TypeArgs {
+                    base_type: self.base_type,
+                    element: self.element,
+                    index: self.index,
+                    fixed_length: self.fixed_length,
+                    base_size: self.base_size,
+                    element_size: self.element_size,
+                },
+            )
+        }
+    }
+    impl Type<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> TypeT {
+            TypeT {
+                base_type: self.base_type(),
+                element: self.element(),
+                index: self.index(),
+                fixed_length: self.fixed_length(),
+                base_size: self.base_size(),
+                element_size: self.element_size(),
+            }
+        }
+    }
+    pub enum KeyValueOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct KeyValue<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for KeyValue<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> KeyValue<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args KeyValueArgs<'args>,
+        ) -> flatbuffers::WIPOffset<KeyValue<'bldr>> {
+            let mut builder = KeyValueBuilder::new(fbb);
+            if let Some(x) = args.key {
+                builder.add_key(x);
+            }
+            if let Some(x) = args.value {
+                builder.add_value(x);
+            }
+            builder.finish()
+        }
+        pub const VT_KEY: flatbuffers::VOffsetT = 4i16;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 6i16;
+        #[inline]
+        pub fn key(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(KeyValue::VT_KEY, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn value(&self) -> Option<&'a str> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(KeyValue::VT_VALUE, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for KeyValue<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for KeyValue<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("KeyValue");
+            ds.field("key", &self.key());
+            if let Some(x) = self.value() {
+                ds.field("value", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl KeyValue<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &KeyValue) -> bool {
+            self.key() < other.key()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.key();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for KeyValue<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            KeyValue::from(a).key_compare_less_than(&KeyValue::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for KeyValue<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct KeyValueArgs<'a> {
+        pub key: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for KeyValueArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                key: None,
+                value: None,
+            }
+        }
+    }
+    pub struct KeyValueBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> KeyValueBuilder<'a, 'b> {
+        #[inline]
+        fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(KeyValue::VT_KEY, key);
+        }
+        #[inline]
+        fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(KeyValue::VT_VALUE, value);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            KeyValueBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<KeyValue<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, KeyValue::VT_KEY, "key");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct KeyValueT {
+        pub key: String,
+        pub value: Option<String>,
+    }
+    impl Default for KeyValueT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                key: Default::default(),
+                value: None,
+            }
+        }
+    }
+    impl KeyValueT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<KeyValue<'b>> {
+            let key = Some({
+                let x = &self.key;
+                fbb.create_string(x)
+            });
+            let value = self.value.as_ref().map(|x| fbb.create_string(x));
+            KeyValue::create(fbb, &KeyValueArgs { key, value })
+        }
+    }
+    impl KeyValue<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> KeyValueT {
+            KeyValueT {
+                key: {
+                    let x = self.key();
+                    x.to_string()
+                },
+                value: self.value().map(|x| x.to_string()),
+            }
+        }
+    }
+    pub enum EnumValOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct EnumVal<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for EnumVal<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> EnumVal<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args EnumValArgs<'args>,
+        ) -> flatbuffers::WIPOffset<EnumVal<'bldr>> {
+            let mut builder = EnumValBuilder::new(fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_value(args.value);
+            if let Some(x) = args.union_type {
+                builder.add_union_type(x);
+            }
+            if let Some(x) = args.documentation {
+                builder.add_documentation(x);
+            }
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            builder.finish()
+        }
+        pub const VT_NAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 6i16;
+        pub const VT_UNION_TYPE: flatbuffers::VOffsetT = 10i16;
+        pub const VT_DOCUMENTATION: flatbuffers::VOffsetT = 12i16;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 14i16;
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(EnumVal::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn value(&self) -> i64 {
+            self.table
+                .get::<i64>(EnumVal::VT_VALUE, Some(0_i64))
+                .unwrap()
+        }
+        #[inline]
+        pub fn union_type(&self) -> Option<Type<'a>> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<Type<'a>>>(EnumVal::VT_UNION_TYPE, None)
+        }
+        #[inline]
+        pub fn documentation(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(EnumVal::VT_DOCUMENTATION, None)
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >>(EnumVal::VT_ATTRIBUTES, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for EnumVal<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for EnumVal<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("EnumVal");
+            ds.field("name", &self.name());
+            ds.field("value", &self.value());
+            if let Some(x) = self.union_type() {
+                ds.field("union_type", &x);
+            }
+            if let Some(x) = self.documentation() {
+                ds.field("documentation", &x);
+            }
+            if let Some(x) = self.attributes() {
+                ds.field("attributes", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl EnumVal<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &EnumVal) -> bool {
+            self.value() < other.value()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: i64) -> std::cmp::Ordering {
+            let key = self.value();
+            key.cmp(&val)
+        }
+    }
+    impl flatbuffers::KeyedTable for EnumVal<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            EnumVal::from(a).key_compare_less_than(&EnumVal::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for EnumVal<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<i64>("value", Self::VT_VALUE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<Type<'_>>>(
+                    "union_type",
+                    Self::VT_UNION_TYPE,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("documentation", Self::VT_DOCUMENTATION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct EnumValArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub value: i64,
+        pub union_type: Option<flatbuffers::WIPOffset<Type<'a>>>,
+        pub documentation: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for EnumValArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: None,
+                value: 0_i64,
+                union_type: None,
+                documentation: None,
+                attributes: None,
+            }
+        }
+    }
+    pub struct EnumValBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> EnumValBuilder<'a, 'b> {
+        #[inline]
+        fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(EnumVal::VT_NAME, name);
+        }
+        #[inline]
+        fn add_value(&mut self, value: i64) {
+            self.fbb.push_slot::<i64>(EnumVal::VT_VALUE, value, 0_i64);
+        }
+        #[inline]
+        fn add_union_type(&mut self, union_type: flatbuffers::WIPOffset<Type<'b>>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<Type<'_>>>(
+                    EnumVal::VT_UNION_TYPE,
+                    union_type,
+                );
+        }
+        #[inline]
+        fn add_documentation(
+            &mut self,
+            documentation: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(EnumVal::VT_DOCUMENTATION, documentation);
+        }
+        #[inline]
+        fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<KeyValue<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+            >>(EnumVal::VT_ATTRIBUTES, attributes);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            EnumValBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<EnumVal<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, EnumVal::VT_NAME, "name");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct EnumValT {
+        pub name: String,
+        pub value: i64,
+        pub union_type: Option<Box<TypeT>>,
+        pub documentation: Option<Vec<String>>,
+        pub attributes: Option<Vec<KeyValueT>>,
+    }
+    impl Default for EnumValT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                value: 0_i64,
+                union_type: None,
+                documentation: None,
+                attributes: None,
+            }
+        }
+    }
+    impl EnumValT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<EnumVal<'b>> {
+            let name = Some({
+                let x = &self.name;
+                fbb.create_string(x)
+            });
+            let union_type = self.union_type.as_ref().map(|x| x.pack(fbb));
+            let documentation = self.documentation.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let attributes = self.attributes.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            EnumVal::create(
+                fbb,
+                &EnumValArgs {
+                    name,
+                    value: self.value,
+                    union_type,
+                    documentation,
+                    attributes,
+                },
+            )
+        }
+    }
+    impl EnumVal<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> EnumValT {
+            EnumValT {
+                name: {
+                    let x = self.name();
+                    x.to_string()
+                },
+                value: self.value(),
+                union_type: self.union_type().map(|x| Box::new(x.unpack())),
+                documentation: self
+                    .documentation()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+                attributes: self
+                    .attributes()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+            }
+        }
+    }
+    pub enum EnumOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct Enum<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for Enum<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> Enum<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args EnumArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Enum<'bldr>> {
+            let mut builder = EnumBuilder::new(fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            if let Some(x) = args.values {
+                builder.add_values(x);
+            }
+            builder.add_is_union(args.is_union);
+            if let Some(x) = args.underlying_type {
+                builder.add_underlying_type(x);
+            }
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            if let Some(x) = args.documentation {
+                builder.add_documentation(x);
+            }
+            if let Some(x) = args.declaration_file {
+                builder.add_declaration_file(x);
+            }
+            builder.finish()
+        }
+        pub const VT_NAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_VALUES: flatbuffers::VOffsetT = 6i16;
+        pub const VT_IS_UNION: flatbuffers::VOffsetT = 8i16;
+        pub const VT_UNDERLYING_TYPE: flatbuffers::VOffsetT = 10i16;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 12i16;
+        pub const VT_DOCUMENTATION: flatbuffers::VOffsetT = 14i16;
+        pub const VT_DECLARATION_FILE: flatbuffers::VOffsetT = 16i16;
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Enum::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn values(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnumVal<'a>>> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnumVal<'a>>>,
+                >>(Enum::VT_VALUES, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn is_union(&self) -> bool {
+            self.table
+                .get::<bool>(Enum::VT_IS_UNION, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn underlying_type(&self) -> Type<'a> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<Type<'a>>>(Enum::VT_UNDERLYING_TYPE, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >>(Enum::VT_ATTRIBUTES, None)
+        }
+        #[inline]
+        pub fn documentation(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(Enum::VT_DOCUMENTATION, None)
+        }
+        #[inline]
+        pub fn declaration_file(&self) -> Option<&'a str> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Enum::VT_DECLARATION_FILE, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for Enum<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for Enum<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Enum");
+            ds.field("name", &self.name());
+            ds.field("values", &self.values());
+            ds.field("is_union", &self.is_union());
+            ds.field("underlying_type", &self.underlying_type());
+            if let Some(x) = self.attributes() {
+                ds.field("attributes", &x);
+            }
+            if let Some(x) = self.documentation() {
+                ds.field("documentation", &x);
+            }
+            if let Some(x) = self.declaration_file() {
+                ds.field("declaration_file", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl Enum<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &Enum) -> bool {
+            self.name() < other.name()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.name();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for Enum<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            Enum::from(a).key_compare_less_than(&Enum::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for Enum<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EnumVal<'_>>>,
+                >>("values", Self::VT_VALUES, true)?
+                .visit_field::<bool>("is_union", Self::VT_IS_UNION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<Type<'_>>>(
+                    "underlying_type",
+                    Self::VT_UNDERLYING_TYPE,
+                    true,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("documentation", Self::VT_DOCUMENTATION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "declaration_file",
+                    Self::VT_DECLARATION_FILE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct EnumArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub values: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EnumVal<'a>>>,
+            >,
+        >,
+        pub is_union: bool,
+        pub underlying_type: Option<flatbuffers::WIPOffset<Type<'a>>>,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >,
+        >,
+        pub documentation: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub declaration_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for EnumArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: None,
+                values: None,
+                is_union: false,
+                underlying_type: None,
+                attributes: None,
+                documentation: None,
+                declaration_file: None,
+            }
+        }
+    }
+    pub struct EnumBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> EnumBuilder<'a, 'b> {
+        #[inline]
+        fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(Enum::VT_NAME, name);
+        }
+        #[inline]
+        fn add_values(
+            &mut self,
+            values: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<EnumVal<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EnumVal<'_>>>,
+            >>(Enum::VT_VALUES, values);
+        }
+        #[inline]
+        fn add_is_union(&mut self, is_union: bool) {
+            self.fbb
+                .push_slot::<bool>(Enum::VT_IS_UNION, is_union, false);
+        }
+        #[inline]
+        fn add_underlying_type(&mut self, underlying_type: flatbuffers::WIPOffset<Type<'b>>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<Type<'_>>>(
+                    Enum::VT_UNDERLYING_TYPE,
+                    underlying_type,
+                );
+        }
+        #[inline]
+        fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<KeyValue<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+            >>(Enum::VT_ATTRIBUTES, attributes);
+        }
+        #[inline]
+        fn add_documentation(
+            &mut self,
+            documentation: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(Enum::VT_DOCUMENTATION, documentation);
+        }
+        #[inline]
+        fn add_declaration_file(&mut self, declaration_file: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(
+                    Enum::VT_DECLARATION_FILE,
+                    declaration_file,
+                );
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            EnumBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Enum<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, Enum::VT_NAME, "name");
+            self.fbb.required(o, Enum::VT_VALUES, "values");
+            self.fbb
+                .required(o, Enum::VT_UNDERLYING_TYPE, "underlying_type");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct EnumT {
+        pub name: String,
+        pub values: Vec<EnumValT>,
+        pub is_union: bool,
+        pub underlying_type: Box<TypeT>,
+        pub attributes: Option<Vec<KeyValueT>>,
+        pub documentation: Option<Vec<String>>,
+        pub declaration_file: Option<String>,
+    }
+    impl Default for EnumT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                values: Default::default(),
+                is_union: false,
+                underlying_type: Default::default(),
+                attributes: None,
+                documentation: None,
+                declaration_file: None,
+            }
+        }
+    }
+    impl EnumT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<Enum<'b>> {
+            let name = Some({
+                let x = &self.name;
+                fbb.create_string(x)
+            });
+            let values = Some({
+                let x = &self.values;
+                {
+                    let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                    fbb.create_vector(&x)
+                }
+            });
+            let underlying_type = Some({
+                let x = &self.underlying_type;
+                x.pack(fbb)
+            });
+            let attributes = self.attributes.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let documentation = self.documentation.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let declaration_file = self.declaration_file.as_ref().map(|x| fbb.create_string(x));
+            Enum::create(
+                fbb,
+                &EnumArgs {
+                    name,
+                    values,
+                    is_union: self.is_union,
+                    underlying_type,
+                    attributes,
+                    documentation,
+                    declaration_file,
+                },
+            )
+        }
+    }
+    impl Enum<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> EnumT {
+            EnumT {
+                name: {
+                    let x = self.name();
+                    x.to_string()
+                },
+                values: {
+                    let x = self.values();
+                    x.iter().map(|x| x.unpack()).collect()
+                },
+                is_union: self.is_union(),
+                underlying_type: {
+                    let x = self.underlying_type();
+                    Box::new(x.unpack())
+                },
+                attributes: self
+                    .attributes()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+                documentation: self
+                    .documentation()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+                declaration_file: self.declaration_file().map(|x| x.to_string()),
+            }
+        }
+    }
+    pub enum FieldOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct Field<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for Field<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> Field<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args FieldArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Field<'bldr>> {
+            let mut builder = FieldBuilder::new(fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            if let Some(x) = args.r#type {
+                builder.add_type(x);
+            }
+            builder.add_id(args.id);
+            builder.add_offset(args.offset);
+            builder.add_default_integer(args.default_integer);
+            builder.add_default_real(args.default_real);
+            builder.add_deprecated(args.deprecated);
+            builder.add_required(args.required);
+            builder.add_key(args.key);
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            if let Some(x) = args.documentation {
+                builder.add_documentation(x);
+            }
+            builder.add_optional(args.optional);
+            builder.add_padding(args.padding);
+            builder.add_offset64(args.offset64);
+            builder.finish()
+        }
+        pub const VT_NAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_TYPE: flatbuffers::VOffsetT = 6i16;
+        pub const VT_ID: flatbuffers::VOffsetT = 8i16;
+        pub const VT_OFFSET: flatbuffers::VOffsetT = 10i16;
+        pub const VT_DEFAULT_INTEGER: flatbuffers::VOffsetT = 12i16;
+        pub const VT_DEFAULT_REAL: flatbuffers::VOffsetT = 14i16;
+        pub const VT_DEPRECATED: flatbuffers::VOffsetT = 16i16;
+        pub const VT_REQUIRED: flatbuffers::VOffsetT = 18i16;
+        pub const VT_KEY: flatbuffers::VOffsetT = 20i16;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 22i16;
+        pub const VT_DOCUMENTATION: flatbuffers::VOffsetT = 24i16;
+        pub const VT_OPTIONAL: flatbuffers::VOffsetT = 26i16;
+        pub const VT_PADDING: flatbuffers::VOffsetT = 28i16;
+        pub const VT_OFFSET64: flatbuffers::VOffsetT = 30i16;
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Field::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn r#type(&self) -> Type<'a> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<Type<'a>>>(Field::VT_TYPE, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn id(&self) -> u16 {
+            self.table.get::<u16>(Field::VT_ID, Some(0_u16)).unwrap()
+        }
+        #[inline]
+        pub fn offset(&self) -> u16 {
+            self.table
+                .get::<u16>(Field::VT_OFFSET, Some(0_u16))
+                .unwrap()
+        }
+        #[inline]
+        pub fn default_integer(&self) -> i64 {
+            self.table
+                .get::<i64>(Field::VT_DEFAULT_INTEGER, Some(0_i64))
+                .unwrap()
+        }
+        #[inline]
+        pub fn default_real(&self) -> f64 {
+            self.table
+                .get::<f64>(Field::VT_DEFAULT_REAL, Some(0.0_f64))
+                .unwrap()
+        }
+        #[inline]
+        pub fn deprecated(&self) -> bool {
+            self.table
+                .get::<bool>(Field::VT_DEPRECATED, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn required(&self) -> bool {
+            self.table
+                .get::<bool>(Field::VT_REQUIRED, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn key(&self) -> bool {
+            self.table.get::<bool>(Field::VT_KEY, Some(false)).unwrap()
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >>(Field::VT_ATTRIBUTES, None)
+        }
+        #[inline]
+        pub fn documentation(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(Field::VT_DOCUMENTATION, None)
+        }
+        #[inline]
+        pub fn optional(&self) -> bool {
+            self.table
+                .get::<bool>(Field::VT_OPTIONAL, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn padding(&self) -> u16 {
+            self.table
+                .get::<u16>(Field::VT_PADDING, Some(0_u16))
+                .unwrap()
+        }
+        #[inline]
+        pub fn offset64(&self) -> bool {
+            self.table
+                .get::<bool>(Field::VT_OFFSET64, Some(false))
+                .unwrap()
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for Field<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for Field<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Field");
+            ds.field("name", &self.name());
+            ds.field("type", &self.r#type());
+            ds.field("id", &self.id());
+            ds.field("offset", &self.offset());
+            ds.field("default_integer", &self.default_integer());
+            ds.field("default_real", &self.default_real());
+            ds.field("deprecated", &self.deprecated());
+            ds.field("required", &self.required());
+            ds.field("key", &self.key());
+            if let Some(x) = self.attributes() {
+                ds.field("attributes", &x);
+            }
+            if let Some(x) = self.documentation() {
+                ds.field("documentation", &x);
+            }
+            ds.field("optional", &self.optional());
+            ds.field("padding", &self.padding());
+            ds.field("offset64", &self.offset64());
+            ds.finish()
+        }
+    }
+    impl Field<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &Field) -> bool {
+            self.name() < other.name()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.name();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for Field<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            Field::from(a).key_compare_less_than(&Field::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for Field<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<Type<'_>>>("type", Self::VT_TYPE, true)?
+                .visit_field::<u16>("id", Self::VT_ID, false)?
+                .visit_field::<u16>("offset", Self::VT_OFFSET, false)?
+                .visit_field::<i64>("default_integer", Self::VT_DEFAULT_INTEGER, false)?
+                .visit_field::<f64>("default_real", Self::VT_DEFAULT_REAL, false)?
+                .visit_field::<bool>("deprecated", Self::VT_DEPRECATED, false)?
+                .visit_field::<bool>("required", Self::VT_REQUIRED, false)?
+                .visit_field::<bool>("key", Self::VT_KEY, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("documentation", Self::VT_DOCUMENTATION, false)?
+                .visit_field::<bool>("optional", Self::VT_OPTIONAL, false)?
+                .visit_field::<u16>("padding", Self::VT_PADDING, false)?
+                .visit_field::<bool>("offset64", Self::VT_OFFSET64, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct FieldArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub r#type: Option<flatbuffers::WIPOffset<Type<'a>>>,
+        pub id: u16,
+        pub offset: u16,
+        pub default_integer: i64,
+        pub default_real: f64,
+        pub deprecated: bool,
+        pub required: bool,
+        pub key: bool,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >,
+        >,
+        pub documentation: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub optional: bool,
+        pub padding: u16,
+        pub offset64: bool,
+    }
+    impl<'a> Default for FieldArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: None,
+                r#type: None,
+                id: 0_u16,
+                offset: 0_u16,
+                default_integer: 0_i64,
+                default_real: 0.0_f64,
+                deprecated: false,
+                required: false,
+                key: false,
+                attributes: None,
+                documentation: None,
+                optional: false,
+                padding: 0_u16,
+                offset64: false,
+            }
+        }
+    }
+    pub struct FieldBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> FieldBuilder<'a, 'b> {
+        #[inline]
+        fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(Field::VT_NAME, name);
+        }
+        #[inline]
+        fn add_type(&mut self, r#type: flatbuffers::WIPOffset<Type<'b>>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<Type<'_>>>(Field::VT_TYPE, r#type);
+        }
+        #[inline]
+        fn add_id(&mut self, id: u16) {
+            self.fbb.push_slot::<u16>(Field::VT_ID, id, 0_u16);
+        }
+        #[inline]
+        fn add_offset(&mut self, offset: u16) {
+            self.fbb.push_slot::<u16>(Field::VT_OFFSET, offset, 0_u16);
+        }
+        #[inline]
+        fn add_default_integer(&mut self, default_integer: i64) {
+            self.fbb
+                .push_slot::<i64>(Field::VT_DEFAULT_INTEGER, default_integer, 0_i64);
+        }
+        #[inline]
+        fn add_default_real(&mut self, default_real: f64) {
+            self.fbb
+                .push_slot::<f64>(Field::VT_DEFAULT_REAL, default_real, 0.0_f64);
+        }
+        #[inline]
+        fn add_deprecated(&mut self, deprecated: bool) {
+            self.fbb
+                .push_slot::<bool>(Field::VT_DEPRECATED, deprecated, false);
+        }
+        #[inline]
+        fn add_required(&mut self, required: bool) {
+            self.fbb
+                .push_slot::<bool>(Field::VT_REQUIRED, required, false);
+        }
+        #[inline]
+        fn add_key(&mut self, key: bool) {
+            self.fbb.push_slot::<bool>(Field::VT_KEY, key, false);
+        }
+        #[inline]
+        fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<KeyValue<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+            >>(Field::VT_ATTRIBUTES, attributes);
+        }
+        #[inline]
+        fn add_documentation(
+            &mut self,
+            documentation: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(Field::VT_DOCUMENTATION, documentation);
+        }
+        #[inline]
+        fn add_optional(&mut self, optional: bool) {
+            self.fbb
+                .push_slot::<bool>(Field::VT_OPTIONAL, optional, false);
+        }
+        #[inline]
+        fn add_padding(&mut self, padding: u16) {
+            self.fbb.push_slot::<u16>(Field::VT_PADDING, padding, 0_u16);
+        }
+        #[inline]
+        fn add_offset64(&mut self, offset64: bool) {
+            self.fbb
+                .push_slot::<bool>(Field::VT_OFFSET64, offset64, false);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            FieldBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Field<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, Field::VT_NAME, "name");
+            self.fbb.required(o, Field::VT_TYPE, "type");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FieldT {
+        pub name: String,
+        pub r#type: Box<TypeT>,
+        pub id: u16,
+        pub offset: u16,
+        pub default_integer: i64,
+        pub default_real: f64,
+        pub deprecated: bool,
+        pub required: bool,
+        pub key: bool,
+        pub attributes: Option<Vec<KeyValueT>>,
+        pub documentation: Option<Vec<String>>,
+        pub optional: bool,
+        pub padding: u16,
+        pub offset64: bool,
+    }
+    impl Default for FieldT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                r#type: Default::default(),
+                id: 0_u16,
+                offset: 0_u16,
+                default_integer: 0_i64,
+                default_real: 0.0_f64,
+                deprecated: false,
+                required: false,
+                key: false,
+                attributes: None,
+                documentation: None,
+                optional: false,
+                padding: 0_u16,
+                offset64: false,
+            }
+        }
+    }
+    impl FieldT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<Field<'b>> {
+            let name = Some({
+                let x = &self.name;
+                fbb.create_string(x)
+            });
+            let r#type = Some({
+                let x = &self.r#type;
+                x.pack(fbb)
+            });
+            let attributes = self.attributes.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let documentation = self.documentation.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            Field::create(
+                fbb,
+                &FieldArgs {
+                    name,
+                    r#type,
+                    id: self.id,
+                    offset: self.offset,
+                    default_integer: self.default_integer,
+                    default_real: self.default_real,
+                    deprecated: self.deprecated,
+                    required: self.required,
+                    key: self.key,
+                    attributes,
+                    documentation,
+                    optional: self.optional,
+                    padding: self.padding,
+                    offset64: self.offset64,
+                },
+            )
+        }
+    }
+    impl Field<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> FieldT {
+            FieldT {
+                name: {
+                    let x = self.name();
+                    x.to_string()
+                },
+                r#type: {
+                    let x = self.r#type();
+                    Box::new(x.unpack())
+                },
+                id: self.id(),
+                offset: self.offset(),
+                default_integer: self.default_integer(),
+                default_real: self.default_real(),
+                deprecated: self.deprecated(),
+                required: self.required(),
+                key: self.key(),
+                attributes: self
+                    .attributes()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+                documentation: self
+                    .documentation()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+                optional: self.optional(),
+                padding: self.padding(),
+                offset64: self.offset64(),
+            }
+        }
+    }
+    pub enum ObjectOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct Object<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for Object<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> Object<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ObjectArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Object<'bldr>> {
+            let mut builder = ObjectBuilder::new(fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            if let Some(x) = args.fields {
+                builder.add_fields(x);
+            }
+            builder.add_is_struct(args.is_struct);
+            builder.add_minalign(args.minalign);
+            builder.add_bytesize(args.bytesize);
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            if let Some(x) = args.documentation {
+                builder.add_documentation(x);
+            }
+            if let Some(x) = args.declaration_file {
+                builder.add_declaration_file(x);
+            }
+            builder.finish()
+        }
+        pub const VT_NAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_FIELDS: flatbuffers::VOffsetT = 6i16;
+        pub const VT_IS_STRUCT: flatbuffers::VOffsetT = 8i16;
+        pub const VT_MINALIGN: flatbuffers::VOffsetT = 10i16;
+        pub const VT_BYTESIZE: flatbuffers::VOffsetT = 12i16;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 14i16;
+        pub const VT_DOCUMENTATION: flatbuffers::VOffsetT = 16i16;
+        pub const VT_DECLARATION_FILE: flatbuffers::VOffsetT = 18i16;
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Object::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn fields(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Field<'a>>> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Field<'a>>>,
+                >>(Object::VT_FIELDS, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn is_struct(&self) -> bool {
+            self.table
+                .get::<bool>(Object::VT_IS_STRUCT, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn minalign(&self) -> i32 {
+            self.table
+                .get::<i32>(Object::VT_MINALIGN, Some(0_i32))
+                .unwrap()
+        }
+        #[inline]
+        pub fn bytesize(&self) -> i32 {
+            self.table
+                .get::<i32>(Object::VT_BYTESIZE, Some(0_i32))
+                .unwrap()
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >>(Object::VT_ATTRIBUTES, None)
+        }
+        #[inline]
+        pub fn documentation(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(Object::VT_DOCUMENTATION, None)
+        }
+        #[inline]
+        pub fn declaration_file(&self) -> Option<&'a str> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Object::VT_DECLARATION_FILE, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for Object<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for Object<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Object");
+            ds.field("name", &self.name());
+            ds.field("fields", &self.fields());
+            ds.field("is_struct", &self.is_struct());
+            ds.field("minalign", &self.minalign());
+            ds.field("bytesize", &self.bytesize());
+            if let Some(x) = self.attributes() {
+                ds.field("attributes", &x);
+            }
+            if let Some(x) = self.documentation() {
+                ds.field("documentation", &x);
+            }
+            if let Some(x) = self.declaration_file() {
+                ds.field("declaration_file", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl Object<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &Object) -> bool {
+            self.name() < other.name()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.name();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for Object<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            Object::from(a).key_compare_less_than(&Object::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for Object<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Field<'_>>>,
+                >>("fields", Self::VT_FIELDS, true)?
+                .visit_field::<bool>("is_struct", Self::VT_IS_STRUCT, false)?
+                .visit_field::<i32>("minalign", Self::VT_MINALIGN, false)?
+                .visit_field::<i32>("bytesize", Self::VT_BYTESIZE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("documentation", Self::VT_DOCUMENTATION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "declaration_file",
+                    Self::VT_DECLARATION_FILE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ObjectArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub fields: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Field<'a>>>,
+            >,
+        >,
+        pub is_struct: bool,
+        pub minalign: i32,
+        pub bytesize: i32,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >,
+        >,
+        pub documentation: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub declaration_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for ObjectArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: None,
+                fields: None,
+                is_struct: false,
+                minalign: 0_i32,
+                bytesize: 0_i32,
+                attributes: None,
+                documentation: None,
+                declaration_file: None,
+            }
+        }
+    }
+    pub struct ObjectBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ObjectBuilder<'a, 'b> {
+        #[inline]
+        fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(Object::VT_NAME, name);
+        }
+        #[inline]
+        fn add_fields(
+            &mut self,
+            fields: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Field<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Field<'_>>>,
+            >>(Object::VT_FIELDS, fields);
+        }
+        #[inline]
+        fn add_is_struct(&mut self, is_struct: bool) {
+            self.fbb
+                .push_slot::<bool>(Object::VT_IS_STRUCT, is_struct, false);
+        }
+        #[inline]
+        fn add_minalign(&mut self, minalign: i32) {
+            self.fbb
+                .push_slot::<i32>(Object::VT_MINALIGN, minalign, 0_i32);
+        }
+        #[inline]
+        fn add_bytesize(&mut self, bytesize: i32) {
+            self.fbb
+                .push_slot::<i32>(Object::VT_BYTESIZE, bytesize, 0_i32);
+        }
+        #[inline]
+        fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<KeyValue<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+            >>(Object::VT_ATTRIBUTES, attributes);
+        }
+        #[inline]
+        fn add_documentation(
+            &mut self,
+            documentation: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(Object::VT_DOCUMENTATION, documentation);
+        }
+        #[inline]
+        fn add_declaration_file(&mut self, declaration_file: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(
+                    Object::VT_DECLARATION_FILE,
+                    declaration_file,
+                );
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            ObjectBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Object<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, Object::VT_NAME, "name");
+            self.fbb.required(o, Object::VT_FIELDS, "fields");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ObjectT {
+        pub name: String,
+        pub fields: Vec<FieldT>,
+        pub is_struct: bool,
+        pub minalign: i32,
+        pub bytesize: i32,
+        pub attributes: Option<Vec<KeyValueT>>,
+        pub documentation: Option<Vec<String>>,
+        pub declaration_file: Option<String>,
+    }
+    impl Default for ObjectT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                fields: Default::default(),
+                is_struct: false,
+                minalign: 0_i32,
+                bytesize: 0_i32,
+                attributes: None,
+                documentation: None,
+                declaration_file: None,
+            }
+        }
+    }
+    impl ObjectT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<Object<'b>> {
+            let name = Some({
+                let x = &self.name;
+                fbb.create_string(x)
+            });
+            let fields = Some({
+                let x = &self.fields;
+                {
+                    let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                    fbb.create_vector(&x)
+                }
+            });
+            let attributes = self.attributes.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let documentation = self.documentation.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let declaration_file = self.declaration_file.as_ref().map(|x| fbb.create_string(x));
+            Object::create(
+                fbb,
+                &ObjectArgs {
+                    name,
+                    fields,
+                    is_struct: self.is_struct,
+                    minalign: self.minalign,
+                    bytesize: self.bytesize,
+                    attributes,
+                    documentation,
+                    declaration_file,
+                },
+            )
+        }
+    }
+    impl Object<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> ObjectT {
+            ObjectT {
+                name: {
+                    let x = self.name();
+                    x.to_string()
+                },
+                fields: {
+                    let x = self.fields();
+                    x.iter().map(|x| x.unpack()).collect()
+                },
+                is_struct: self.is_struct(),
+                minalign: self.minalign(),
+                bytesize: self.bytesize(),
+                attributes: self
+                    .attributes()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+                documentation: self
+                    .documentation()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+                declaration_file: self.declaration_file().map(|x| x.to_string()),
+            }
+        }
+    }
+    pub enum RPCCallOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct RPCCall<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for RPCCall<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> RPCCall<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args RPCCallArgs<'args>,
+        ) -> flatbuffers::WIPOffset<RPCCall<'bldr>> {
+            let mut builder = RPCCallBuilder::new(fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            if let Some(x) = args.request {
+                builder.add_request(x);
+            }
+            if let Some(x) = args.response {
+                builder.add_response(x);
+            }
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            if let Some(x) = args.documentation {
+                builder.add_documentation(x);
+            }
+            builder.finish()
+        }
+        pub const VT_NAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_REQUEST: flatbuffers::VOffsetT = 6i16;
+        pub const VT_RESPONSE: flatbuffers::VOffsetT = 8i16;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 10i16;
+        pub const VT_DOCUMENTATION: flatbuffers::VOffsetT = 12i16;
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(RPCCall::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn request(&self) -> Object<'a> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<Object<'a>>>(RPCCall::VT_REQUEST, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn response(&self) -> Object<'a> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<Object<'a>>>(RPCCall::VT_RESPONSE, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >>(RPCCall::VT_ATTRIBUTES, None)
+        }
+        #[inline]
+        pub fn documentation(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(RPCCall::VT_DOCUMENTATION, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for RPCCall<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for RPCCall<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("RPCCall");
+            ds.field("name", &self.name());
+            ds.field("request", &self.request());
+            ds.field("response", &self.response());
+            if let Some(x) = self.attributes() {
+                ds.field("attributes", &x);
+            }
+            if let Some(x) = self.documentation() {
+                ds.field("documentation", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl RPCCall<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &RPCCall) -> bool {
+            self.name() < other.name()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.name();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for RPCCall<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            RPCCall::from(a).key_compare_less_than(&RPCCall::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for RPCCall<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<Object<'_>>>(
+                    "request",
+                    Self::VT_REQUEST,
+                    true,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<Object<'_>>>(
+                    "response",
+                    Self::VT_RESPONSE,
+                    true,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("documentation", Self::VT_DOCUMENTATION, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct RPCCallArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub request: Option<flatbuffers::WIPOffset<Object<'a>>>,
+        pub response: Option<flatbuffers::WIPOffset<Object<'a>>>,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >,
+        >,
+        pub documentation: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+    }
+    impl<'a> Default for RPCCallArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: None,
+                request: None,
+                response: None,
+                attributes: None,
+                documentation: None,
+            }
+        }
+    }
+    pub struct RPCCallBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> RPCCallBuilder<'a, 'b> {
+        #[inline]
+        fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(RPCCall::VT_NAME, name);
+        }
+        #[inline]
+        fn add_request(&mut self, request: flatbuffers::WIPOffset<Object<'b>>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<Object<'_>>>(
+                    RPCCall::VT_REQUEST,
+                    request,
+                );
+        }
+        #[inline]
+        fn add_response(&mut self, response: flatbuffers::WIPOffset<Object<'b>>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<Object<'_>>>(
+                    RPCCall::VT_RESPONSE,
+                    response,
+                );
+        }
+        #[inline]
+        fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<KeyValue<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+            >>(RPCCall::VT_ATTRIBUTES, attributes);
+        }
+        #[inline]
+        fn add_documentation(
+            &mut self,
+            documentation: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(RPCCall::VT_DOCUMENTATION, documentation);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            RPCCallBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<RPCCall<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, RPCCall::VT_NAME, "name");
+            self.fbb.required(o, RPCCall::VT_REQUEST, "request");
+            self.fbb.required(o, RPCCall::VT_RESPONSE, "response");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RPCCallT {
+        pub name: String,
+        pub request: Box<ObjectT>,
+        pub response: Box<ObjectT>,
+        pub attributes: Option<Vec<KeyValueT>>,
+        pub documentation: Option<Vec<String>>,
+    }
+    impl Default for RPCCallT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                request: Default::default(),
+                response: Default::default(),
+                attributes: None,
+                documentation: None,
+            }
+        }
+    }
+    impl RPCCallT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<RPCCall<'b>> {
+            let name = Some({
+                let x = &self.name;
+                fbb.create_string(x)
+            });
+            let request = Some({
+                let x = &self.request;
+                x.pack(fbb)
+            });
+            let response = Some({
+                let x = &self.response;
+                x.pack(fbb)
+            });
+            let attributes = self.attributes.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let documentation = self.documentation.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            RPCCall::create(
+                fbb,
+                &RPCCallArgs {
+                    name,
+                    request,
+                    response,
+                    attributes,
+                    documentation,
+                },
+            )
+        }
+    }
+    impl RPCCall<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> RPCCallT {
+            RPCCallT {
+                name: {
+                    let x = self.name();
+                    x.to_string()
+                },
+                request: {
+                    let x = self.request();
+                    Box::new(x.unpack())
+                },
+                response: {
+                    let x = self.response();
+                    Box::new(x.unpack())
+                },
+                attributes: self
+                    .attributes()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+                documentation: self
+                    .documentation()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+            }
+        }
+    }
+    pub enum ServiceOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct Service<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for Service<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> Service<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ServiceArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Service<'bldr>> {
+            let mut builder = ServiceBuilder::new(fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            if let Some(x) = args.calls {
+                builder.add_calls(x);
+            }
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            if let Some(x) = args.documentation {
+                builder.add_documentation(x);
+            }
+            if let Some(x) = args.declaration_file {
+                builder.add_declaration_file(x);
+            }
+            builder.finish()
+        }
+        pub const VT_NAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_CALLS: flatbuffers::VOffsetT = 6i16;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 8i16;
+        pub const VT_DOCUMENTATION: flatbuffers::VOffsetT = 10i16;
+        pub const VT_DECLARATION_FILE: flatbuffers::VOffsetT = 12i16;
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Service::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn calls(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RPCCall<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RPCCall<'a>>>,
+            >>(Service::VT_CALLS, None)
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >>(Service::VT_ATTRIBUTES, None)
+        }
+        #[inline]
+        pub fn documentation(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(Service::VT_DOCUMENTATION, None)
+        }
+        #[inline]
+        pub fn declaration_file(&self) -> Option<&'a str> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Service::VT_DECLARATION_FILE, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for Service<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for Service<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Service");
+            ds.field("name", &self.name());
+            if let Some(x) = self.calls() {
+                ds.field("calls", &x);
+            }
+            if let Some(x) = self.attributes() {
+                ds.field("attributes", &x);
+            }
+            if let Some(x) = self.documentation() {
+                ds.field("documentation", &x);
+            }
+            if let Some(x) = self.declaration_file() {
+                ds.field("declaration_file", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl Service<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &Service) -> bool {
+            self.name() < other.name()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.name();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for Service<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            Service::from(a).key_compare_less_than(&Service::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for Service<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RPCCall<'_>>>,
+                >>("calls", Self::VT_CALLS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("documentation", Self::VT_DOCUMENTATION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "declaration_file",
+                    Self::VT_DECLARATION_FILE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ServiceArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub calls: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RPCCall<'a>>>,
+            >,
+        >,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<KeyValue<'a>>>,
+            >,
+        >,
+        pub documentation: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub declaration_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for ServiceArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: None,
+                calls: None,
+                attributes: None,
+                documentation: None,
+                declaration_file: None,
+            }
+        }
+    }
+    pub struct ServiceBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ServiceBuilder<'a, 'b> {
+        #[inline]
+        fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(Service::VT_NAME, name);
+        }
+        #[inline]
+        fn add_calls(
+            &mut self,
+            calls: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RPCCall<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RPCCall<'_>>>,
+            >>(Service::VT_CALLS, calls);
+        }
+        #[inline]
+        fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<KeyValue<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<KeyValue<'_>>>,
+            >>(Service::VT_ATTRIBUTES, attributes);
+        }
+        #[inline]
+        fn add_documentation(
+            &mut self,
+            documentation: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(Service::VT_DOCUMENTATION, documentation);
+        }
+        #[inline]
+        fn add_declaration_file(&mut self, declaration_file: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(
+                    Service::VT_DECLARATION_FILE,
+                    declaration_file,
+                );
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            ServiceBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Service<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, Service::VT_NAME, "name");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ServiceT {
+        pub name: String,
+        pub calls: Option<Vec<RPCCallT>>,
+        pub attributes: Option<Vec<KeyValueT>>,
+        pub documentation: Option<Vec<String>>,
+        pub declaration_file: Option<String>,
+    }
+    impl Default for ServiceT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                name: Default::default(),
+                calls: None,
+                attributes: None,
+                documentation: None,
+                declaration_file: None,
+            }
+        }
+    }
+    impl ServiceT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<Service<'b>> {
+            let name = Some({
+                let x = &self.name;
+                fbb.create_string(x)
+            });
+            let calls = self.calls.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let attributes = self.attributes.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let documentation = self.documentation.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let declaration_file = self.declaration_file.as_ref().map(|x| fbb.create_string(x));
+            Service::create(
+                fbb,
+                &ServiceArgs {
+                    name,
+                    calls,
+                    attributes,
+                    documentation,
+                    declaration_file,
+                },
+            )
+        }
+    }
+    impl Service<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> ServiceT {
+            ServiceT {
+                name: {
+                    let x = self.name();
+                    x.to_string()
+                },
+                calls: self.calls().map(|x| x.iter().map(|x| x.unpack()).collect()),
+                attributes: self
+                    .attributes()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+                documentation: self
+                    .documentation()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+                declaration_file: self.declaration_file().map(|x| x.to_string()),
+            }
+        }
+    }
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    /// New schema language features that are not supported by old code generators.
+    pub struct AdvancedFeatures(u64);
+    #[allow(non_upper_case_globals)]
+    impl AdvancedFeatures {
+        pub const AdvancedArrayFeatures: Self = Self(1_u64);
+        pub const AdvancedUnionFeatures: Self = Self(2_u64);
+        pub const OptionalScalars: Self = Self(4_u64);
+        pub const DefaultVectorsAndStrings: Self = Self(8_u64);
+        /// No flags set.
+        #[inline]
+        pub const fn empty() -> Self {
+            Self(0)
+        }
+        /// Every flag set.
+        #[inline]
+        pub const fn all() -> Self {
+            Self(15_u64)
+        }
+        /// The bits of the flags, as they're stored in a buffer.
+        #[inline]
+        pub const fn bits(self) -> u64 {
+            self.0
+        }
+        /// Convert `bits` to flags, or return `None` if any bit isn't one of the flags.
+        #[inline]
+        pub const fn from_bits(bits: u64) -> Option<Self> {
+            if bits & !Self::all().0 == 0 {
+                Some(Self(bits))
+            } else {
+                None
+            }
+        }
+        /// Convert `bits` to flags, dropping any bit that isn't one of the flags.
+        #[inline]
+        pub const fn from_bits_truncate(bits: u64) -> Self {
+            Self(bits & Self::all().0)
+        }
+        /// Convert `bits` to flags, keeping any bit that isn't one of the flags.
+        #[inline]
+        pub const fn from_bits_retain(bits: u64) -> Self {
+            Self(bits)
+        }
+        #[inline]
+        pub const fn is_empty(self) -> bool {
+            self.0 == 0
+        }
+        /// Check whether every flag in `other` is set.
+        #[inline]
+        pub const fn contains(self, other: Self) -> bool {
+            self.0 & other.0 == other.0
+        }
+        /// Check whether any flag in `other` is set.
+        #[inline]
+        pub const fn intersects(self, other: Self) -> bool {
+            self.0 & other.0 != 0
+        }
+        #[inline]
+        pub fn insert(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+        #[inline]
+        pub fn remove(&mut self, other: Self) {
+            self.0 &= !other.0;
+        }
+    }
+    impl std::fmt::Debug for AdvancedFeatures {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("AdvancedFeatures(")?;
+            let mut rest = self.0;
+            let mut first = true;
+            for (name, flag) in [
+                ("AdvancedArrayFeatures", Self::AdvancedArrayFeatures),
+                ("AdvancedUnionFeatures", Self::AdvancedUnionFeatures),
+                ("OptionalScalars", Self::OptionalScalars),
+                ("DefaultVectorsAndStrings", Self::DefaultVectorsAndStrings),
+            ]
+            .iter()
+            {
+                if flag.0 != 0 && self.contains(*flag) {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    rest &= !flag.0;
+                    first = false;
+                }
+            }
+            if rest != 0 || first {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                write!(f, "{:#x}", rest)?;
+            }
+            f.write_str(")")
+        }
+    }
+    impl std::ops::BitOr for AdvancedFeatures {
+        type Output = Self;
+        #[inline]
+        fn bitor(self, other: Self) -> Self {
+            Self(self.0 | other.0)
+        }
+    }
+    impl std::ops::BitOrAssign for AdvancedFeatures {
+        #[inline]
+        fn bitor_assign(&mut self, other: Self) {
+            self.0 |= other.0;
+        }
+    }
+    impl std::ops::BitAnd for AdvancedFeatures {
+        type Output = Self;
+        #[inline]
+        fn bitand(self, other: Self) -> Self {
+            Self(self.0 & other.0)
+        }
+    }
+    impl std::ops::BitAndAssign for AdvancedFeatures {
+        #[inline]
+        fn bitand_assign(&mut self, other: Self) {
+            self.0 &= other.0;
+        }
+    }
+    impl std::ops::Not for AdvancedFeatures {
+        type Output = Self;
+        /// The flags that aren't set, leaving out any bit that isn't one of the flags.
+        #[inline]
+        fn not(self) -> Self {
+            Self::from_bits_truncate(!self.0)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for AdvancedFeatures {
+        type Inner = Self;
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            flatbuffers::read_scalar_at::<Self>(buf, loc)
+        }
+    }
+    impl flatbuffers::EndianScalar for AdvancedFeatures {
+        #[inline]
+        fn to_little_endian(self) -> Self {
+            Self(u64::to_le(self.0))
+        }
+        #[inline]
+        fn from_little_endian(self) -> Self {
+            Self(u64::from_le(self.0))
+        }
+    }
+    impl flatbuffers::Push for AdvancedFeatures {
+        type Output = Self;
+        #[inline]
+        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+            flatbuffers::emplace_scalar::<Self>(dst, *self);
+        }
+    }
+    impl flatbuffers::Verifiable for AdvancedFeatures {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.in_buffer::<Self>(pos)
+        }
+        #[inline]
+        fn run_verifier_many(
+            _: &mut flatbuffers::Verifier,
+            _: usize,
+            _: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            Ok(())
+        }
+    }
+    pub enum SchemaFileOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    /// File specific information.
+    /// Symbols declared within a file may be recovered by iterating over all
+    /// symbols and examining the `declaration_file` field.
+    pub struct SchemaFile<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for SchemaFile<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> SchemaFile<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args SchemaFileArgs<'args>,
+        ) -> flatbuffers::WIPOffset<SchemaFile<'bldr>> {
+            let mut builder = SchemaFileBuilder::new(fbb);
+            if let Some(x) = args.filename {
+                builder.add_filename(x);
+            }
+            if let Some(x) = args.included_filenames {
+                builder.add_included_filenames(x);
+            }
+            builder.finish()
+        }
+        pub const VT_FILENAME: flatbuffers::VOffsetT = 4i16;
+        pub const VT_INCLUDED_FILENAMES: flatbuffers::VOffsetT = 6i16;
+        #[inline]
+        pub fn filename(&self) -> &'a str {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(SchemaFile::VT_FILENAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn included_filenames(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(SchemaFile::VT_INCLUDED_FILENAMES, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for SchemaFile<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for SchemaFile<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("SchemaFile");
+            ds.field("filename", &self.filename());
+            if let Some(x) = self.included_filenames() {
+                ds.field("included_filenames", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl SchemaFile<'_> {
+        #[inline]
+        pub fn key_compare_less_than(&self, other: &SchemaFile) -> bool {
+            self.filename() < other.filename()
+        }
+        #[inline]
+        pub fn key_compare_with_value(&self, val: &str) -> std::cmp::Ordering {
+            let key = self.filename();
+            key.cmp(val)
+        }
+    }
+    impl flatbuffers::KeyedTable for SchemaFile<'_> {
+        #[inline]
+        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+            SchemaFile::from(a).key_compare_less_than(&SchemaFile::from(b))
+        }
+    }
+    impl flatbuffers::Verifiable for SchemaFile<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "filename",
+                    Self::VT_FILENAME,
+                    true,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("included_filenames", Self::VT_INCLUDED_FILENAMES, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SchemaFileArgs<'a> {
+        pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub included_filenames: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+    }
+    impl<'a> Default for SchemaFileArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                filename: None,
+                included_filenames: None,
+            }
+        }
+    }
+    pub struct SchemaFileBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> SchemaFileBuilder<'a, 'b> {
+        #[inline]
+        fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(
+                    SchemaFile::VT_FILENAME,
+                    filename,
+                );
+        }
+        #[inline]
+        fn add_included_filenames(
+            &mut self,
+            included_filenames: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>(SchemaFile::VT_INCLUDED_FILENAMES, included_filenames);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            SchemaFileBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SchemaFile<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, SchemaFile::VT_FILENAME, "filename");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SchemaFileT {
+        pub filename: String,
+        pub included_filenames: Option<Vec<String>>,
+    }
+    impl Default for SchemaFileT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                filename: Default::default(),
+                included_filenames: None,
+            }
+        }
+    }
+    impl SchemaFileT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<SchemaFile<'b>> {
+            let filename = Some({
+                let x = &self.filename;
+                fbb.create_string(x)
+            });
+            let included_filenames = self.included_filenames.as_ref().map(|x| {
+                let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            SchemaFile::create(
+                fbb,
+                &SchemaFileArgs {
+                    filename,
+                    included_filenames,
+                },
+            )
+        }
+    }
+    impl SchemaFile<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> SchemaFileT {
+            SchemaFileT {
+                filename: {
+                    let x = self.filename();
+                    x.to_string()
+                },
+                included_filenames: self
+                    .included_filenames()
+                    .map(|x| x.iter().map(|x| x.to_string()).collect()),
+            }
+        }
+    }
+    pub enum SchemaOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+    pub struct Schema<'a> {
+        table: flatbuffers::Table<'a>,
+    }
+    impl<'a> From<flatbuffers::Table<'a>> for Schema<'a> {
+        fn from(table: flatbuffers::Table<'a>) -> Self {
+            Self { table }
+        }
+    }
+    impl<'a> Schema<'a> {
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args SchemaArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Schema<'bldr>> {
+            let mut builder = SchemaBuilder::new(fbb);
+            if let Some(x) = args.objects {
+                builder.add_objects(x);
+            }
+            if let Some(x) = args.enums {
+                builder.add_enums(x);
+            }
+            if let Some(x) = args.file_ident {
+                builder.add_file_ident(x);
+            }
+            if let Some(x) = args.file_ext {
+                builder.add_file_ext(x);
+            }
+            if let Some(x) = args.root_table {
+                builder.add_root_table(x);
+            }
+            if let Some(x) = args.services {
+                builder.add_services(x);
+            }
+            builder.add_advanced_features(args.advanced_features);
+            if let Some(x) = args.fbs_files {
+                builder.add_fbs_files(x);
+            }
+            builder.finish()
+        }
+        pub const VT_OBJECTS: flatbuffers::VOffsetT = 4i16;
+        pub const VT_ENUMS: flatbuffers::VOffsetT = 6i16;
+        pub const VT_FILE_IDENT: flatbuffers::VOffsetT = 8i16;
+        pub const VT_FILE_EXT: flatbuffers::VOffsetT = 10i16;
+        pub const VT_ROOT_TABLE: flatbuffers::VOffsetT = 12i16;
+        pub const VT_SERVICES: flatbuffers::VOffsetT = 14i16;
+        pub const VT_ADVANCED_FEATURES: flatbuffers::VOffsetT = 16i16;
+        pub const VT_FBS_FILES: flatbuffers::VOffsetT = 18i16;
+        #[inline]
+        pub fn objects(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Object<'a>>> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Object<'a>>>,
+                >>(Schema::VT_OBJECTS, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn enums(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Enum<'a>>> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Enum<'a>>>,
+                >>(Schema::VT_ENUMS, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn file_ident(&self) -> Option<&'a str> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Schema::VT_FILE_IDENT, None)
+        }
+        #[inline]
+        pub fn file_ext(&self) -> Option<&'a str> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<&'a str>>(Schema::VT_FILE_EXT, None)
+        }
+        #[inline]
+        pub fn root_table(&self) -> Option<Object<'a>> {
+            self.table
+                .get::<flatbuffers::ForwardsUOffset<Object<'a>>>(Schema::VT_ROOT_TABLE, None)
+        }
+        #[inline]
+        pub fn services(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Service<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Service<'a>>>,
+            >>(Schema::VT_SERVICES, None)
+        }
+        #[inline]
+        pub fn advanced_features(&self) -> AdvancedFeatures {
+            self.table
+                .get::<AdvancedFeatures>(
+                    Schema::VT_ADVANCED_FEATURES,
+                    Some(AdvancedFeatures::from_bits_retain(0_u64)),
+                )
+                .unwrap()
+        }
+        #[inline]
+        pub fn fbs_files(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SchemaFile<'a>>>> {
+            self.table.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SchemaFile<'a>>>,
+            >>(Schema::VT_FBS_FILES, None)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for Schema<'a> {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let table = flatbuffers::Table { buf, loc };
+            Self { table }
+        }
+    }
+    impl std::fmt::Debug for Schema<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Schema");
+            ds.field("objects", &self.objects());
+            ds.field("enums", &self.enums());
+            if let Some(x) = self.file_ident() {
+                ds.field("file_ident", &x);
+            }
+            if let Some(x) = self.file_ext() {
+                ds.field("file_ext", &x);
+            }
+            if let Some(x) = self.root_table() {
+                ds.field("root_table", &x);
+            }
+            if let Some(x) = self.services() {
+                ds.field("services", &x);
+            }
+            ds.field("advanced_features", &self.advanced_features());
+            if let Some(x) = self.fbs_files() {
+                ds.field("fbs_files", &x);
+            }
+            ds.finish()
+        }
+    }
+    impl flatbuffers::Verifiable for Schema<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Object<'_>>>,
+                >>("objects", Self::VT_OBJECTS, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Enum<'_>>>,
+                >>("enums", Self::VT_ENUMS, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "file_ident",
+                    Self::VT_FILE_IDENT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "file_ext",
+                    Self::VT_FILE_EXT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<Object<'_>>>(
+                    "root_table",
+                    Self::VT_ROOT_TABLE,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Service<'_>>>,
+                >>("services", Self::VT_SERVICES, false)?
+                .visit_field::<AdvancedFeatures>(
+                    "advanced_features",
+                    Self::VT_ADVANCED_FEATURES,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SchemaFile<'_>>>,
+                >>("fbs_files", Self::VT_FBS_FILES, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SchemaArgs<'a> {
+        pub objects: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Object<'a>>>,
+            >,
+        >,
+        pub enums: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Enum<'a>>>>,
+        >,
+        pub file_ident: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub file_ext: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub root_table: Option<flatbuffers::WIPOffset<Object<'a>>>,
+        pub services: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Service<'a>>>,
+            >,
+        >,
+        pub advanced_features: AdvancedFeatures,
+        pub fbs_files: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SchemaFile<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for SchemaArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                objects: None,
+                enums: None,
+                file_ident: None,
+                file_ext: None,
+                root_table: None,
+                services: None,
+                advanced_features: AdvancedFeatures::from_bits_retain(0_u64),
+                fbs_files: None,
+            }
+        }
+    }
+    pub struct SchemaBuilder<'a, 'b> {
+        fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> SchemaBuilder<'a, 'b> {
+        #[inline]
+        fn add_objects(
+            &mut self,
+            objects: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Object<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Object<'_>>>,
+            >>(Schema::VT_OBJECTS, objects);
+        }
+        #[inline]
+        fn add_enums(
+            &mut self,
+            enums: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Enum<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Enum<'_>>>,
+            >>(Schema::VT_ENUMS, enums);
+        }
+        #[inline]
+        fn add_file_ident(&mut self, file_ident: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(
+                    Schema::VT_FILE_IDENT,
+                    file_ident,
+                );
+        }
+        #[inline]
+        fn add_file_ext(&mut self, file_ext: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<&'_ str>>(Schema::VT_FILE_EXT, file_ext);
+        }
+        #[inline]
+        fn add_root_table(&mut self, root_table: flatbuffers::WIPOffset<Object<'b>>) {
+            self.fbb
+                .push_slot_always::<flatbuffers::WIPOffset<Object<'_>>>(
+                    Schema::VT_ROOT_TABLE,
+                    root_table,
+                );
+        }
+        #[inline]
+        fn add_services(
+            &mut self,
+            services: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Service<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Service<'_>>>,
+            >>(Schema::VT_SERVICES, services);
+        }
+        #[inline]
+        fn add_advanced_features(&mut self, advanced_features: AdvancedFeatures) {
+            self.fbb.push_slot::<AdvancedFeatures>(
+                Schema::VT_ADVANCED_FEATURES,
+                advanced_features,
+                AdvancedFeatures::from_bits_retain(0_u64),
+            );
+        }
+        #[inline]
+        fn add_fbs_files(
+            &mut self,
+            fbs_files: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SchemaFile<'b>>>,
+            >,
+        ) {
+            self.fbb.push_slot_always::<flatbuffers::WIPOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SchemaFile<'_>>>,
+            >>(Schema::VT_FBS_FILES, fbs_files);
+        }
+        #[inline]
+        pub fn new(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Self {
+            let start = fbb.start_table();
+            SchemaBuilder { fbb, start }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Schema<'a>> {
+            let o = self.fbb.end_table(self.start);
+            self.fbb.required(o, Schema::VT_OBJECTS, "objects");
+            self.fbb.required(o, Schema::VT_ENUMS, "enums");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct SchemaT {
+        pub objects: Vec<ObjectT>,
+        pub enums: Vec<EnumT>,
+        pub file_ident: Option<String>,
+        pub file_ext: Option<String>,
+        pub root_table: Option<Box<ObjectT>>,
+        pub services: Option<Vec<ServiceT>>,
+        pub advanced_features: AdvancedFeatures,
+        pub fbs_files: Option<Vec<SchemaFileT>>,
+    }
+    impl Default for SchemaT {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                objects: Default::default(),
+                enums: Default::default(),
+                file_ident: None,
+                file_ext: None,
+                root_table: None,
+                services: None,
+                advanced_features: AdvancedFeatures::from_bits_retain(0_u64),
+                fbs_files: None,
+            }
+        }
+    }
+    impl SchemaT {
+        /// Write the table to `fbb`, along with everything it contains.
+        pub fn pack<'b>(
+            &self,
+            fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+        ) -> flatbuffers::WIPOffset<Schema<'b>> {
+            let objects = Some({
+                let x = &self.objects;
+                {
+                    let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                    fbb.create_vector(&x)
+                }
+            });
+            let enums = Some({
+                let x = &self.enums;
+                {
+                    let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                    fbb.create_vector(&x)
+                }
+            });
+            let file_ident = self.file_ident.as_ref().map(|x| fbb.create_string(x));
+            let file_ext = self.file_ext.as_ref().map(|x| fbb.create_string(x));
+            let root_table = self.root_table.as_ref().map(|x| x.pack(fbb));
+            let services = self.services.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            let fbs_files = self.fbs_files.as_ref().map(|x| {
+                let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                fbb.create_vector(&x)
+            });
+            Schema::create(
+                fbb,
+                &SchemaArgs {
+                    objects,
+                    enums,
+                    file_ident,
+                    file_ext,
+                    root_table,
+                    services,
+                    advanced_features: self.advanced_features,
+                    fbs_files,
+                },
+            )
+        }
+    }
+    impl Schema<'_> {
+        /// Copy the table, along with everything it contains, out of the buffer.
+        pub fn unpack(&self) -> SchemaT {
+            SchemaT {
+                objects: {
+                    let x = self.objects();
+                    x.iter().map(|x| x.unpack()).collect()
+                },
+                enums: {
+                    let x = self.enums();
+                    x.iter().map(|x| x.unpack()).collect()
+                },
+                file_ident: self.file_ident().map(|x| x.to_string()),
+                file_ext: self.file_ext().map(|x| x.to_string()),
+                root_table: self.root_table().map(|x| Box::new(x.unpack())),
+                services: self
+                    .services()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+                advanced_features: self.advanced_features(),
+                fbs_files: self
+                    .fbs_files()
+                    .map(|x| x.iter().map(|x| x.unpack()).collect()),
+            }
+        }
+    }
+    /// Verify that `buf` holds a valid root table before reading it.
+    #[inline]
+    pub fn root_as_schema<'a>(buf: &'a [u8]) -> Result<Schema<'a>, flatbuffers::InvalidFlatbuffer> {
+        flatbuffers::root::<Schema<'a>>(buf)
+    }
+    /// Verify that `buf` holds a valid size-prefixed root table before reading it.
+    #[inline]
+    pub fn size_prefixed_root_as_schema<'a>(
+        buf: &'a [u8],
+    ) -> Result<Schema<'a>, flatbuffers::InvalidFlatbuffer> {
+        flatbuffers::size_prefixed_root::<Schema<'a>>(buf)
+    }
+    /// Verify that `buf` holds a valid root table within the limits of `opts` before reading
+    /// it.
+    #[inline]
+    pub fn root_as_schema_with_opts<'a>(
+        opts: &flatbuffers::VerifierOptions,
+        buf: &'a [u8],
+    ) -> Result<Schema<'a>, flatbuffers::InvalidFlatbuffer> {
+        flatbuffers::root_with_opts::<Schema<'a>>(opts, buf)
+    }
+    /// Verify that `buf` holds a valid size-prefixed root table within the limits of `opts`
+    /// before reading it.
+    #[inline]
+    pub fn size_prefixed_root_as_schema_with_opts<'a>(
+        opts: &flatbuffers::VerifierOptions,
+        buf: &'a [u8],
+    ) -> Result<Schema<'a>, flatbuffers::InvalidFlatbuffer> {
+        flatbuffers::size_prefixed_root_with_opts::<Schema<'a>>(opts, buf)
+    }
+    /// Read the root table of `buf` without verifying it first.
+    ///
+    /// `buf` must come from a trusted source, since an invalid buffer can cause reads out
+    /// of bounds. Use the verifying version otherwise.
+    #[inline]
+    pub fn get_root_as_schema<'a>(buf: &'a [u8]) -> Schema<'a> {
+        flatbuffers::get_root::<Schema<'a>>(buf)
+    }
+    #[inline]
+    pub fn get_size_prefixed_root_as_schema<'a>(buf: &'a [u8]) -> Schema<'a> {
+        flatbuffers::get_size_prefixed_root::<Schema<'a>>(buf)
+    }
+    pub const SCHEMA_IDENTIFIER: &str = "BFBS";
+    #[inline]
+    pub fn schema_buffer_has_identifier(buf: &[u8]) -> bool {
+        flatbuffers::buffer_has_identifier(buf, SCHEMA_IDENTIFIER, false)
+    }
+    #[inline]
+    pub fn schema_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+        flatbuffers::buffer_has_identifier(buf, SCHEMA_IDENTIFIER, true)
+    }
+    pub const SCHEMA_EXTENSION: &str = "bfbs";
+    #[inline]
+    pub fn finish_schema_buffer<'a>(
+        fbb: &mut flatbuffers::FlatBufferBuilder<'a>,
+        root: flatbuffers::WIPOffset<Schema<'a>>,
+    ) {
+        fbb.finish(root, Some(SCHEMA_IDENTIFIER));
+    }
+    #[inline]
+    pub fn finish_size_prefixed_schema_buffer<'a>(
+        fbb: &mut flatbuffers::FlatBufferBuilder<'a>,
+        root: flatbuffers::WIPOffset<Schema<'a>>,
+    ) {
+        fbb.finish_size_prefixed(root, Some(SCHEMA_IDENTIFIER));
+    }
+}
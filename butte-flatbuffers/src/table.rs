@@ -45,6 +45,14 @@ impl<'a> Table<'a> {
     }
 }
 
+/// A table with a field marked with the `key` attribute, by which vectors of the table can be
+/// sorted and then searched with `Vector::lookup_by_key`.
+pub trait KeyedTable {
+    /// Check whether the key of the table `a` sorts before the key of the table `b`, where both
+    /// are tables of this type.
+    fn key_less_than(a: Table, b: Table) -> bool;
+}
+
 impl<'a> Follow<'a> for Table<'a> {
     type Inner = Table<'a>;
     #[inline]
@@ -15,6 +15,8 @@
  */
 
 use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
     iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator},
     marker::PhantomData,
     mem::size_of,
@@ -26,7 +28,6 @@ use std::{
 use crate::endian_scalar::EndianScalar;
 use crate::{endian_scalar::read_scalar_at, follow::Follow, primitives::*};
 
-#[derive(Debug)]
 pub struct Vector<'a, T: 'a>(&'a [u8], usize, PhantomData<T>);
 
 // We cannot use derive for these two impls, as it would only implement Copy
@@ -72,6 +73,50 @@ impl<'a, T: Follow<'a> + 'a> Vector<'a, T> {
     pub fn iter(&self) -> VectorIter<'a, T> {
         VectorIter::new(*self)
     }
+
+    /// Binary search a sorted vector for the element whose key is `key`.
+    ///
+    /// `compare` compares the key of an element to `key`; for tables with a `key` field, that's
+    /// their generated `key_compare_with_value`. The vector must be sorted by the same key, as
+    /// done by `FlatBufferBuilder::create_vector_of_sorted_tables`.
+    pub fn lookup_by_key<K>(
+        &self,
+        key: K,
+        compare: impl Fn(&T::Inner, &K) -> Ordering,
+    ) -> Option<T::Inner> {
+        let mut left = 0;
+        let mut right = self.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            let value = self.get(mid);
+            match compare(&value, &key) {
+                Ordering::Equal => return Some(value),
+                Ordering::Less => left = mid + 1,
+                Ordering::Greater => right = mid,
+            }
+        }
+        None
+    }
+}
+
+// A vector is shown as a list of its elements, rather than the buffer it's in.
+impl<'a, T: Follow<'a> + 'a> Debug for Vector<'a, T>
+where
+    T::Inner: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: Follow<'a> + 'a> serde::Serialize for Vector<'a, T>
+where
+    T::Inner: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
 }
 
 pub trait SafeSliceAccess {}
@@ -0,0 +1,543 @@
+/*
+ * Copyright 2018 Google Inc. All rights reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::{fmt, mem::align_of, mem::size_of, ops::Range};
+
+use crate::{
+    endian_scalar::{read_scalar_at, EndianScalar},
+    follow::Follow,
+    primitives::*,
+    vector::Vector,
+};
+
+/// The reason a buffer failed verification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvalidFlatbuffer {
+    /// A field marked `required` is missing from a table.
+    MissingRequiredField { required: &'static str },
+
+    /// A union has a value without a type, or a type without a value.
+    InconsistentUnion {
+        field: &'static str,
+        field_type: &'static str,
+    },
+
+    /// The schema a buffer is read with gives a union the first field id, leaving no slot before
+    /// it for its type.
+    UnionWithoutTypeSlot { position: usize },
+
+    /// A string isn't valid UTF-8.
+    Utf8Error { range: Range<usize> },
+
+    /// A string isn't followed by a `0` byte.
+    MissingNullTerminator { range: Range<usize> },
+
+    /// A value isn't aligned to its size.
+    Unaligned {
+        position: usize,
+        unaligned_type: &'static str,
+    },
+
+    /// A value extends past the end of the buffer.
+    RangeOutOfBounds { range: Range<usize> },
+
+    /// An offset points before the start or past the end of the buffer.
+    SignedOffsetOutOfBounds { soffset: SOffsetT, position: usize },
+
+    /// A vtable is too small to hold its own header, or has an odd size.
+    InvalidVTable { position: usize, size: usize },
+
+    /// A bool is neither `0` nor `1`.
+    InvalidBool { position: usize },
+
+    /// An enum holds a value it doesn't declare.
+    UnknownEnumValue {
+        enum_name: &'static str,
+        position: usize,
+    },
+
+    /// The buffer contains more tables than
+    /// [`VerifierOptions::max_tables`](crate::VerifierOptions::max_tables).
+    TooManyTables,
+
+    /// Following every offset in the buffer visits more than
+    /// [`VerifierOptions::max_apparent_size`](crate::VerifierOptions::max_apparent_size) bytes.
+    ApparentSizeTooLarge,
+
+    /// Tables are nested deeper than
+    /// [`VerifierOptions::max_depth`](crate::VerifierOptions::max_depth).
+    DepthLimitReached,
+}
+
+impl fmt::Display for InvalidFlatbuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidFlatbuffer::MissingRequiredField { required } => {
+                write!(f, "missing required field `{}`", required)
+            }
+            InvalidFlatbuffer::InconsistentUnion { field, field_type } => write!(
+                f,
+                "union field `{}` is inconsistent with its type field `{}`",
+                field, field_type
+            ),
+            InvalidFlatbuffer::UnionWithoutTypeSlot { position } => write!(
+                f,
+                "union at {} has the first field id, so it has no slot for its type",
+                position
+            ),
+            InvalidFlatbuffer::Utf8Error { range } => {
+                write!(f, "string at {:?} is not valid UTF-8", range)
+            }
+            InvalidFlatbuffer::MissingNullTerminator { range } => {
+                write!(f, "string at {:?} is missing its null terminator", range)
+            }
+            InvalidFlatbuffer::Unaligned {
+                position,
+                unaligned_type,
+            } => write!(f, "{} at {} is unaligned", unaligned_type, position),
+            InvalidFlatbuffer::RangeOutOfBounds { range } => {
+                write!(f, "range {:?} is out of bounds", range)
+            }
+            InvalidFlatbuffer::SignedOffsetOutOfBounds { soffset, position } => write!(
+                f,
+                "signed offset {} at {} points out of bounds",
+                soffset, position
+            ),
+            InvalidFlatbuffer::InvalidVTable { position, size } => {
+                write!(f, "vtable at {} has invalid size {}", position, size)
+            }
+            InvalidFlatbuffer::InvalidBool { position } => {
+                write!(f, "value at {} is not a bool", position)
+            }
+            InvalidFlatbuffer::UnknownEnumValue {
+                enum_name,
+                position,
+            } => write!(
+                f,
+                "value at {} is not a variant of `{}`",
+                position, enum_name
+            ),
+            InvalidFlatbuffer::TooManyTables => f.write_str("too many tables"),
+            InvalidFlatbuffer::ApparentSizeTooLarge => f.write_str("apparent size too large"),
+            InvalidFlatbuffer::DepthLimitReached => f.write_str("nested table depth limit reached"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidFlatbuffer {}
+
+pub type Result<T> = std::result::Result<T, InvalidFlatbuffer>;
+
+/// Limits on the work done verifying a buffer.
+///
+/// A buffer can refer to the same data any number of times, so these bound the time spent
+/// verifying a buffer crafted to make verification expensive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifierOptions {
+    /// The deepest tables may be nested.
+    pub max_depth: usize,
+
+    /// The most tables a buffer may contain.
+    pub max_tables: usize,
+
+    /// The most bytes verification may visit, counting data once for every time it's referred to.
+    pub max_apparent_size: usize,
+
+    /// Accept strings that aren't followed by a `0` byte.
+    pub ignore_missing_null_terminator: bool,
+
+    /// Reject enums holding values they don't declare.
+    ///
+    /// Such values can be read safely, and are expected in buffers written with a newer version
+    /// of the schema, so they're accepted by default.
+    pub reject_unknown_enum_values: bool,
+}
+
+impl Default for VerifierOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_tables: 1_000_000,
+            max_apparent_size: FLATBUFFERS_MAX_BUFFER_SIZE,
+            ignore_missing_null_terminator: false,
+            reject_unknown_enum_values: false,
+        }
+    }
+}
+
+/// Checks that reading a buffer stays within its bounds and produces valid values.
+///
+/// Alignment is checked relative to the start of the buffer, so the buffer itself must be
+/// aligned to the largest alignment of the types in it.
+#[derive(Debug)]
+pub struct Verifier<'opts, 'buf> {
+    buffer: &'buf [u8],
+    opts: &'opts VerifierOptions,
+    depth: usize,
+    num_tables: usize,
+    apparent_size: usize,
+}
+
+impl<'opts, 'buf> Verifier<'opts, 'buf> {
+    pub fn new(opts: &'opts VerifierOptions, buffer: &'buf [u8]) -> Self {
+        Self {
+            buffer,
+            opts,
+            depth: 0,
+            num_tables: 0,
+            apparent_size: 0,
+        }
+    }
+
+    /// Check whether enums holding values they don't declare should be rejected.
+    #[inline]
+    pub fn rejects_unknown_enum_values(&self) -> bool {
+        self.opts.reject_unknown_enum_values
+    }
+
+    /// Check that a `T` at `pos` would be aligned.
+    // `is_multiple_of` needs Rust 1.87, which is newer than the oldest toolchain CI builds with
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    #[inline]
+    pub fn is_aligned<T>(&self, pos: usize) -> Result<()> {
+        if pos % align_of::<T>() == 0 {
+            Ok(())
+        } else {
+            Err(InvalidFlatbuffer::Unaligned {
+                position: pos,
+                unaligned_type: std::any::type_name::<T>(),
+            })
+        }
+    }
+
+    /// Check that `size` bytes starting at `pos` are in the buffer.
+    #[inline]
+    pub fn range_in_buffer(&mut self, pos: usize, size: usize) -> Result<()> {
+        // saturating at the end of the address space still puts `end` out of bounds
+        let end = pos.saturating_add(size);
+        if end > self.buffer.len() {
+            return Err(InvalidFlatbuffer::RangeOutOfBounds { range: pos..end });
+        }
+        self.apparent_size += size;
+        if self.apparent_size > self.opts.max_apparent_size {
+            return Err(InvalidFlatbuffer::ApparentSizeTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Check that a `T` at `pos` is aligned and in the buffer.
+    #[inline]
+    pub fn in_buffer<T>(&mut self, pos: usize) -> Result<()> {
+        self.is_aligned::<T>(pos)?;
+        self.range_in_buffer(pos, size_of::<T>())
+    }
+
+    /// Read a scalar that has already been checked to be in the buffer.
+    #[inline]
+    fn read<T: EndianScalar>(&self, pos: usize) -> T {
+        read_scalar_at::<T>(self.buffer, pos)
+    }
+
+    /// Read the scalar at `pos`, checking that it's in the buffer first.
+    #[inline]
+    pub fn get<T: EndianScalar>(&mut self, pos: usize) -> Result<T> {
+        self.in_buffer::<T>(pos)?;
+        Ok(self.read::<T>(pos))
+    }
+
+    /// Follow the unsigned offset at `pos`, returning the position it points to.
+    #[inline]
+    pub fn deref_uoffset(&mut self, pos: usize) -> Result<usize> {
+        let offset = self.get::<UOffsetT>(pos)? as usize;
+        Ok(pos.saturating_add(offset))
+    }
+
+    /// Start verifying the table at `pos`, checking its vtable.
+    ///
+    /// The fields of the table are verified with the returned [`TableVerifier`], which must be
+    /// [`finish`](TableVerifier::finish)ed once they've all been visited.
+    // `is_multiple_of` needs Rust 1.87, which is newer than the oldest toolchain CI builds with
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    pub fn visit_table<'ver>(
+        &'ver mut self,
+        pos: usize,
+    ) -> Result<TableVerifier<'ver, 'opts, 'buf>> {
+        self.depth += 1;
+        if self.depth > self.opts.max_depth {
+            return Err(InvalidFlatbuffer::DepthLimitReached);
+        }
+        self.num_tables += 1;
+        if self.num_tables > self.opts.max_tables {
+            return Err(InvalidFlatbuffer::TooManyTables);
+        }
+
+        // the table starts with a signed offset back to its vtable
+        let soffset = self.get::<SOffsetT>(pos)?;
+        let vtable = (pos as i64)
+            .checked_sub(i64::from(soffset))
+            .filter(|&vtable| vtable >= 0 && vtable <= self.buffer.len() as i64)
+            .ok_or(InvalidFlatbuffer::SignedOffsetOutOfBounds {
+                soffset,
+                position: pos,
+            })? as usize;
+
+        let vtable_len = self.get::<VOffsetT>(vtable)? as u16 as usize;
+        if vtable_len < VTABLE_METADATA_FIELDS * SIZE_VOFFSET || vtable_len % SIZE_VOFFSET != 0 {
+            return Err(InvalidFlatbuffer::InvalidVTable {
+                position: vtable,
+                size: vtable_len,
+            });
+        }
+        self.range_in_buffer(vtable, vtable_len)?;
+        let table_len = self.read::<VOffsetT>(vtable + SIZE_VOFFSET) as u16 as usize;
+        self.range_in_buffer(pos, table_len)?;
+
+        Ok(TableVerifier {
+            verifier: self,
+            pos,
+            vtable,
+            vtable_len,
+        })
+    }
+}
+
+/// Verifies the fields of a table, one at a time.
+#[derive(Debug)]
+pub struct TableVerifier<'ver, 'opts, 'buf> {
+    pub(crate) verifier: &'ver mut Verifier<'opts, 'buf>,
+    pos: usize,
+    vtable: usize,
+    vtable_len: usize,
+}
+
+impl<'ver, 'opts, 'buf> TableVerifier<'ver, 'opts, 'buf> {
+    /// The position of the field stored in vtable slot `field`, or `None` if it isn't present.
+    pub(crate) fn deref(&mut self, field: VOffsetT) -> Option<usize> {
+        let field = field as u16 as usize;
+        if field + SIZE_VOFFSET > self.vtable_len {
+            return None;
+        }
+        match self.verifier.read::<VOffsetT>(self.vtable + field) as u16 as usize {
+            0 => None,
+            offset => Some(self.pos + offset),
+        }
+    }
+
+    /// Verify the field stored in vtable slot `field`, if it's present.
+    pub fn visit_field<T: Verifiable>(
+        mut self,
+        name: &'static str,
+        field: VOffsetT,
+        required: bool,
+    ) -> Result<Self> {
+        match self.deref(field) {
+            Some(pos) => T::run_verifier(self.verifier, pos)?,
+            None if required => {
+                return Err(InvalidFlatbuffer::MissingRequiredField { required: name })
+            }
+            None => {}
+        }
+        Ok(self)
+    }
+
+    /// Verify a union field, given the slots of its type and its value.
+    ///
+    /// `verify_union` is called with the type of the union to verify the value accordingly.
+    pub fn visit_union<Key, F>(
+        mut self,
+        key_name: &'static str,
+        key_field: VOffsetT,
+        val_name: &'static str,
+        val_field: VOffsetT,
+        required: bool,
+        verify_union: F,
+    ) -> Result<Self>
+    where
+        Key: Verifiable + Follow<'buf, Inner = Key>,
+        F: FnOnce(Key, &mut Verifier, usize) -> Result<()>,
+    {
+        let key = self.deref(key_field);
+        let val = self.deref(val_field);
+        match (key, val) {
+            (Some(key), Some(val)) => {
+                Key::run_verifier(self.verifier, key)?;
+                let key = Key::follow(self.verifier.buffer, key);
+                verify_union(key, self.verifier, val)?;
+            }
+            (None, None) if required => {
+                return Err(InvalidFlatbuffer::MissingRequiredField { required: val_name })
+            }
+            (None, None) => {}
+            _ => {
+                return Err(InvalidFlatbuffer::InconsistentUnion {
+                    field: val_name,
+                    field_type: key_name,
+                })
+            }
+        }
+        Ok(self)
+    }
+
+    /// Finish verifying the table, returning the verifier it was started from.
+    pub fn finish(self) -> &'ver mut Verifier<'opts, 'buf> {
+        self.verifier.depth -= 1;
+        self.verifier
+    }
+}
+
+/// A type that can be checked to be valid at a position in a buffer.
+///
+/// This is implemented for every type that can be read from a buffer: scalars, offsets, strings
+/// and vectors here, and tables, structs, enums and unions by generated code.
+pub trait Verifiable {
+    /// Check that a value of this type is valid at `pos`.
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<()>;
+
+    /// Check that `count` consecutive values of this type starting at `pos` are valid.
+    ///
+    /// The bytes of the values have already been checked to be in the buffer, so types without
+    /// invalid bit patterns don't need to do anything.
+    fn run_verifier_many(v: &mut Verifier, pos: usize, count: usize) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let size = size_of::<Self>();
+        (0..count).try_for_each(|i| Self::run_verifier(v, pos + i * size))
+    }
+}
+
+macro_rules! impl_verifiable_for_scalar {
+    ($ty:ident) => {
+        impl Verifiable for $ty {
+            #[inline]
+            fn run_verifier(v: &mut Verifier, pos: usize) -> Result<()> {
+                v.in_buffer::<$ty>(pos)
+            }
+
+            #[inline]
+            fn run_verifier_many(_: &mut Verifier, _: usize, _: usize) -> Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_verifiable_for_scalar!(u8);
+impl_verifiable_for_scalar!(u16);
+impl_verifiable_for_scalar!(u32);
+impl_verifiable_for_scalar!(u64);
+impl_verifiable_for_scalar!(i8);
+impl_verifiable_for_scalar!(i16);
+impl_verifiable_for_scalar!(i32);
+impl_verifiable_for_scalar!(i64);
+impl_verifiable_for_scalar!(f32);
+impl_verifiable_for_scalar!(f64);
+
+/// Reading a byte other than `0` or `1` as a bool is undefined behaviour.
+impl Verifiable for bool {
+    #[inline]
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<()> {
+        match v.get::<u8>(pos)? {
+            0 | 1 => Ok(()),
+            _ => Err(InvalidFlatbuffer::InvalidBool { position: pos }),
+        }
+    }
+
+    fn run_verifier_many(v: &mut Verifier, pos: usize, count: usize) -> Result<()> {
+        match v.buffer[pos..pos + count].iter().position(|&b| b > 1) {
+            Some(i) => Err(InvalidFlatbuffer::InvalidBool { position: pos + i }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Verifiable> Verifiable for ForwardsUOffset<T> {
+    #[inline]
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<()> {
+        let pos = v.deref_uoffset(pos)?;
+        T::run_verifier(v, pos)
+    }
+}
+
+impl Verifiable for &str {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<()> {
+        let len = v.get::<UOffsetT>(pos)? as usize;
+        let start = pos + SIZE_UOFFSET;
+        v.range_in_buffer(start, len)?;
+        let range = start..start + len;
+        if std::str::from_utf8(&v.buffer[range.clone()]).is_err() {
+            return Err(InvalidFlatbuffer::Utf8Error { range });
+        }
+        if !v.opts.ignore_missing_null_terminator && v.buffer.get(range.end) != Some(&0) {
+            return Err(InvalidFlatbuffer::MissingNullTerminator { range });
+        }
+        Ok(())
+    }
+}
+
+impl<T: Verifiable> Verifiable for Vector<'_, T> {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<()> {
+        let len = v.get::<UOffsetT>(pos)? as usize;
+        let start = pos + SIZE_UOFFSET;
+        let size = len
+            .checked_mul(size_of::<T>())
+            .ok_or(InvalidFlatbuffer::ApparentSizeTooLarge)?;
+        v.is_aligned::<T>(start)?;
+        v.range_in_buffer(start, size)?;
+        T::run_verifier_many(v, start, len)
+    }
+}
+
+/// Verify that `buf` starts with an offset to a valid `T`.
+pub fn root<'buf, T>(buf: &'buf [u8]) -> Result<T::Inner>
+where
+    T: Follow<'buf> + Verifiable + 'buf,
+{
+    root_with_opts::<T>(&VerifierOptions::default(), buf)
+}
+
+/// Verify that `buf` starts with an offset to a valid `T`, within the limits of `opts`.
+pub fn root_with_opts<'buf, T>(opts: &VerifierOptions, buf: &'buf [u8]) -> Result<T::Inner>
+where
+    T: Follow<'buf> + Verifiable + 'buf,
+{
+    let mut v = Verifier::new(opts, buf);
+    <ForwardsUOffset<T>>::run_verifier(&mut v, 0)?;
+    Ok(crate::get_root::<T>(buf))
+}
+
+/// Verify that `buf` starts with a size prefix followed by an offset to a valid `T`.
+pub fn size_prefixed_root<'buf, T>(buf: &'buf [u8]) -> Result<T::Inner>
+where
+    T: Follow<'buf> + Verifiable + 'buf,
+{
+    size_prefixed_root_with_opts::<T>(&VerifierOptions::default(), buf)
+}
+
+/// Verify that `buf` starts with a size prefix followed by an offset to a valid `T`, within the
+/// limits of `opts`.
+pub fn size_prefixed_root_with_opts<'buf, T>(
+    opts: &VerifierOptions,
+    buf: &'buf [u8],
+) -> Result<T::Inner>
+where
+    T: Follow<'buf> + Verifiable + 'buf,
+{
+    let mut v = Verifier::new(opts, buf);
+    v.in_buffer::<UOffsetT>(0)?;
+    <ForwardsUOffset<T>>::run_verifier(&mut v, SIZE_SIZEPREFIX)?;
+    Ok(crate::get_size_prefixed_root::<T>(buf))
+}
@@ -1,16 +1,21 @@
-use crate::types::*;
+use crate::{
+    resolver::{Declaration, Symbol, SymbolTable},
+    types::*,
+};
 
 #[cfg(test)]
-use crate::{field, table};
+use crate::{field, meta, table};
 
 use flatbuffers::VOffsetT;
 use heck::{ShoutySnakeCase, SnakeCase};
-use itertools::Itertools;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use std::{convert::TryInto, fmt::Display};
+use std::fmt::Display;
 use syn::spanned::Spanned;
 
+mod object_api;
+mod serde;
+
 #[cfg(test)]
 fn to_code(value: impl ToTokens) -> String {
     format!("{}", value.to_token_stream())
@@ -53,9 +58,25 @@ mod constant_tests {
     }
 }
 
+/// The Rust identifier for `name`, which is a raw identifier when `name` is a keyword.
+///
+/// The few keywords that can't be raw identifiers have an underscore appended instead.
+fn rust_ident(name: &str) -> syn::Ident {
+    match name {
+        "self" | "Self" | "super" | "crate" => format_ident!("{}_", name),
+        _ if syn::parse_str::<syn::Ident>(name).is_err() => format_ident!("r#{}", name),
+        _ => format_ident!("{}", name),
+    }
+}
+
 impl ToTokens for Ident<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        format_ident!("{}", self.raw).to_tokens(tokens)
+        // `super` is how the relative paths to declarations in other namespaces start
+        if self.raw == "super" {
+            format_ident!("super").to_tokens(tokens)
+        } else {
+            rust_ident(self.raw).to_tokens(tokens)
+        }
     }
 }
 
@@ -69,6 +90,13 @@ mod ident_tests {
         let expected = "foo";
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_visit_keyword_ident() {
+        assert_eq!(to_code(Ident::from("type")), "r#type");
+        assert_eq!(to_code(Ident::from("self")), "self_");
+        assert_eq!(to_code(Ident::from("super")), "super");
+    }
 }
 
 impl ToTokens for Scalar {
@@ -81,19 +109,355 @@ impl ToTokens for Scalar {
     }
 }
 
-/// Convert a `types::Type` to a type with the supplied wrapper for reference types
-fn to_type(ty: &Type, lifetime: impl ToTokens, wrap_refs_types: impl ToTokens) -> impl ToTokens {
-    match ty {
-        Type::String => {
-            let wrap_tokens = wrap_refs_types.into_token_stream();
+/// Optional parts of the generated code.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Options {
+    /// Generate an owned `XT` type for every table and union `X`, along with methods converting
+    /// between it and the buffer.
+    pub object_api: bool,
+
+    /// Implement `serde::Serialize` for every type reading a buffer, and `serde::Deserialize` as
+    /// well for structs, enums and the object types.
+    pub serde: bool,
+}
+
+/// The schemas compiled together, along with the information about them needed to generate code
+/// for the elements in them.
+#[derive(Debug, Default)]
+pub struct Context<'a> {
+    /// The schemas, each preceded by the ones it includes.
+    schemas: &'a [Schema<'a>],
+
+    /// Every type declared in the schemas.
+    symbols: SymbolTable<'a>,
+
+    options: Options,
+}
+
+impl<'a> Context<'a> {
+    /// Collect the type declarations of `schemas`.
+    pub fn new(schemas: &'a [Schema<'a>]) -> Self {
+        Self::with_options(schemas, Options::default())
+    }
+
+    /// Collect the type declarations of `schemas`, to generate code with `options`.
+    pub fn with_options(schemas: &'a [Schema<'a>], options: Options) -> Self {
+        Self {
+            schemas,
+            symbols: SymbolTable::new(schemas),
+            options,
+        }
+    }
+
+    /// Find the declaration `ident` refers to when used in `namespace`.
+    pub fn lookup(&self, namespace: &[Ident], ident: &DottedIdent) -> Option<Declaration<'a>> {
+        self.symbols
+            .resolve(namespace, ident)
+            .map(|symbol| symbol.declaration)
+    }
+
+    /// Check the schemas for mistakes that would result in invalid code.
+    pub fn validate(&self) -> Vec<crate::validate::Error<'a>> {
+        crate::validate::validate(self.schemas, &self.symbols)
+    }
+
+    /// Pair `item` with this context for generating code in `namespace`.
+    pub fn with<'c, T>(&'c self, namespace: &'c [Ident<'a>], item: &'c T) -> InContext<'c, 'a, T> {
+        InContext {
+            context: self,
+            namespace,
+            item,
+        }
+    }
+}
+
+/// A schema item whose generated code depends on the rest of the schema.
+pub struct InContext<'c, 'a, T> {
+    pub context: &'c Context<'a>,
+
+    /// The namespace the item resides in.
+    pub namespace: &'c [Ident<'a>],
+
+    pub item: &'c T,
+}
+
+impl<'a, T> InContext<'_, 'a, T> {
+    /// Find the type `ident` refers to from the namespace of the item.
+    pub(crate) fn resolve(&self, ident: &DottedIdent) -> Option<Symbol<'a>> {
+        self.context.symbols.resolve(self.namespace, ident)
+    }
+
+    /// Return the union `ty` refers to, if any.
+    pub(crate) fn union_of(&self, ty: &Type) -> Option<(Symbol<'a>, &'a Union<'a>)> {
+        match ty {
+            Type::Ident(ident) => self
+                .resolve(ident)
+                .and_then(|symbol| match symbol.declaration {
+                    Declaration::Union(union) => Some((symbol, union)),
+                    _ => None,
+                }),
+            _ => None,
+        }
+    }
+
+    /// Check whether `ty` refers to a table.
+    fn is_table(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Ident(ident) => matches!(
+                self.resolve(ident),
+                Some(Symbol {
+                    declaration: Declaration::Table(_),
+                    ..
+                })
+            ),
+            _ => false,
+        }
+    }
+
+    /// The path to the type `ident` refers to from the module of the item.
+    ///
+    /// Types that can't be resolved are left as written.
+    fn path(&self, ident: &DottedIdent) -> TokenStream {
+        match self.resolve(ident) {
+            Some(symbol) => DottedIdent::from(symbol.path_from(self.namespace)).into_token_stream(),
+            None => ident.into_token_stream(),
+        }
+    }
+
+    /// The Rust type of `ty`, as seen from the module of the item.
+    fn ty(&self, ty: &Type) -> TokenStream {
+        match ty {
+            Type::Ident(ident) => self.path(ident),
+            Type::Array(ty) => {
+                let ty = self.ty(ty);
+                quote!(Vec<#ty>)
+            }
+            Type::FixedArray(ty, length) => {
+                let ty = self.ty(ty);
+                let length = proc_macro2::Literal::usize_unsuffixed(*length);
+                quote!([#ty; #length])
+            }
+            _ => ty.into_token_stream(),
+        }
+    }
+
+    /// Convert a `types::Type` to a type with the supplied wrapper for reference types
+    fn to_type(
+        &self,
+        ty: &Type,
+        lifetime: impl ToTokens,
+        wrap_refs_types: impl ToTokens,
+    ) -> TokenStream {
+        let wrap_tokens = wrap_refs_types.into_token_stream();
+        let wrap = |ty: TokenStream| {
             if wrap_tokens.is_empty() {
-                quote!(&#lifetime str)
+                ty
             } else {
-                quote!(#wrap_tokens::<&#lifetime str>)
+                quote!(#wrap_tokens::<#ty>)
+            }
+        };
+        match ty {
+            Type::String => wrap(quote!(&#lifetime str)),
+            Type::Array(element) => {
+                let lifetime = lifetime.into_token_stream();
+                let element = self.vector_element(element, &lifetime);
+                wrap(quote!(flatbuffers::Vector<#lifetime, #element>))
+            }
+            Type::Ident(ident) if self.is_table(ty) => {
+                let path = self.path(ident);
+                wrap(quote!(#path<#lifetime>))
+            }
+            _ => self.ty(ty),
+        }
+    }
+
+    /// The type of the elements of a vector of `ty`, as stored in the vector.
+    ///
+    /// Strings and tables are stored as offsets to them, everything else is stored inline.
+    fn vector_element(&self, ty: &Type, lifetime: &TokenStream) -> TokenStream {
+        if ty.is_reference() || self.is_table(ty) {
+            self.to_type(ty, lifetime, quote!(flatbuffers::ForwardsUOffset))
+        } else {
+            self.ty(ty)
+        }
+    }
+
+    /// Check whether a vector of `ty` can be read as a slice.
+    ///
+    /// That's the case for structs, which are always stored little-endian, and for single byte
+    /// scalars, which have no byte order.
+    fn is_slice_element(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Bool | Type::Byte | Type::UByte | Type::Int8 | Type::UInt8 => true,
+            Type::Ident(ident) => matches!(
+                self.resolve(ident),
+                Some(Symbol {
+                    declaration: Declaration::Struct(_),
+                    ..
+                })
+            ),
+            _ => false,
+        }
+    }
+
+    /// The type that reads a table field of type `ty` out of a buffer, as seen from the module of
+    /// the item.
+    fn wire_type(&self, ty: &Type) -> TokenStream {
+        match ty {
+            Type::String => quote!(flatbuffers::ForwardsUOffset<&str>),
+            Type::Array(ty) => {
+                let element = self.vector_element(ty, &quote!('_));
+                quote!(flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, #element>>)
             }
+            Type::Ident(_) if self.is_table(ty) => {
+                self.to_type(ty, quote!('_), quote!(flatbuffers::ForwardsUOffset))
+            }
+            Type::Ident(ident) => self.path(ident),
+            _ => ty.into_token_stream(),
+        }
+    }
+
+    /// The size and alignment of `ty` when stored inline, or `None` if it's stored as an offset.
+    pub(crate) fn inline_layout(&self, ty: &Type) -> Option<(usize, usize)> {
+        self.inline_layout_in(ty, &mut Vec::new())
+    }
+
+    /// The size and alignment of `ty` as a field of the structs in `enclosing`, which are being
+    /// laid out.
+    ///
+    /// A struct that contains itself has no layout, and validation rejects it, but laying it out
+    /// mustn't recurse forever before then.
+    fn inline_layout_in(
+        &self,
+        ty: &Type,
+        enclosing: &mut Vec<*const ()>,
+    ) -> Option<(usize, usize)> {
+        match ty {
+            Type::Ident(ident) => {
+                let symbol = self.resolve(ident)?;
+                match symbol.declaration {
+                    Declaration::Enum(e) => e.base_type.size().map(|size| (size, size)),
+                    Declaration::Struct(s) if !enclosing.contains(&struct_ptr(s)) => {
+                        let (_, size, align) =
+                            self.context.with(symbol.namespace, s).layout_in(enclosing);
+                        Some((size, align))
+                    }
+                    _ => None,
+                }
+            }
+            Type::FixedArray(element, length) => self
+                .inline_layout_in(element, enclosing)
+                .map(|(size, align)| (size * length, align)),
+            _ => ty.size().map(|size| (size, size)),
+        }
+    }
+
+    /// The value a scalar or enum field of a table reads as when it isn't set in the buffer.
+    ///
+    /// That's the default given in the schema, or zero when there isn't one. Optional scalars,
+    /// declared with `= null`, and fields that aren't scalars or enums have no default.
+    fn default_value(&self, field: &Field) -> Option<TokenStream> {
+        let default = match field.default {
+            Some(DefaultValue::Null) => return None,
+            Some(default) => default,
+            None => DefaultValue::Scalar(Scalar::Integer(0)),
+        };
+        match &field.ty {
+            Type::Ident(ident) => match self.resolve(ident)?.declaration {
+                Declaration::Enum(e) if e.is_bit_flags() => {
+                    let path = self.path(ident);
+                    match default {
+                        DefaultValue::Ident(flag) => Some(quote!(#path::#flag)),
+                        DefaultValue::Scalar(Scalar::Integer(bits)) => {
+                            let bits = lit_int(bits, e.base_type.to_token_stream());
+                            Some(quote!(#path::from_bits_retain(#bits)))
+                        }
+                        _ => Some(quote!(#path::empty())),
+                    }
+                }
+                Declaration::Enum(e) => {
+                    let (variant, _) = enum_values(&e.values, 0)
+                        .find(|(variant, variant_value)| match default {
+                            DefaultValue::Scalar(Scalar::Integer(value)) => *variant_value == value,
+                            DefaultValue::Ident(name) => *variant == name,
+                            _ => false,
+                        })
+                        .expect("validation rejects enum defaults that aren't values of the enum");
+                    let path = self.path(ident);
+                    Some(quote!(#path::#variant))
+                }
+                _ => None,
+            },
+            ty if ty.is_scalar() => match default {
+                DefaultValue::Scalar(scalar) => Some(scalar_literal(ty, scalar)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The path to the discriminant enum of the union declared by `symbol`.
+    fn union_type_path(&self, symbol: &Symbol, union: &Union) -> TokenStream {
+        let mut path = symbol.path_from(self.namespace);
+        path.pop();
+        let type_id = union_type_id(union);
+        quote!(#(#path::)*#type_id)
+    }
+
+    /// The path to the table `variant` of the union declared by `symbol` refers to.
+    fn union_variant_path(&self, symbol: &Symbol, variant: &EnumVal) -> TokenStream {
+        let ident = DottedIdent::from(vec![variant.id]);
+        match self.context.symbols.resolve(symbol.namespace, &ident) {
+            Some(variant) => {
+                DottedIdent::from(variant.path_from(self.namespace)).into_token_stream()
+            }
+            None => ident.into_token_stream(),
         }
-        // TODO other reference types?
-        _ => quote!(#ty),
+    }
+}
+
+#[cfg(test)]
+mod context_tests {
+    use super::*;
+    use crate::parser::schema_decl;
+
+    #[test]
+    fn test_lookup() {
+        let input = "\
+table A { x: int; }
+namespace foo.bar;
+table B { x: int; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let context = Context::new(&schemas);
+        let foo_bar = [Ident::from("foo"), Ident::from("bar")];
+
+        let result = context.lookup(&foo_bar, &DottedIdent::from(vec!["B".into()]));
+        assert_eq!(
+            result,
+            Some(Declaration::Table(&table!(B, [field!(x, Int)])))
+        );
+
+        let result = context.lookup(&foo_bar, &DottedIdent::from(vec!["A".into()]));
+        assert_eq!(
+            result,
+            Some(Declaration::Table(&table!(A, [field!(x, Int)])))
+        );
+    }
+
+    #[test]
+    fn test_visit_cross_namespace_references() {
+        let input = "\
+namespace foo.bar;
+struct A { x: int; }
+namespace foo.baz;
+struct B { a: foo.bar.A; }
+namespace qux;
+struct C { a: foo.bar.A; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("a : super :: bar :: A"));
+        assert!(result.contains("a : super :: foo :: bar :: A"));
     }
 }
 
@@ -101,89 +465,185 @@ fn offset_id(field: &Field) -> impl ToTokens {
     format_ident!("VT_{}", field.id.as_ref().to_shouty_snake_case())
 }
 
-impl ToTokens for Table<'_> {
+/// The offset constant of the hidden field holding the discriminant of a union field.
+fn union_type_offset_id(field: &Field) -> impl ToTokens {
+    format_ident!("VT_{}_TYPE", field.id.as_ref().to_shouty_snake_case())
+}
+
+/// The name of the enum holding the discriminants of `union`.
+fn union_type_id(union: &Union) -> syn::Ident {
+    format_ident!("{}Type", union.id.raw)
+}
+
+impl InContext<'_, '_, Table<'_>> {
+    /// The index of the vtable slot of every field of the table.
+    ///
+    /// Fields are numbered in the order they're declared, unless they're given explicit ids with
+    /// the `id` attribute. A union field occupies two slots: the one of its value, and the one
+    /// before it for the type of the value.
+    pub(crate) fn slots(&self) -> Vec<VOffsetT> {
+        let fields = &self.item.fields;
+        let ids = fields
+            .iter()
+            .map(Field::explicit_id)
+            .collect::<Option<Vec<_>>>();
+        match ids {
+            Some(ids) if !ids.is_empty() => ids.into_iter().map(|id| id as VOffsetT).collect(),
+            _ => fields
+                .iter()
+                .scan(0, |next, field| {
+                    *next += if self.union_of(&field.ty).is_some() {
+                        2
+                    } else {
+                        1
+                    };
+                    Some(*next - 1)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ToTokens for InContext<'_, '_, Table<'_>> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
+        let Table {
             id: struct_id,
             fields,
             doc,
             ..
-        } = self;
+        } = self.item;
+
+        // Deprecated fields keep their vtable slots, so that the fields after them stay where they
+        // are, but are otherwise left out of the generated code.
+        let (fields, slots): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .zip(self.slots())
+            .filter(|(field, _)| !field.is_deprecated())
+            .unzip();
 
         let raw_struct_name = struct_id.raw;
 
-        let builder_add_calls = fields.iter().map(|Field { id: field_id, .. }| {
-            let raw_field_name = field_id.raw;
-            let add_field_method = format_ident!("add_{}", raw_field_name);
-            quote!(builder.#add_field_method(args.#field_id);)
+        let builder_add_calls = fields.iter().map(|field| {
+            let field_id = &field.id;
+            let add_field_method = format_ident!("add_{}", field_id.raw);
+            if self.union_of(&field.ty).is_some() {
+                let field_type_id = format_ident!("{}_type", field_id.raw);
+                let add_field_type_method = format_ident!("add_{}", field_type_id);
+                quote! {
+                    builder.#add_field_type_method(args.#field_type_id);
+                    if let Some(x) = args.#field_id {
+                        builder.#add_field_method(x);
+                    }
+                }
+            } else if self.default_value(field).is_some() {
+                quote!(builder.#add_field_method(args.#field_id);)
+            } else {
+                quote! {
+                    if let Some(x) = args.#field_id {
+                        builder.#add_field_method(x);
+                    }
+                }
+            }
         });
 
         let args = format_ident!("{}Args", raw_struct_name);
-        let args_fields = fields.iter().map(
-            |Field {
-                 id: field_id,
-                 ty,
-                 scalar,
-                 ..
-             }| {
-                let arg_ty = to_type(ty, quote!('a), quote!(flatbuffers::WIPOffset));
-                // Scalar fields can have a default value
-                let default = if let Some(default_value) = scalar {
-                    quote!(#[default = #default_value])
-                } else {
-                    quote!()
-                };
-                quote! {
-                    #default
-                    pub #field_id: #arg_ty
-                }
-            },
-        );
 
-        let builder_type = format_ident!("{}Builder", struct_id.raw);
+        // Only fields that point elsewhere in the buffer borrow from the builder, so only give
+        // the args struct a lifetime parameter when at least one of those is present.
+        let args_lifetime = if fields
+            .iter()
+            .any(|Field { ty, .. }| ty.is_reference() || self.is_table(ty))
+        {
+            quote!(<'a>)
+        } else {
+            quote!()
+        };
+        let args_create_lifetime = if args_lifetime.is_empty() {
+            quote!()
+        } else {
+            quote!(<'args>)
+        };
 
-        // TODO: field accessors
-        // TODO: check the impl of offset generation
-        // TODO: unions
-        // TODO: testing this is going to be fun
-        let field_nested_flatbuffers = fields.iter().filter_map(|Field { id: field_id, metadata, .. }| {
-            let method_name = format_ident!("{}_nested_flatbuffer", field_id.raw);
-            if let Some(metadata) = metadata {
-                if metadata.values.contains_key(&Ident::from("nested_flatbuffer")) {
-                    Some(quote! {
-                        pub fn #method_name(&self) -> Option<Self> {
-                            self.#field_id.map(|data| <flatbuffers::ForwardsUOffset<Self>>::follow(data, 0))
-                        }
-                    })
-                } else {
-                    None
-                }
+        // Fields with a default are always passed, every other field may be left out.
+        let args_fields = fields.iter().map(|field| {
+            let Field {
+                id: field_id, ty, ..
+            } = field;
+            if let Some((symbol, union)) = self.union_of(ty) {
+                let field_type_id = format_ident!("{}_type", field_id.raw);
+                let union_type = self.union_type_path(&symbol, union);
+                return quote! {
+                    pub #field_type_id: #union_type,
+                    pub #field_id: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>
+                };
+            }
+            let arg_ty = self.to_type(ty, quote!('a), quote!(flatbuffers::WIPOffset));
+            if self.default_value(field).is_some() {
+                quote!(pub #field_id: #arg_ty)
             } else {
-                None
+                quote!(pub #field_id: Option<#arg_ty>)
+            }
+        });
+        let args_defaults = fields.iter().map(|field| {
+            let field_id = &field.id;
+            if let Some((symbol, union)) = self.union_of(&field.ty) {
+                let field_type_id = format_ident!("{}_type", field_id.raw);
+                let union_type = self.union_type_path(&symbol, union);
+                return quote! {
+                    #field_type_id: #union_type::NONE,
+                    #field_id: None
+                };
+            }
+            match self.default_value(field) {
+                Some(default) => quote!(#field_id: #default),
+                None => quote!(#field_id: None),
             }
         });
 
+        let builder_type = format_ident!("{}Builder", struct_id.raw);
+
         let builder_field_methods = fields.iter().map(|field| {
             let Field {
-                id: field_id,
-                ty,
-                scalar,
-                ..
+                id: field_id, ty, ..
             } = field;
             let add_method_name = format_ident!("add_{}", field_id.raw);
             let offset = offset_id(&field);
             let field_offset = quote!(#struct_id::#offset);
-            let arg_ty = to_type(ty, quote!('_), quote!(flatbuffers::WIPOffset));
-            let body = if ty.is_scalar() {
-                if let Some(default_value) = scalar {
-                    quote!(self.fbb.push_slot<#arg_ty>(#field_offset, #field_id, #default_value))
-                } else {
-                    quote!(self.fbb.push_slot_always::<#arg_ty>(#field_offset, #field_id))
-                }
+            if let Some((symbol, union)) = self.union_of(ty) {
+                let field_type_id = format_ident!("{}_type", field_id.raw);
+                let add_type_method_name = format_ident!("add_{}", field_type_id);
+                let type_offset = union_type_offset_id(field);
+                let union_type = self.union_type_path(&symbol, union);
+                return quote! {
+                    #[inline]
+                    fn #add_type_method_name(&mut self, #field_type_id: #union_type) {
+                        self.fbb.push_slot::<#union_type>(
+                            #struct_id::#type_offset,
+                            #field_type_id,
+                            #union_type::NONE
+                        );
+                    }
+
+                    #[inline]
+                    fn #add_method_name(
+                        &mut self,
+                        #field_id: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>
+                    ) {
+                        self.fbb.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                            #field_offset,
+                            #field_id
+                        );
+                    }
+                };
+            }
+            let arg_ty = self.to_type(ty, quote!('_), quote!(flatbuffers::WIPOffset));
+            // A field that's equal to its default is left out of the buffer
+            let body = if let Some(default) = self.default_value(field) {
+                quote!(self.fbb.push_slot::<#arg_ty>(#field_offset, #field_id, #default))
             } else {
                 quote!(self.fbb.push_slot_always::<#arg_ty>(#field_offset, #field_id))
             };
-            let arg_ty = to_type(ty, quote!('b), quote!(flatbuffers::WIPOffset));
+            let arg_ty = self.to_type(ty, quote!('b), quote!(flatbuffers::WIPOffset));
             quote! {
                 #[inline]
                 fn #add_method_name(&mut self, #field_id: #arg_ty) {
@@ -192,44 +652,288 @@ impl ToTokens for Table<'_> {
             }
         });
 
-        let field_offset_constants = fields.iter().enumerate().map(|(index, field)| {
+        // A union field occupies two vtable slots: the first one holds the discriminant of the
+        // union, the second one the offset of the value.
+        let field_offset_constants = fields.iter().zip(slots).map(|(field, slot)| {
             let offset_name = offset_id(&field);
-            let offset_value = flatbuffers::field_index_to_field_offset(index as VOffsetT);
-            quote! {
-                pub const #offset_name: flatbuffers::VOffsetT = #offset_value;
+            let offset_value = flatbuffers::field_index_to_field_offset(slot);
+            if self.union_of(&field.ty).is_some() {
+                let type_offset_name = union_type_offset_id(field);
+                let type_offset_value = flatbuffers::field_index_to_field_offset(slot - 1);
+                quote! {
+                    pub const #type_offset_name: flatbuffers::VOffsetT = #type_offset_value;
+                    pub const #offset_name: flatbuffers::VOffsetT = #offset_value;
+                }
+            } else {
+                quote! {
+                    pub const #offset_name: flatbuffers::VOffsetT = #offset_value;
+                }
             }
         });
 
         let field_accessors = fields.iter().map(|field| {
-            let snake_name = format_ident!("{}", field.id.as_ref().to_snake_case());
+            let snake_name = rust_ident(&field.id.as_ref().to_snake_case());
             let offset_name = offset_id(&field);
             let ty = &field.ty;
-            let ty_simple_lifetime = to_type(ty, quote!('a), quote!());
-            let ty_wrapped = to_type(ty, quote!(), quote!(flatbuffers::ForwardsUOffset));
 
-            quote! {
-                #[inline]
-                pub fn #snake_name(&self) -> Option<#ty_simple_lifetime> {
-                    self.table
-                        .get::<#ty_wrapped>(#struct_id::#offset_name, None)
+            if let Some((symbol, union)) = self.union_of(ty) {
+                let type_snake_name = format_ident!("{}_type", snake_name);
+                let type_offset_name = union_type_offset_id(field);
+                let union_type = self.union_type_path(&symbol, union);
+                let variant_accessors = union.values.iter().map(|variant| {
+                    let variant_id = variant.id;
+                    let method_name = format_ident!(
+                        "{}_as_{}",
+                        snake_name,
+                        variant_id.as_ref().to_snake_case()
+                    );
+                    let variant_path = self.union_variant_path(&symbol, variant);
+                    quote! {
+                        #[inline]
+                        pub fn #method_name(&self) -> Option<#variant_path<'a>> {
+                            if self.#type_snake_name() == #union_type::#variant_id {
+                                self.#snake_name().map(#variant_path::from)
+                            } else {
+                                None
+                            }
+                        }
+                    }
+                });
+                return quote! {
+                    #[inline]
+                    pub fn #type_snake_name(&self) -> #union_type {
+                        self.table
+                            .get::<#union_type>(#struct_id::#type_offset_name, Some(#union_type::NONE))
+                            .unwrap()
+                    }
+
+                    #[inline]
+                    pub fn #snake_name(&self) -> Option<flatbuffers::Table<'a>> {
+                        self.table
+                            .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                                #struct_id::#offset_name,
+                                None
+                            )
+                    }
+
+                    #(#variant_accessors)*
+                };
+            }
+
+            let ty_simple_lifetime = self.to_type(ty, quote!('a), quote!());
+            let ty_wrapped = self.to_type(ty, quote!('a), quote!(flatbuffers::ForwardsUOffset));
+
+            match ty {
+                Type::Array(element) if self.is_slice_element(element) => {
+                    let element = self.ty(element);
+                    if field.is_required() {
+                        quote! {
+                            #[inline]
+                            pub fn #snake_name(&self) -> &'a [#element] {
+                                self.table
+                                    .get::<#ty_wrapped>(#struct_id::#offset_name, None)
+                                    .unwrap()
+                                    .safe_slice()
+                            }
+                        }
+                    } else {
+                        quote! {
+                            #[inline]
+                            pub fn #snake_name(&self) -> Option<&'a [#element]> {
+                                self.table
+                                    .get::<#ty_wrapped>(#struct_id::#offset_name, None)
+                                    .map(|vector| vector.safe_slice())
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(default) = self.default_value(field) {
+                        quote! {
+                            #[inline]
+                            pub fn #snake_name(&self) -> #ty_simple_lifetime {
+                                self.table
+                                    .get::<#ty_wrapped>(#struct_id::#offset_name, Some(#default))
+                                    .unwrap()
+                            }
+                        }
+                    } else if field.is_required() {
+                        // the verifier checks required fields are present
+                        quote! {
+                            #[inline]
+                            pub fn #snake_name(&self) -> #ty_simple_lifetime {
+                                self.table
+                                    .get::<#ty_wrapped>(#struct_id::#offset_name, None)
+                                    .unwrap()
+                            }
+                        }
+                    } else {
+                        quote! {
+                            #[inline]
+                            pub fn #snake_name(&self) -> Option<#ty_simple_lifetime> {
+                                self.table
+                                    .get::<#ty_wrapped>(#struct_id::#offset_name, None)
+                            }
+                        }
+                    }
                 }
             }
         });
 
-        let struct_offset_enum_name = format_ident!("{}Offset", struct_id.raw);
-
-        let required_fields = fields.iter().map(|field| {
-            let snake_name = field.id.as_ref().to_snake_case();
+        let field_verifiers = fields.iter().map(|field| {
+            let name = field.id.raw;
             let offset_name = offset_id(field);
+            let required = field.is_required();
+            if let Some((symbol, union)) = self.union_of(&field.ty) {
+                let type_name = format!("{}_type", name);
+                let type_offset_name = union_type_offset_id(field);
+                let union_type = self.union_type_path(&symbol, union);
+                let variants = union.values.iter().map(|variant| {
+                    let variant_id = variant.id;
+                    let variant_path = self.union_variant_path(&symbol, variant);
+                    quote! {
+                        #union_type::#variant_id => <
+                            flatbuffers::ForwardsUOffset<#variant_path<'_>> as flatbuffers::Verifiable
+                        >::run_verifier(v, pos)
+                    }
+                });
+                return quote! {
+                    .visit_union::<#union_type, _>(
+                        #type_name,
+                        Self::#type_offset_name,
+                        #name,
+                        Self::#offset_name,
+                        #required,
+                        |key, v, pos| match key {
+                            #(#variants,)*
+                            // nothing is known about the value of a variant added by a newer
+                            // version of the schema
+                            _ => Ok(()),
+                        },
+                    )?
+                };
+            }
+            let wire_type = self.wire_type(&field.ty);
             quote! {
-                self.fbb.required(o, #struct_id::#offset_name, #snake_name);
+                .visit_field::<#wire_type>(#name, Self::#offset_name, #required)?
+            }
+        });
+
+        // A field marked as the key of the table lets sorted vectors of the table be searched
+        let key_compare = fields
+            .iter()
+            .find(|field| field.has_attribute("key"))
+            .map(|field| {
+                let snake_name = rust_ident(&field.id.as_ref().to_snake_case());
+                let ty = &field.ty;
+                let value_ty = match ty {
+                    Type::String => quote!(&str),
+                    _ => self.ty(ty),
+                };
+                let compare = match ty {
+                    Type::String => quote!(key.cmp(val)),
+                    Type::Float | Type::Float32 | Type::Double | Type::Float64 => quote! {
+                        key.partial_cmp(&val).unwrap_or(std::cmp::Ordering::Equal)
+                    },
+                    _ => quote!(key.cmp(&val)),
+                };
+                // a key that isn't set sorts before every other key
+                let compare = if self.default_value(field).is_some() || field.is_required() {
+                    quote! {
+                        let key = self.#snake_name();
+                        #compare
+                    }
+                } else {
+                    quote! {
+                        match self.#snake_name() {
+                            Some(key) => #compare,
+                            None => std::cmp::Ordering::Less,
+                        }
+                    }
+                };
+                quote! {
+                    impl #struct_id<'_> {
+                        #[inline]
+                        pub fn key_compare_less_than(&self, other: &#struct_id) -> bool {
+                            self.#snake_name() < other.#snake_name()
+                        }
+
+                        #[inline]
+                        pub fn key_compare_with_value(&self, val: #value_ty) -> std::cmp::Ordering {
+                            #compare
+                        }
+                    }
+
+                    impl flatbuffers::KeyedTable for #struct_id<'_> {
+                        #[inline]
+                        fn key_less_than(a: flatbuffers::Table, b: flatbuffers::Table) -> bool {
+                            #struct_id::from(a).key_compare_less_than(&#struct_id::from(b))
+                        }
+                    }
+                }
+            });
+
+        // Only the fields present in the buffer are shown, along with every field that has a
+        // default, since reading those never fails.
+        let debug_fields = fields.iter().map(|field| {
+            let name = field.id.raw;
+            let snake_name = rust_ident(&field.id.as_ref().to_snake_case());
+            if let Some((_, union)) = self.union_of(&field.ty) {
+                let type_name = format!("{}_type", name);
+                let type_snake_name = format_ident!("{}_type", snake_name);
+                // at most one of the variants matches the type, and a variant added by a newer
+                // version of the schema only shows its type
+                let variants = union.values.iter().map(|variant| {
+                    let method_name =
+                        format_ident!("{}_as_{}", snake_name, variant.id.as_ref().to_snake_case());
+                    quote! {
+                        if let Some(x) = self.#method_name() {
+                            ds.field(#name, &x);
+                        }
+                    }
+                });
+                return quote! {
+                    ds.field(#type_name, &self.#type_snake_name());
+                    #(#variants)*
+                };
+            }
+            if self.default_value(field).is_some() || field.is_required() {
+                quote!(ds.field(#name, &self.#snake_name());)
+            } else {
+                quote! {
+                    if let Some(x) = self.#snake_name() {
+                        ds.field(#name, &x);
+                    }
+                }
             }
         });
+        let debug_fmt = if fields.is_empty() {
+            quote!(f.debug_struct(#raw_struct_name).finish())
+        } else {
+            quote! {
+                let mut ds = f.debug_struct(#raw_struct_name);
+                #(#debug_fields)*
+                ds.finish()
+            }
+        };
+
+        let struct_offset_enum_name = format_ident!("{}Offset", struct_id.raw);
+
+        let required_fields = fields
+            .iter()
+            .filter(|field| field.is_required())
+            .map(|field| {
+                let snake_name = field.id.as_ref().to_snake_case();
+                let offset_name = offset_id(field);
+                quote! {
+                    self.fbb.required(o, #struct_id::#offset_name, #snake_name);
+                }
+            });
 
         (quote! {
             pub enum #struct_offset_enum_name {}
 
-            #[derive(Copy, Clone, Debug, PartialEq)]
+            #[derive(Copy, Clone, PartialEq)]
             #doc
             pub struct #struct_id<'a> {
                 table: flatbuffers::Table<'a>,
@@ -244,7 +948,7 @@ impl ToTokens for Table<'_> {
             impl<'a> #struct_id<'a> {
                 pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
                     fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-                    args: &'args #args<'args>
+                    args: &'args #args#args_create_lifetime
                 ) -> flatbuffers::WIPOffset<#struct_id<'bldr>> {
                     let mut builder = #builder_type::new(fbb);
                     #(#builder_add_calls)*
@@ -256,9 +960,6 @@ impl ToTokens for Table<'_> {
 
                 // fields access
                 #(#field_accessors)*
-
-                // nested flatbuffers if applicable
-                #(#field_nested_flatbuffers)*
             }
 
             impl<'a> flatbuffers::Follow<'a> for #struct_id<'a> {
@@ -271,13 +972,41 @@ impl ToTokens for Table<'_> {
                 }
             }
 
+            impl std::fmt::Debug for #struct_id<'_> {
+                fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    #debug_fmt
+                }
+            }
+
+            #key_compare
+
+            impl flatbuffers::Verifiable for #struct_id<'_> {
+                #[inline]
+                fn run_verifier(
+                    v: &mut flatbuffers::Verifier,
+                    pos: usize,
+                ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                    v.visit_table(pos)?
+                        #(#field_verifiers)*
+                        .finish();
+                    Ok(())
+                }
+            }
+
             // Builder Args
-            // TODO: Can't use this because we can mix fields that are
-            // default-able with those that are not
-            pub struct #args<'a> {
+            pub struct #args#args_lifetime {
                 #(#args_fields),*
             }
 
+            impl#args_lifetime Default for #args#args_lifetime {
+                #[inline]
+                fn default() -> Self {
+                    Self {
+                        #(#args_defaults),*
+                    }
+                }
+            }
+
             //// builder
             pub struct #builder_type<'a, 'b> {
                 fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
@@ -317,9 +1046,537 @@ mod product_type_tests {
             MyMessage,
             [field!(message, String), field!(foo, Float64 = 2.0)]
         );
-        let result = to_code(table);
+        let result = to_code(Context::default().with(&[], &table));
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_visit_product_type_table_nested_table() {
+        let input = "\
+table Greeting { text: string; }
+table HelloReply { inner: Greeting; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub struct HelloReplyArgs < 'a >"));
+        assert!(result
+            .contains("pub inner : Option < flatbuffers :: WIPOffset :: < Greeting < 'a > > >"));
+        assert!(result.contains("pub fn inner (& self) -> Option < Greeting < 'a > >"));
+        assert!(!result.contains("self . fbb . required (o , HelloReply :: VT_INNER"));
+    }
+
+    #[test]
+    fn test_visit_product_type_table_vectors() {
+        let input = "\
+struct Point { x: int; }
+table Item { x: int; }
+table Inventory { bytes: [ubyte]; counts: [int]; names: [string]; points: [Point]; items: [Item]; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub fn bytes (& self) -> Option < & 'a [u8] >"));
+        assert!(result
+            .contains("pub fn counts (& self) -> Option < flatbuffers :: Vector < 'a , i32 > >"));
+        assert!(result.contains(
+            "pub fn names (& self) -> Option < flatbuffers :: Vector < 'a , flatbuffers :: ForwardsUOffset :: < & 'a str > > >"
+        ));
+        assert!(result.contains("pub fn points (& self) -> Option < & 'a [Point] >"));
+        assert!(result.contains(
+            "pub fn items (& self) -> Option < flatbuffers :: Vector < 'a , flatbuffers :: ForwardsUOffset :: < Item < 'a > > > >"
+        ));
+        assert!(result.contains(
+            "pub counts : Option < flatbuffers :: WIPOffset :: < flatbuffers :: Vector < 'a , i32 > > >"
+        ));
+    }
+
+    #[test]
+    fn test_visit_product_type_table_ids() {
+        let input = "\
+table A { x: int; }
+union U { A }
+table B { x: int (id: 2); u: U (id: 1); y: string (id: 3); }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub const VT_U_TYPE : flatbuffers :: VOffsetT = 4i16 ;"));
+        assert!(result.contains("pub const VT_U : flatbuffers :: VOffsetT = 6i16 ;"));
+        assert!(result.contains("pub const VT_X : flatbuffers :: VOffsetT = 8i16 ;"));
+        assert!(result.contains("pub const VT_Y : flatbuffers :: VOffsetT = 10i16 ;"));
+    }
+
+    #[test]
+    fn test_visit_product_type_table_deprecated() {
+        let input = "table A { x: int; y: int (deprecated); z: string; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub const VT_X : flatbuffers :: VOffsetT = 4i16 ;"));
+        assert!(result.contains("pub const VT_Z : flatbuffers :: VOffsetT = 8i16 ;"));
+        assert!(!result.contains("VT_Y"));
+        assert!(!result.contains("pub fn y"));
+        assert!(!result.contains("fn add_y"));
+        assert!(!result.contains("pub y :"));
+    }
+
+    #[test]
+    fn test_visit_product_type_table_debug() {
+        let input = "\
+table A { x: int; }
+union U { A }
+table B { name: string (required); hp: short = 100; a: A; u: U; }
+table C { x: int (deprecated); }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("impl std :: fmt :: Debug for B < '_ >"));
+        assert!(!result.contains("# [derive (Copy , Clone , Debug , PartialEq)]"));
+        assert!(result.contains("ds . field (\"name\" , & self . name ()) ;"));
+        assert!(result.contains("ds . field (\"hp\" , & self . hp ()) ;"));
+        assert!(result.contains("if let Some (x) = self . a () { ds . field (\"a\" , & x) ; }"));
+        assert!(result.contains("ds . field (\"u_type\" , & self . u_type ()) ;"));
+        assert!(
+            result.contains("if let Some (x) = self . u_as_a () { ds . field (\"u\" , & x) ; }")
+        );
+        assert!(result.contains("f . debug_struct (\"C\") . finish ()"));
+    }
+
+    #[test]
+    fn test_visit_product_type_table_key() {
+        let input = "\
+table A { name: string (key); }
+table B { id: ulong (key); }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub fn key_compare_less_than (& self , other : & A) -> bool"));
+        assert!(result.contains(
+            "pub fn key_compare_with_value (& self , val : & str) -> std :: cmp :: Ordering"
+        ));
+        assert!(result.contains("Some (key) => key . cmp (val) ,"));
+        assert!(result.contains(
+            "pub fn key_compare_with_value (& self , val : u64) -> std :: cmp :: Ordering"
+        ));
+        assert!(result.contains("impl flatbuffers :: KeyedTable for B < '_ >"));
+    }
+
+    #[test]
+    fn test_visit_product_type_table_defaults() {
+        let input = "\
+enum Color : byte { Red = -1, Green, Blue }
+table Monster {
+  name: string (required);
+  hp: short = 100;
+  speed: float = 1.5;
+  color: Color = 1;
+  mana: int = null;
+  friendly: bool;
+  tint: Color = Red;
+}";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub fn name (& self) -> & 'a str"));
+        assert!(result.contains("pub fn hp (& self) -> i16"));
+        assert!(result.contains("Some (100_i16)"));
+        assert!(result.contains("pub fn speed (& self) -> f32"));
+        assert!(result.contains("Some (1.5_f32)"));
+        assert!(result.contains("pub fn color (& self) -> Color"));
+        assert!(result.contains("Some (Color :: Blue)"));
+        assert!(result.contains("Some (Color :: Red)"));
+        assert!(
+            result.contains("push_slot :: < Color > (Monster :: VT_TINT , tint , Color :: Red)")
+        );
+        assert!(result.contains("pub fn mana (& self) -> Option < i32 >"));
+        assert!(result.contains("pub fn friendly (& self) -> bool"));
+        assert!(result.contains("pub mana : Option < i32 >"));
+        assert!(result.contains("impl < 'a > Default for MonsterArgs < 'a >"));
+        assert!(result.contains("self . fbb . required (o , Monster :: VT_NAME , \"name\") ;"));
+        assert!(!result.contains("self . fbb . required (o , Monster :: VT_HP"));
+    }
+}
+
+impl InContext<'_, '_, Struct<'_>> {
+    /// The offset of every field of the struct, followed by the size and alignment of the struct.
+    ///
+    /// This is the layout of the `#[repr(C)]` struct generated for it: every field is aligned to
+    /// its own alignment, and the struct is padded to the largest alignment of its fields.
+    pub(crate) fn layout(&self) -> (Vec<usize>, usize, usize) {
+        self.layout_in(&mut Vec::new())
+    }
+
+    /// The layout of the struct as a field of the structs in `enclosing`.
+    // `div_ceil` needs Rust 1.73, which is newer than the oldest toolchain CI builds with
+    #[allow(unknown_lints, clippy::manual_div_ceil)]
+    fn layout_in(&self, enclosing: &mut Vec<*const ()>) -> (Vec<usize>, usize, usize) {
+        let Struct {
+            fields, metadata, ..
+        } = self.item;
+        enclosing.push(struct_ptr(self.item));
+        let round_up = |n: usize, align: usize| (n + align - 1) / align * align;
+        let mut align = force_align(metadata).map_or(1, |align| align as usize);
+        let mut end = 0;
+        let offsets = fields
+            .iter()
+            .map(|field| {
+                let (field_size, field_align) = self
+                    .inline_layout_in(&field.ty, enclosing)
+                    .unwrap_or((0, 1));
+                let offset = round_up(end, field_align);
+                end = offset + field_size;
+                align = align.max(field_align);
+                offset
+            })
+            .collect();
+        enclosing.pop();
+        (offsets, round_up(end, align), align)
+    }
+}
+
+/// The identity of a struct declaration, to tell whether it's already being laid out.
+fn struct_ptr(s: &Struct) -> *const () {
+    s as *const Struct as *const ()
+}
+
+impl ToTokens for InContext<'_, '_, Struct<'_>> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Struct {
+            id: struct_id,
+            fields,
+            metadata,
+            doc,
+        } = self.item;
+
+        // Structs are laid out exactly like a C struct, which matches the flatbuffers rules: every
+        // field is aligned to its own size and the struct is padded to its largest alignment.
+        // `force_align` can only ever increase that alignment.
+        let repr = if let Some(alignment) = force_align(metadata) {
+            let alignment = syn::LitInt::new(&alignment.to_string(), struct_id.span());
+            quote!(#[repr(C, align(#alignment))])
+        } else {
+            quote!(#[repr(C)])
+        };
+
+        let struct_fields = fields.iter().map(
+            |Field {
+                 id: field_id, ty, ..
+             }| {
+                let ty = self.ty(ty);
+                quote! {
+                    #field_id: #ty
+                }
+            },
+        );
+
+        let constructor_args = fields.iter().map(
+            |Field {
+                 id: field_id, ty, ..
+             }| {
+                let ty = self.ty(ty);
+                quote! {
+                    #field_id: #ty
+                }
+            },
+        );
+
+        // Only bools and enums can hold invalid values, so only fields that are bools or enums,
+        // or structs that may contain them, need verifying beyond checking the struct is in the
+        // buffer.
+        let (offsets, _, _) = self.layout();
+        let field_verifiers = fields
+            .iter()
+            .zip(offsets)
+            .filter_map(|(Field { ty, .. }, offset)| {
+                let pos = match offset {
+                    0 => quote!(pos),
+                    offset => quote!(pos + #offset),
+                };
+                match ty {
+                    Type::Bool | Type::Ident(_) => {
+                        let ty = self.ty(ty);
+                        Some(quote! {
+                            <#ty as flatbuffers::Verifiable>::run_verifier(v, #pos)?;
+                        })
+                    }
+                    Type::FixedArray(element, length) => match **element {
+                        Type::Bool | Type::Ident(_) => {
+                            let element = self.ty(element);
+                            Some(quote! {
+                                <#element as flatbuffers::Verifiable>::run_verifier_many(
+                                    v,
+                                    #pos,
+                                    #length,
+                                )?;
+                            })
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let verify_many = if field_verifiers.is_empty() {
+            quote! {
+                #[inline]
+                fn run_verifier_many(
+                    _: &mut flatbuffers::Verifier,
+                    _: usize,
+                    _: usize,
+                ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                    Ok(())
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        // Every field is a scalar, an enum, a struct or an array of them, all of which are `Copy`
+        // and zero by default.
+        let default_fields = fields.iter().map(
+            |Field {
+                 id: field_id, ty, ..
+             }| match ty {
+                Type::FixedArray(_, length) => {
+                    let length = proc_macro2::Literal::usize_unsuffixed(*length);
+                    quote!(#field_id: [Default::default(); #length])
+                }
+                _ => quote!(#field_id: Default::default()),
+            },
+        );
+
+        let constructor_fields = fields.iter().map(|Field { id: field_id, .. }| {
+            quote! {
+                #field_id: flatbuffers::EndianScalar::to_little_endian(#field_id)
+            }
+        });
+
+        // Every field is stored in little-endian byte order, so reading a field means converting
+        // it back to the host's byte order. Fixed-length arrays are read in place, one element at
+        // a time.
+        let field_accessors = fields.iter().map(
+            |Field {
+                 id: field_id,
+                 ty,
+                 doc,
+                 ..
+             }| {
+                let snake_name = rust_ident(&field_id.as_ref().to_snake_case());
+                if let Type::FixedArray(element, length) = ty {
+                    let element = self.ty(element);
+                    let length = proc_macro2::Literal::usize_unsuffixed(*length);
+                    return quote! {
+                        #doc
+                        #[inline]
+                        pub fn #snake_name(&self) -> flatbuffers::Array<'_, #element, #length> {
+                            flatbuffers::Array::from(&self.#field_id)
+                        }
+                    };
+                }
+                let ty = self.ty(ty);
+                quote! {
+                    #doc
+                    #[inline]
+                    pub fn #snake_name(&self) -> #ty {
+                        flatbuffers::EndianScalar::from_little_endian(self.#field_id)
+                    }
+                }
+            },
+        );
+
+        (quote! {
+            #repr
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            #doc
+            pub struct #struct_id {
+                #(#struct_fields),*
+            }
+
+            impl #struct_id {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(#(#constructor_args),*) -> Self {
+                    Self {
+                        #(#constructor_fields),*
+                    }
+                }
+
+                #(#field_accessors)*
+            }
+
+            impl Default for #struct_id {
+                #[inline]
+                fn default() -> Self {
+                    Self {
+                        #(#default_fields),*
+                    }
+                }
+            }
+
+            // The fields of a struct are always stored in little-endian byte order, so the struct
+            // itself never needs converting.
+            impl flatbuffers::EndianScalar for #struct_id {
+                #[inline]
+                fn to_little_endian(self) -> Self {
+                    self
+                }
+
+                #[inline]
+                fn from_little_endian(self) -> Self {
+                    self
+                }
+            }
+
+            impl flatbuffers::SafeSliceAccess for #struct_id {}
+
+            impl<'a> flatbuffers::Follow<'a> for #struct_id {
+                type Inner = Self;
+
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                }
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for &'a #struct_id {
+                type Inner = Self;
+
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::follow_cast_ref::<#struct_id>(buf, loc)
+                }
+            }
+
+            impl flatbuffers::Push for #struct_id {
+                type Output = Self;
+
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<Self>(dst, *self);
+                }
+            }
+
+            impl flatbuffers::Push for &#struct_id {
+                type Output = #struct_id;
+
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<#struct_id>(dst, **self);
+                }
+            }
+
+            impl flatbuffers::Verifiable for #struct_id {
+                #[inline]
+                fn run_verifier(
+                    v: &mut flatbuffers::Verifier,
+                    pos: usize,
+                ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                    v.in_buffer::<Self>(pos)?;
+                    #(#field_verifiers)*
+                    Ok(())
+                }
+
+                #verify_many
+            }
+        })
+        .to_tokens(tokens)
+    }
+}
+
+/// Return the value of the `force_align` attribute, if present.
+fn force_align(metadata: &Option<Metadata>) -> Option<IntegerConstant> {
+    metadata
+        .as_ref()
+        .and_then(|metadata| metadata.values.get(&Ident::from("force_align")))
+        .and_then(|value| match value {
+            Some(Single::Scalar(Scalar::Integer(alignment))) => Some(*alignment),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod struct_tests {
+    use super::*;
+
+    #[test]
+    fn test_visit_struct() {
+        let s = Struct::builder()
+            .id(Ident::from("Vec3"))
+            .fields(vec![
+                field!(x, Float32),
+                field!(y, Float32),
+                field!(z, Float32),
+            ])
+            .build();
+        let result = to_code(Context::default().with(&[], &s));
+        assert!(result.starts_with("# [repr (C)]"));
+        assert!(result.contains("pub struct Vec3 { x : f32 , y : f32 , z : f32 }"));
+    }
+
+    #[test]
+    fn test_visit_struct_force_align() {
+        let s = Struct::builder()
+            .id(Ident::from("Vec3"))
+            .fields(vec![field!(x, Float32)])
+            .metadata(Some(Metadata::from(vec![meta!(force_align, 16)])))
+            .build();
+        let result = to_code(Context::default().with(&[], &s));
+        assert!(result.starts_with("# [repr (C , align (16))]"));
+    }
+
+    #[test]
+    fn test_struct_layout() {
+        let input = "\
+enum Axis : byte { X, Y, Z }
+struct Vec3 { x: float; y: float; z: float; }
+struct Ray { origin: Vec3; axis: Axis; length: double; }
+struct Aligned (force_align: 16) { a: byte; }
+struct Path { axis: Axis; points: [Vec3:2]; lengths: [double:3]; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let schemas = [schema];
+        let context = Context::new(&schemas);
+        let layout = |name| match context.lookup(&[], &DottedIdent::from(vec![Ident::from(name)])) {
+            Some(Declaration::Struct(s)) => context.with(&[], s).layout(),
+            _ => panic!("{} is not a struct", name),
+        };
+        assert_eq!(layout("Vec3"), (vec![0, 4, 8], 12, 4));
+        assert_eq!(layout("Ray"), (vec![0, 12, 16], 24, 8));
+        assert_eq!(layout("Aligned"), (vec![0], 16, 16));
+        assert_eq!(layout("Path"), (vec![0, 4, 32], 56, 8));
+    }
+
+    #[test]
+    fn test_recursive_struct_layout() {
+        // validation rejects these, but laying them out mustn't overflow the stack
+        let input = "\
+struct A { a: A; }
+struct B { x: int; c: [C:2]; }
+struct C { y: short; b: B; }
+table T { a: A; b: B; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let schemas = [schema];
+        let context = Context::new(&schemas);
+        let layout = |name| match context.lookup(&[], &DottedIdent::from(vec![Ident::from(name)])) {
+            Some(Declaration::Struct(s)) => context.with(&[], s).layout(),
+            _ => panic!("{} is not a struct", name),
+        };
+        assert_eq!(layout("A"), (vec![0], 0, 1));
+        assert_eq!(layout("B"), (vec![0, 4], 8, 4));
+        to_code(&schemas[0]);
+    }
+
+    #[test]
+    fn test_visit_struct_fixed_array() {
+        let input = "\
+enum Axis : byte { X, Y, Z }
+struct Mat { m: [float:16]; axes: [Axis:3]; on: bool; lit: [bool:2]; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains(
+            "< bool as flatbuffers :: Verifiable > :: run_verifier (v , pos + 67usize) ?"
+        ));
+        assert!(result.contains(
+            "< bool as flatbuffers :: Verifiable > :: run_verifier_many (v , pos + 68usize , 2usize ,) ?"
+        ));
+        assert!(!result.contains("< f32 as flatbuffers :: Verifiable >"));
+        assert!(result.contains(
+            "pub struct Mat { m : [f32 ; 16] , axes : [Axis ; 3] , on : bool , lit : [bool ; 2] }"
+        ));
+        assert!(result.contains("pub fn m (& self) -> flatbuffers :: Array < '_ , f32 , 16 >"));
+        assert!(result.contains(
+            "< Axis as flatbuffers :: Verifiable > :: run_verifier_many (v , pos + 64usize , 3usize ,) ?"
+        ));
+    }
 }
 
 impl ToTokens for Type<'_> {
@@ -348,6 +1605,10 @@ impl ToTokens for Type<'_> {
             Type::Float64 => quote!(f64),
             Type::String => quote!(String),
             Type::Array(ty) => quote!(Vec<#ty>),
+            Type::FixedArray(ty, length) => {
+                let length = proc_macro2::Literal::usize_unsuffixed(*length);
+                quote!([#ty; #length])
+            }
             Type::Ident(id) => quote!(#id),
         }
         .to_tokens(tokens)
@@ -377,16 +1638,18 @@ mod type_tests {
 // TODO: Properly implement this.
 // We only generate a trait method right now.
 // TODO: Figure out how this will integrate into tonic.
-impl ToTokens for RpcMethod<'_> {
+impl ToTokens for InContext<'_, '_, RpcMethod<'_>> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
+        let RpcMethod {
             id,
             request_type,
             response_type,
             doc,
             ..
-        } = self;
-        let snake_name = format_ident!("{}", id.raw.to_snake_case());
+        } = self.item;
+        let snake_name = rust_ident(&id.raw.to_snake_case());
+        let request_type = self.path(request_type);
+        let response_type = self.path(response_type);
         (quote! {
             #doc
             fn #snake_name(request: #request_type) -> #response_type;
@@ -409,34 +1672,24 @@ impl ToTokens for Comment<'_> {
 
 impl ToTokens for DottedIdent<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let parts = &self.parts;
         debug_assert!(!self.parts.is_empty());
-        let code = parts.iter().map(|e| e.raw).join("::");
-        let num_parts = parts.len();
-        let path_string = if num_parts > 1 {
-            format!(
-                "{}::{}",
-                std::iter::repeat("super").take(num_parts - 1).join("::"),
-                code
-            )
-        } else {
-            code
-        };
-        syn::parse_str::<syn::Path>(&path_string)
-            .expect("Cannot parse path")
-            .to_tokens(tokens)
+        let parts = &self.parts;
+        quote!(#(#parts)::*).to_tokens(tokens)
     }
 }
 
 // TODO: This is woefully incomplete
-impl ToTokens for Rpc<'_> {
+impl ToTokens for InContext<'_, '_, Rpc<'_>> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
+        let Rpc {
             id: Ident { raw },
             methods,
             doc,
-        } = self;
+        } = self.item;
         let service_name = format_ident!("{}Service", raw);
+        let methods = methods
+            .iter()
+            .map(|method| self.context.with(self.namespace, method));
         (quote! {
             #doc
             pub trait #service_name {
@@ -452,108 +1705,572 @@ fn lit_int(value: impl Display, base_type: impl Spanned + Display) -> impl ToTok
     syn::LitInt::new(&stringified_int, base_type.span())
 }
 
-// TODO: Unions. Though see structs, because those overlap with unions in a way
-// that isn't clear to me ATM. It seems that unions can be enum-like or
-// struct-like depending on the element types.
-impl ToTokens for Enum<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
-            id: enum_id,
-            values,
-            base_type,
-            doc,
-            ..
-        } = self;
-
-        // generate enum variant name => string name of the variant for use in
-        // a match statement
-        let names_to_strings = values.iter().map(|EnumVal { id: key, .. }| {
-            let raw_key = key.raw;
-            quote! {
-                #enum_id::#key => #raw_key
+/// A literal of the scalar type `ty` holding `value`.
+fn scalar_literal(ty: &Type, value: Scalar) -> TokenStream {
+    match ty {
+        Type::Bool => match value {
+            Scalar::Boolean(value) => quote!(#value),
+            Scalar::Integer(value) => {
+                let value = value != 0;
+                quote!(#value)
             }
-        });
+            Scalar::Float(value) => {
+                let value = value != 0.0;
+                quote!(#value)
+            }
+        },
+        Type::Float | Type::Float32 | Type::Double | Type::Float64 => {
+            let value = match value {
+                Scalar::Float(value) => value,
+                Scalar::Integer(value) => value as FloatingConstant,
+                Scalar::Boolean(value) => FloatingConstant::from(u8::from(value)),
+            };
+            let single = matches!(ty, Type::Float | Type::Float32);
+            if value.is_finite() {
+                let literal = if single {
+                    format!("{:?}_{}", value as f32, ty.to_token_stream())
+                } else {
+                    format!("{:?}_{}", value, ty.to_token_stream())
+                };
+                syn::LitFloat::new(&literal, proc_macro2::Span::call_site()).into_token_stream()
+            } else if single {
+                // there are no literals for infinities and NaN
+                let bits = (value as f32).to_bits();
+                quote!(f32::from_bits(#bits))
+            } else {
+                let bits = value.to_bits();
+                quote!(f64::from_bits(#bits))
+            }
+        }
+        _ => {
+            let value = match value {
+                Scalar::Integer(value) => value,
+                Scalar::Float(value) => value as IntegerConstant,
+                Scalar::Boolean(value) => IntegerConstant::from(value),
+            };
+            lit_int(value, ty.to_token_stream()).into_token_stream()
+        }
+    }
+}
 
-        // assign a value to the key if one was given, otherwise give it the
-        // enumerated index's value
-        let fields = values
-            .iter()
-            .enumerate()
-            .map(|(i, EnumVal { id: key, value })| {
-                // format the value with the correct type, i.e., base_type
-                let scalar_value = lit_int(
-                    if let Some(constant) = *value {
-                        constant
-                    } else {
-                        i.try_into().expect("invalid conversion to enum base type")
-                    },
-                    base_type.to_token_stream(),
-                );
-                quote! {
-                    #key = #scalar_value
-                }
-            });
+/// Assign a value to each enum value: the one given in the schema if there is one, otherwise one
+/// more than the value before it, starting at `first`.
+pub(crate) fn enum_values<'a, 'b>(
+    values: &'b [EnumVal<'a>],
+    first: IntegerConstant,
+) -> impl Iterator<Item = (Ident<'a>, IntegerConstant)> + 'b {
+    values.iter().scan(first, |next, EnumVal { id, value }| {
+        let value = value.unwrap_or(*next);
+        *next = value + 1;
+        Some((*id, value))
+    })
+}
 
-        let raw_snake_enum_name = enum_id.raw.to_snake_case();
-        let enum_id_fn_name = format_ident!("enum_name_{}", raw_snake_enum_name);
+/// Generate an enum, along with the impls needed to store it in a flatbuffer.
+///
+/// The enum is a newtype around `base_type` with an associated constant for each variant, rather
+/// than a C-style enum, so that a value a newer version of the schema added can still be read.
+/// Reading it as a C-style enum would be undefined behavior.
+fn enum_tokens(
+    enum_id: &syn::Ident,
+    values: &[(Ident, IntegerConstant)],
+    base_type: TokenStream,
+    doc: &Comment,
+) -> TokenStream {
+    // generate enum variant name => string name of the variant for use in
+    // a match statement
+    let names_to_strings = values.iter().map(|(key, _)| {
+        let raw_key = key.raw;
+        quote! {
+            #enum_id::#key => Some(#raw_key)
+        }
+    });
 
-        // TODO: Maybe separate these pieces to avoid variables that used far
-        // away from their definition.
-        (quote! {
-            // force a C-style enum
-            #[repr(#base_type)]
-            #[allow(non_camel_case_types)]
-            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-            #doc
-            pub enum #enum_id {
-                #(#fields),*
+    let variants = values.iter().map(|(key, value)| {
+        // format the value with the correct type, i.e., base_type
+        let scalar_value = lit_int(value, base_type.clone());
+        quote! {
+            pub const #key: Self = Self(#scalar_value);
+        }
+    });
+
+    let strings_to_names = values.iter().map(|(key, _)| {
+        let raw_key = key.raw;
+        quote! {
+            #raw_key => Ok(#enum_id::#key)
+        }
+    });
+
+    let raw_enum_name = enum_id.to_string();
+
+    // the values are in ascending order, which validation checks
+    let min = lit_int(
+        values.first().map_or(0, |(_, value)| *value),
+        base_type.clone(),
+    );
+    let max = lit_int(
+        values.last().map_or(0, |(_, value)| *value),
+        base_type.clone(),
+    );
+    let keys = values.iter().map(|(key, _)| key);
+
+    let raw_snake_enum_name = enum_id.to_string().to_snake_case();
+    let enum_id_fn_name = format_ident!("enum_name_{}", raw_snake_enum_name);
+
+    quote! {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #doc
+        pub struct #enum_id(pub #base_type);
+
+        #[allow(non_upper_case_globals)]
+        impl #enum_id {
+            #(#variants)*
+
+            /// The smallest value of the variants.
+            pub const ENUM_MIN: #base_type = #min;
+
+            /// The largest value of the variants.
+            pub const ENUM_MAX: #base_type = #max;
+
+            /// Every variant, in the order they're declared.
+            pub const ENUM_VALUES: &'static [Self] = &[#(Self::#keys),*];
+
+            /// The name of the variant, or `None` if the value isn't one of the variants.
+            pub fn variant_name(self) -> Option<&'static str> {
+                match self {
+                    #(#names_to_strings,)*
+                    _ => None,
+                }
             }
+        }
 
-            impl<'a> flatbuffers::Follow<'a> for #enum_id {
-                type Inner = Self;
+        // Values that aren't variants are shown as the newtype they are.
+        impl std::fmt::Debug for #enum_id {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match self.variant_name() {
+                    Some(name) => f.write_str(name),
+                    None => f.debug_tuple(#raw_enum_name).field(&self.0).finish(),
+                }
+            }
+        }
 
-                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+        // Values that aren't variants are shown as numbers.
+        impl std::fmt::Display for #enum_id {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match self.variant_name() {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "{}", self.0),
                 }
             }
+        }
 
-            impl flatbuffers::EndianScalar for #enum_id {
-                #[inline]
-                fn to_little_endian(self) -> Self {
-                    let n = #base_type::to_le(self as #base_type);
-                    let p = &n as *const #base_type as *const Self;
-                    unsafe { *p }
+        impl std::str::FromStr for #enum_id {
+            type Err = flatbuffers::UnknownVariant;
+
+            /// Parse the name of a variant.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#strings_to_names,)*
+                    _ => Err(flatbuffers::UnknownVariant {
+                        enum_name: #raw_enum_name,
+                        name: s.to_string(),
+                    }),
                 }
+            }
+        }
 
-                #[inline]
-                fn from_little_endian(self) -> Self {
-                    let n = #base_type::from_le(self as #base_type);
-                    let p = &n as *const #base_type as *const Self;
-                    unsafe { *p }
+        impl<'a> flatbuffers::Follow<'a> for #enum_id {
+            type Inner = Self;
+
+            fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                flatbuffers::read_scalar_at::<Self>(buf, loc)
+            }
+        }
+
+        impl flatbuffers::EndianScalar for #enum_id {
+            #[inline]
+            fn to_little_endian(self) -> Self {
+                Self(#base_type::to_le(self.0))
+            }
+
+            #[inline]
+            fn from_little_endian(self) -> Self {
+                Self(#base_type::from_le(self.0))
+            }
+        }
+
+        impl flatbuffers::Push for #enum_id {
+            type Output = Self;
+
+            #[inline]
+            fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                flatbuffers::emplace_scalar::<Self>(dst, *self);
+            }
+        }
+
+        // Any value can be read safely, so values that aren't variants are only rejected when the
+        // verifier is asked to.
+        impl flatbuffers::Verifiable for #enum_id {
+            #[inline]
+            fn run_verifier(
+                v: &mut flatbuffers::Verifier,
+                pos: usize,
+            ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                let value = v.get::<Self>(pos)?;
+                if Self::ENUM_VALUES.contains(&value) || !v.rejects_unknown_enum_values() {
+                    Ok(())
+                } else {
+                    Err(flatbuffers::InvalidFlatbuffer::UnknownEnumValue {
+                        enum_name: #raw_enum_name,
+                        position: pos,
+                    })
                 }
             }
+        }
 
-            impl flatbuffers::Push for #enum_id {
-                type Output = Self;
+        /// The name of the variant `e` is, or an empty string if it isn't one of the variants.
+        pub fn #enum_id_fn_name(e: #enum_id) -> &'static str {
+            e.variant_name().unwrap_or("")
+        }
+    }
+}
 
-                #[inline]
-                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
-                    flatbuffers::emplace_scalar::<Self>(dst, *self);
+/// Generate a set of bit flags as a newtype around `base_type`, with an associated constant for
+/// each flag.
+///
+/// Unlike an enum, any combination of flags, including bits no flag uses, can be stored in it, so
+/// every value read from a buffer is valid.
+fn bit_flags_tokens(
+    flags_id: &syn::Ident,
+    values: &[(Ident, u64)],
+    base_type: TokenStream,
+    doc: &Comment,
+) -> TokenStream {
+    let flags = values.iter().map(|(key, value)| {
+        let value = lit_int(value, base_type.clone());
+        quote! {
+            pub const #key: Self = Self(#value);
+        }
+    });
+    let all = lit_int(
+        values.iter().fold(0, |all, (_, value)| all | value),
+        base_type.clone(),
+    );
+    let debug_prefix = format!("{}(", flags_id);
+    let names = values.iter().map(|(key, _)| key.raw);
+    let keys = values.iter().map(|(key, _)| key);
+
+    quote! {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #doc
+        pub struct #flags_id(#base_type);
+
+        #[allow(non_upper_case_globals)]
+        impl #flags_id {
+            #(#flags)*
+
+            /// No flags set.
+            #[inline]
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Every flag set.
+            #[inline]
+            pub const fn all() -> Self {
+                Self(#all)
+            }
+
+            /// The bits of the flags, as they're stored in a buffer.
+            #[inline]
+            pub const fn bits(self) -> #base_type {
+                self.0
+            }
+
+            /// Convert `bits` to flags, or return `None` if any bit isn't one of the flags.
+            #[inline]
+            pub const fn from_bits(bits: #base_type) -> Option<Self> {
+                if bits & !Self::all().0 == 0 {
+                    Some(Self(bits))
+                } else {
+                    None
                 }
             }
 
-            pub fn #enum_id_fn_name(e: #enum_id) -> &'static str {
-                match e {
-                    #(#names_to_strings),*
+            /// Convert `bits` to flags, dropping any bit that isn't one of the flags.
+            #[inline]
+            pub const fn from_bits_truncate(bits: #base_type) -> Self {
+                Self(bits & Self::all().0)
+            }
+
+            /// Convert `bits` to flags, keeping any bit that isn't one of the flags.
+            #[inline]
+            pub const fn from_bits_retain(bits: #base_type) -> Self {
+                Self(bits)
+            }
+
+            #[inline]
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Check whether every flag in `other` is set.
+            #[inline]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Check whether any flag in `other` is set.
+            #[inline]
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            #[inline]
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            #[inline]
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+        }
+
+        // The flags that are set are shown by name, followed by any bits that aren't flags.
+        impl std::fmt::Debug for #flags_id {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(#debug_prefix)?;
+                let mut rest = self.0;
+                let mut first = true;
+                for (name, flag) in [#((#names, Self::#keys)),*].iter() {
+                    if flag.0 != 0 && self.contains(*flag) {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        f.write_str(name)?;
+                        rest &= !flag.0;
+                        first = false;
+                    }
                 }
+                if rest != 0 || first {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{:#x}", rest)?;
+                }
+                f.write_str(")")
             }
-        })
+        }
+
+        impl std::ops::BitOr for #flags_id {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for #flags_id {
+            #[inline]
+            fn bitor_assign(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl std::ops::BitAnd for #flags_id {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+        }
+
+        impl std::ops::BitAndAssign for #flags_id {
+            #[inline]
+            fn bitand_assign(&mut self, other: Self) {
+                self.0 &= other.0;
+            }
+        }
+
+        impl std::ops::Not for #flags_id {
+            type Output = Self;
+
+            /// The flags that aren't set, leaving out any bit that isn't one of the flags.
+            #[inline]
+            fn not(self) -> Self {
+                Self::from_bits_truncate(!self.0)
+            }
+        }
+
+        impl<'a> flatbuffers::Follow<'a> for #flags_id {
+            type Inner = Self;
+
+            fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                flatbuffers::read_scalar_at::<Self>(buf, loc)
+            }
+        }
+
+        impl flatbuffers::EndianScalar for #flags_id {
+            #[inline]
+            fn to_little_endian(self) -> Self {
+                Self(#base_type::to_le(self.0))
+            }
+
+            #[inline]
+            fn from_little_endian(self) -> Self {
+                Self(#base_type::from_le(self.0))
+            }
+        }
+
+        impl flatbuffers::Push for #flags_id {
+            type Output = Self;
+
+            #[inline]
+            fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                flatbuffers::emplace_scalar::<Self>(dst, *self);
+            }
+        }
+
+        impl flatbuffers::Verifiable for #flags_id {
+            #[inline]
+            fn run_verifier(
+                v: &mut flatbuffers::Verifier,
+                pos: usize,
+            ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                v.in_buffer::<Self>(pos)
+            }
+
+            #[inline]
+            fn run_verifier_many(
+                _: &mut flatbuffers::Verifier,
+                _: usize,
+                _: usize,
+            ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ToTokens for Enum<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            id: enum_id,
+            values,
+            base_type,
+            doc,
+            ..
+        } = self;
+        let enum_id = rust_ident(enum_id.raw);
+        let values = enum_values(values, 0);
+        if self.is_bit_flags() {
+            // the values of bit flags are the positions of their bits
+            let values = values
+                .map(|(key, bit)| {
+                    debug_assert!(
+                        (0..64).contains(&bit),
+                        "validation rejects bits that don't fit in the underlying type"
+                    );
+                    (key, 1u64 << bit)
+                })
+                .collect::<Vec<_>>();
+            bit_flags_tokens(&enum_id, &values, base_type.to_token_stream(), doc)
+        } else {
+            let values = values.collect::<Vec<_>>();
+            enum_tokens(&enum_id, &values, base_type.to_token_stream(), doc)
+        }
         .to_tokens(tokens)
     }
 }
 
+/// A union is stored as two fields of the table containing it: the type of the value, which is
+/// a `u8` enum with a `NONE` variant for the empty union, followed by the value itself.
+impl ToTokens for Union<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { values, doc, .. } = self;
+        let values = std::iter::once((Ident::from("NONE"), 0))
+            .chain(enum_values(values, 1))
+            .collect::<Vec<_>>();
+        enum_tokens(&union_type_id(self), &values, quote!(u8), doc).to_tokens(tokens)
+    }
+}
+
+#[cfg(test)]
+mod sum_type_tests {
+    use super::*;
+    use crate::{e_item, enum_, union};
+
+    #[test]
+    fn test_visit_enum_values() {
+        let e = enum_!(
+            Color,
+            UByte,
+            [e_item!(Red), e_item!(Green = 4), e_item!(Blue)]
+        );
+        let result = to_code(e);
+        assert!(result.contains("pub struct Color (pub u8) ;"));
+        assert!(result.contains(
+            "impl Color { pub const Red : Self = Self (0_u8) ; \
+             pub const Green : Self = Self (4_u8) ; \
+             pub const Blue : Self = Self (5_u8) ;"
+        ));
+        assert!(result.contains("pub const ENUM_MIN : u8 = 0_u8 ;"));
+        assert!(result.contains("pub const ENUM_MAX : u8 = 5_u8 ;"));
+        assert!(result.contains(
+            "pub const ENUM_VALUES : & 'static [Self] = & [Self :: Red , Self :: Green , Self :: Blue] ;"
+        ));
+        assert!(result.contains("Color :: Green => Some (\"Green\")"));
+        assert!(result.contains("\"Green\" => Ok (Color :: Green)"));
+        assert!(result.contains("if Self :: ENUM_VALUES . contains (& value)"));
+    }
+
+    #[test]
+    fn test_visit_enum_without_values() {
+        // the parser doesn't accept these, but the types can still be built by hand
+        let e = crate::types::Enum::builder()
+            .id(Ident::from("Empty"))
+            .base_type(Type::UByte)
+            .values(vec![])
+            .build();
+        let result = to_code(e);
+        assert!(result.contains("pub const ENUM_VALUES : & 'static [Self] = & [] ;"));
+        assert!(result.contains("if Self :: ENUM_VALUES . contains (& value)"));
+    }
+
+    #[test]
+    fn test_visit_bit_flags() {
+        let input = "\
+enum Perm : ubyte (bit_flags) { Read, Write, Exec = 7 }
+table File { perm: Perm = Write; mask: Perm = 3; }";
+        let (_, schema) = crate::parser::schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub struct Perm (u8) ;"));
+        assert!(result.contains("pub const Read : Self = Self (1_u8) ;"));
+        assert!(result.contains("pub const Write : Self = Self (2_u8) ;"));
+        assert!(result.contains("pub const Exec : Self = Self (128_u8) ;"));
+        assert!(result.contains("Self (131_u8)"));
+        assert!(!result.contains("pub enum Perm"));
+        assert!(result.contains("Some (Perm :: Write)"));
+        assert!(result.contains("Some (Perm :: from_bits_retain (3_u8))"));
+    }
+
+    #[test]
+    fn test_visit_union() {
+        let u = union!(Animal, [e_item!(Cat), e_item!(Dog)]);
+        let result = to_code(u);
+        assert!(result.contains("pub struct AnimalType (pub u8) ;"));
+        assert!(result.contains("pub const NONE : Self = Self (0_u8) ;"));
+        assert!(result.contains("pub const Dog : Self = Self (2_u8) ;"));
+    }
+}
+
 // TODO: better error messages for things that aren't implemented
-impl ToTokens for Element<'_> {
+impl ToTokens for InContext<'_, '_, Element<'_>> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         // the following constructs are (or should be) handled at the file
         // level:
@@ -564,106 +2281,380 @@ impl ToTokens for Element<'_> {
         //
         // Additionally, attributes do not have corresponding concrete code
         // generated, they are used to *affect* codegen of other items.
-        match self {
-            Element::Table(t) => t.to_tokens(tokens),
-            Element::Struct(_) => unimplemented!(),
-            Element::Enum(e) => e.to_tokens(tokens),
-
-            Element::Root(_) => unimplemented!(),
-            Element::FileExtension(_) => unimplemented!(),
-            Element::FileIdentifier(_) => unimplemented!(),
-            Element::Attribute(_) => unimplemented!(),
-            Element::Rpc(rpc) => rpc.to_tokens(tokens),
-            Element::Object(_) => unimplemented!(),
+        match self.item {
+            Element::Table(t) => {
+                let table = self.context.with(self.namespace, t);
+                table.to_tokens(tokens);
+                if self.context.options.object_api {
+                    table.object_tokens().to_tokens(tokens);
+                }
+                if self.context.options.serde {
+                    table.serde_tokens().to_tokens(tokens);
+                }
+            }
+            Element::Struct(s) => {
+                let s = self.context.with(self.namespace, s);
+                s.to_tokens(tokens);
+                if self.context.options.serde {
+                    s.serde_tokens().to_tokens(tokens);
+                }
+            }
+            Element::Enum(e) => {
+                e.to_tokens(tokens);
+                if self.context.options.serde {
+                    serde::enum_serde_tokens(e).to_tokens(tokens);
+                }
+            }
+            Element::Union(u) => {
+                u.to_tokens(tokens);
+                if self.context.options.serde {
+                    serde::union_type_serde_tokens(u).to_tokens(tokens);
+                }
+                if self.context.options.object_api {
+                    self.context
+                        .with(self.namespace, u)
+                        .object_tokens()
+                        .to_tokens(tokens);
+                }
+            }
+
+            // attribute declarations and objects only matter to other tools
+            Element::Root(_)
+            | Element::FileExtension(_)
+            | Element::FileIdentifier(_)
+            | Element::Attribute(_)
+            | Element::Object(_) => {}
+            Element::Rpc(rpc) => self.context.with(self.namespace, rpc).to_tokens(tokens),
             element => panic!("{:?}", element),
         }
     }
 }
 
-// TODO: actually open up a file
-// this will need some notion of a default include path to search for fbs
-// files.
-impl ToTokens for Include<'_> {
+/// The generated code for a namespace, followed by that of the namespaces nested in it.
+#[derive(Default)]
+struct Module<'a> {
+    doc: Option<&'a Comment<'a>>,
+    items: Vec<TokenStream>,
+    children: Vec<(Ident<'a>, Module<'a>)>,
+}
+
+impl<'a> Module<'a> {
+    /// Return the module at `path` relative to this one, creating it and any missing parents.
+    fn descendant(&mut self, path: &[Ident<'a>]) -> &mut Self {
+        match path.split_first() {
+            None => self,
+            Some((name, rest)) => {
+                let index = match self.children.iter().position(|(id, _)| id == name) {
+                    Some(index) => index,
+                    None => {
+                        self.children.push((*name, Module::default()));
+                        self.children.len() - 1
+                    }
+                };
+                self.children[index].1.descendant(rest)
+            }
+        }
+    }
+}
+
+impl ToTokens for Module<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self { stem, doc, .. } = self;
-        let id = format_ident!("{}", stem);
+        let Self {
+            items, children, ..
+        } = self;
+        let children = children.iter().map(|(name, module)| {
+            let doc = module.doc;
+            quote! {
+                #doc
+                pub mod #name {
+                    #module
+                }
+            }
+        });
         (quote! {
-            #doc
-            use #id::*;
+            #(#items)*
+            #(#children)*
         })
         .to_tokens(tokens)
     }
 }
 
-// TODO:
-// * Root types
-// * File identifiers
-// * File extensions
-// * Parsing included files
-impl ToTokens for Schema<'_> {
+/// The functions for reading and writing buffers whose root is the `root_type` of a schema,
+/// along with the file identifier and extension declared in the same schema.
+struct RootFunctions<'a> {
+    root: Ident<'a>,
+    identifier: Option<&'a FileIdentifier<'a>>,
+    extension: Option<&'a FileExtension<'a>>,
+}
+
+impl ToTokens for RootFunctions<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self { includes, elements } = self;
+        let root = self.root;
+        let snake_name = root.raw.to_snake_case();
+        let shouty_name = root.raw.to_shouty_snake_case();
+        let root_as = format_ident!("root_as_{}", snake_name);
+        let root_as_with_opts = format_ident!("root_as_{}_with_opts", snake_name);
+        let size_prefixed_root_as = format_ident!("size_prefixed_root_as_{}", snake_name);
+        let size_prefixed_root_as_with_opts =
+            format_ident!("size_prefixed_root_as_{}_with_opts", snake_name);
+        let get_root = format_ident!("get_root_as_{}", snake_name);
+        let get_size_prefixed_root = format_ident!("get_size_prefixed_root_as_{}", snake_name);
+        let finish = format_ident!("finish_{}_buffer", snake_name);
+        let finish_size_prefixed = format_ident!("finish_size_prefixed_{}_buffer", snake_name);
+
+        let (identifier_items, identifier) = match self.identifier {
+            Some(FileIdentifier { id, .. }) => {
+                let identifier_const = format_ident!("{}_IDENTIFIER", shouty_name);
+                let has_identifier = format_ident!("{}_buffer_has_identifier", snake_name);
+                let size_prefixed_has_identifier =
+                    format_ident!("{}_size_prefixed_buffer_has_identifier", snake_name);
+                let id = id.iter().collect::<String>();
+                let items = quote! {
+                    pub const #identifier_const: &str = #id;
+
+                    #[inline]
+                    pub fn #has_identifier(buf: &[u8]) -> bool {
+                        flatbuffers::buffer_has_identifier(buf, #identifier_const, false)
+                    }
 
-        // namespaces precede all of their contents, so track the current namespace and accumulate
-        // its change into the key of a map of namespace -> elements contained within that
-        // namespace.
-        let code = elements
-            .iter()
-            .scan((None, None), |ns_item_pair, element| {
-                *ns_item_pair = if element.is_namespace() {
-                    (element.namespace(), None)
-                } else {
-                    (ns_item_pair.0, Some(element))
-                };
-                Some(*ns_item_pair)
-            })
-            .filter(|(_, element)| element.is_some())
-            .into_group_map()
-            .into_iter()
-            // for each non-None namespace and elements contained within:
-            // reverse fold over the namespace pieces to generate nested modules:
-            // if namespace parts is [a, b, c]
-            // and body is struct Foo { ... }
-            // then
-            // reverse:
-            //   [c, b, a]
-            // fold:
-            //    init: struct Foo { ... }
-            //    next: pub mod c { struct Foo { ... } }
-            //    next: pub mod b { pub mod c { struct Foo { ... } } }
-            //   final: pub mod a { pub mod b { pub mod c { struct Foo { ... } } } }
-            .map(|(namespace, elements)| {
-                let base_body = quote! { #(#elements)* };
-                if let Some(Namespace { ident, doc }) = namespace {
-                    let nested =
-                        ident
-                            .parts
-                            .iter()
-                            .rev()
-                            .fold(base_body, |module_body, module_name| {
-                                quote! {
-                                    pub mod #module_name {
-                                        #module_body
-                                    }
-                                }
-                            });
-                    quote! {
-                        #doc
-                        #nested
+                    #[inline]
+                    pub fn #size_prefixed_has_identifier(buf: &[u8]) -> bool {
+                        flatbuffers::buffer_has_identifier(buf, #identifier_const, true)
                     }
-                } else {
-                    base_body
-                }
-            });
+                };
+                (items, quote!(Some(#identifier_const)))
+            }
+            None => (quote!(), quote!(None)),
+        };
+
+        let extension = self.extension.map(|FileExtension { ext, .. }| {
+            let extension_const = format_ident!("{}_EXTENSION", shouty_name);
+            quote!(pub const #extension_const: &str = #ext;)
+        });
 
         (quote! {
-            #(#includes)*
-            #(#code)*
+            /// Verify that `buf` holds a valid root table before reading it.
+            #[inline]
+            pub fn #root_as<'a>(buf: &'a [u8]) -> Result<#root<'a>, flatbuffers::InvalidFlatbuffer> {
+                flatbuffers::root::<#root<'a>>(buf)
+            }
+
+            /// Verify that `buf` holds a valid size-prefixed root table before reading it.
+            #[inline]
+            pub fn #size_prefixed_root_as<'a>(
+                buf: &'a [u8],
+            ) -> Result<#root<'a>, flatbuffers::InvalidFlatbuffer> {
+                flatbuffers::size_prefixed_root::<#root<'a>>(buf)
+            }
+
+            /// Verify that `buf` holds a valid root table within the limits of `opts` before reading
+            /// it.
+            #[inline]
+            pub fn #root_as_with_opts<'a>(
+                opts: &flatbuffers::VerifierOptions,
+                buf: &'a [u8],
+            ) -> Result<#root<'a>, flatbuffers::InvalidFlatbuffer> {
+                flatbuffers::root_with_opts::<#root<'a>>(opts, buf)
+            }
+
+            /// Verify that `buf` holds a valid size-prefixed root table within the limits of `opts`
+            /// before reading it.
+            #[inline]
+            pub fn #size_prefixed_root_as_with_opts<'a>(
+                opts: &flatbuffers::VerifierOptions,
+                buf: &'a [u8],
+            ) -> Result<#root<'a>, flatbuffers::InvalidFlatbuffer> {
+                flatbuffers::size_prefixed_root_with_opts::<#root<'a>>(opts, buf)
+            }
+
+            /// Read the root table of `buf` without verifying it first.
+            ///
+            /// `buf` must come from a trusted source, since an invalid buffer can cause reads out
+            /// of bounds. Use the verifying version otherwise.
+            #[inline]
+            pub fn #get_root<'a>(buf: &'a [u8]) -> #root<'a> {
+                flatbuffers::get_root::<#root<'a>>(buf)
+            }
+
+            #[inline]
+            pub fn #get_size_prefixed_root<'a>(buf: &'a [u8]) -> #root<'a> {
+                flatbuffers::get_size_prefixed_root::<#root<'a>>(buf)
+            }
+
+            #identifier_items
+
+            #extension
+
+            #[inline]
+            pub fn #finish<'a>(
+                fbb: &mut flatbuffers::FlatBufferBuilder<'a>,
+                root: flatbuffers::WIPOffset<#root<'a>>,
+            ) {
+                fbb.finish(root, #identifier);
+            }
+
+            #[inline]
+            pub fn #finish_size_prefixed<'a>(
+                fbb: &mut flatbuffers::FlatBufferBuilder<'a>,
+                root: flatbuffers::WIPOffset<#root<'a>>,
+            ) {
+                fbb.finish_size_prefixed(root, #identifier);
+            }
         })
         .to_tokens(tokens)
     }
 }
 
+impl<'a> Context<'a> {
+    /// The root functions of `schema`, along with the namespace of its root type.
+    ///
+    /// Schemas without a `root_type` get no root functions, even if they declare a file
+    /// identifier or extension.
+    fn root_functions(
+        &self,
+        schema: &'a Schema<'a>,
+    ) -> Option<(&'a [Ident<'a>], RootFunctions<'a>)> {
+        let (namespace, root) = schema
+            .namespaced_elements()
+            .find_map(|(namespace, element)| match element {
+                Element::Root(root) => Some((namespace, root)),
+                _ => None,
+            })?;
+        let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+        let symbol = self
+            .symbols
+            .resolve(namespace, &DottedIdent::from(vec![root.typename]))?;
+        let identifier = schema.elements.iter().find_map(|element| match element {
+            Element::FileIdentifier(identifier) => Some(identifier),
+            _ => None,
+        });
+        let extension = schema.elements.iter().find_map(|element| match element {
+            Element::FileExtension(extension) => Some(extension),
+            _ => None,
+        });
+        Some((
+            symbol.namespace,
+            RootFunctions {
+                root: symbol.id,
+                identifier,
+                extension,
+            },
+        ))
+    }
+}
+
+/// Generate a single module tree for all of the schemas, so that namespaces declared in more than
+/// one place, whether in the same file or in different ones, end up in the same module.
+impl ToTokens for Context<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut root = Module::default();
+        for (namespace, element) in self.schemas.iter().flat_map(Schema::namespaced_elements) {
+            let parts = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+            let module = root.descendant(parts);
+            if let Some(Namespace { doc, .. }) = namespace {
+                if module.doc.is_none() && !doc.lines.is_empty() {
+                    module.doc = Some(doc);
+                }
+            }
+            module
+                .items
+                .push(self.with(parts, element).into_token_stream());
+        }
+        for (namespace, functions) in self.schemas.iter().filter_map(|s| self.root_functions(s)) {
+            root.descendant(namespace)
+                .items
+                .push(functions.into_token_stream());
+        }
+        root.to_tokens(tokens)
+    }
+}
+
+impl ToTokens for Schema<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        Context::new(std::slice::from_ref(self)).to_tokens(tokens)
+    }
+}
+
+#[cfg(test)]
+mod schema_tests {
+    use super::*;
+    use crate::parser::schema_decl;
+
+    #[test]
+    fn test_visit_schema_module_tree() {
+        let input = "\
+namespace foo.bar;
+table A { x: int; }
+namespace foo.baz;
+table B { x: int; }
+namespace foo.bar;
+table C { x: int; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert_eq!(result.matches("pub mod foo").count(), 1);
+        assert_eq!(result.matches("pub mod bar").count(), 1);
+        assert_eq!(result.matches("pub mod baz").count(), 1);
+        let a = result.find("pub struct A").unwrap();
+        let c = result.find("pub struct C").unwrap();
+        let baz = result.find("pub mod baz").unwrap();
+        assert!(a < c && c < baz);
+    }
+
+    #[test]
+    fn test_root_functions() {
+        let input = "\
+file_identifier \"MONS\";
+namespace game;
+table Monster { hp: int; }
+root_type Monster;
+namespace other;
+table Other { x: int; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let result = to_code(schema);
+        let game = result.find("pub mod game").unwrap();
+        let other = result.find("pub mod other").unwrap();
+        let get_root = result.find("pub fn get_root_as_monster").unwrap();
+        assert!(game < get_root && get_root < other);
+        assert!(result.contains("pub const MONSTER_IDENTIFIER : & str = \"MONS\""));
+        assert!(result.contains("fbb . finish (root , Some (MONSTER_IDENTIFIER))"));
+        assert!(!result.contains("MONSTER_EXTENSION"));
+    }
+
+    #[test]
+    fn test_no_root_functions_without_root_type() {
+        let input = "file_identifier \"MONS\"; table Monster { hp: int; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(!result.contains("get_root_as_monster"));
+        assert!(!result.contains("MONSTER_IDENTIFIER"));
+    }
+
+    #[test]
+    fn test_keyword_namespace() {
+        let input = "\
+namespace a.match;
+table T { x: int; }
+namespace b;
+table U { t: a.match.T; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let result = to_code(schema);
+        assert!(result.contains("pub mod r#match"));
+        assert!(result.contains("super :: a :: r#match :: T"));
+    }
+
+    #[test]
+    fn test_attribute_and_object_generate_nothing() {
+        let input = "attribute \"priority\"; table A { x: int; } { x: 1 }";
+        let (rest, schema) = schema_decl(input).unwrap();
+        assert_eq!((rest, schema.elements.len()), ("", 3));
+        let result = to_code(&schema);
+        assert_eq!(
+            result,
+            to_code(schema_decl("table A { x: int; }").unwrap().1)
+        );
+    }
+}
+
 impl ToTokens for File<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Self { schema, .. } = self;
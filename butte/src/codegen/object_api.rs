@@ -0,0 +1,418 @@
+/// The object API: owned types for tables and unions, which can be built up and changed freely,
+/// along with methods converting between them and buffers.
+///
+/// The object type of a table `X` is the struct `XT`, with an `unpack` method on `X` creating one
+/// and a `pack` method on `XT` writing it to a builder. The object type of a union `U` is the enum
+/// `UT`, with a variant holding the object type of each of the tables of the union.
+use super::*;
+
+/// The name of the object type of the table or union `id`.
+fn object_id(id: &Ident) -> syn::Ident {
+    format_ident!("{}T", id.raw)
+}
+
+impl<T> InContext<'_, '_, T> {
+    /// The path to the object type of the table or union `ident` refers to.
+    fn object_path(&self, ident: &DottedIdent) -> TokenStream {
+        match self.resolve(ident) {
+            Some(symbol) => {
+                let mut path = symbol.path_from(self.namespace);
+                path.pop();
+                let object_id = object_id(&symbol.id);
+                quote!(#(#path::)*#object_id)
+            }
+            None => ident.into_token_stream(),
+        }
+    }
+
+    /// The owned type of a value of type `ty` held directly, such as the element of a vector.
+    fn object_element(&self, ty: &Type) -> TokenStream {
+        match ty {
+            Type::String => quote!(String),
+            Type::Ident(ident) if self.is_table(ty) => self.object_path(ident),
+            _ => self.ty(ty),
+        }
+    }
+
+    /// The owned type of a field of type `ty`.
+    ///
+    /// Tables are boxed, since a table may contain itself.
+    fn object_type(&self, ty: &Type) -> TokenStream {
+        match ty {
+            Type::Array(element) => {
+                let element = self.object_element(element);
+                quote!(Vec<#element>)
+            }
+            Type::Ident(ident) if self.is_table(ty) || self.union_of(ty).is_some() => {
+                let path = self.object_path(ident);
+                if self.is_table(ty) {
+                    quote!(Box<#path>)
+                } else {
+                    path
+                }
+            }
+            _ => self.object_element(ty),
+        }
+    }
+
+    /// Convert `x`, the value of a field of type `ty` read from a buffer, to its owned type.
+    ///
+    /// Returns `None` when the value is already owned.
+    fn unpack_value(&self, ty: &Type) -> Option<TokenStream> {
+        match ty {
+            Type::String => Some(quote!(x.to_string())),
+            Type::Ident(_) if self.is_table(ty) => Some(quote!(Box::new(x.unpack()))),
+            Type::Array(element) if self.is_slice_element(element) => Some(quote!(x.to_vec())),
+            Type::Array(element) => match &**element {
+                Type::String => Some(quote!(x.iter().map(|x| x.to_string()).collect())),
+                element if self.is_table(element) => {
+                    Some(quote!(x.iter().map(|x| x.unpack()).collect()))
+                }
+                _ => Some(quote!(x.iter().collect())),
+            },
+            _ => None,
+        }
+    }
+
+    /// Write `x`, a reference to the owned value of a field of type `ty`, to the builder `fbb`.
+    ///
+    /// Returns `None` for values that are stored inline, which are passed as they are.
+    fn pack_value(&self, ty: &Type) -> Option<TokenStream> {
+        match ty {
+            Type::String => Some(quote!(fbb.create_string(x))),
+            Type::Ident(_) if self.is_table(ty) => Some(quote!(x.pack(fbb))),
+            Type::Array(element) => match &**element {
+                Type::String => Some(quote! {{
+                    let x = x.iter().map(|x| fbb.create_string(x)).collect::<Vec<_>>();
+                    fbb.create_vector(&x)
+                }}),
+                element if self.is_table(element) => Some(quote! {{
+                    let x = x.iter().map(|x| x.pack(fbb)).collect::<Vec<_>>();
+                    fbb.create_vector(&x)
+                }}),
+                _ => Some(quote!(fbb.create_vector(x))),
+            },
+            _ => None,
+        }
+    }
+}
+
+impl InContext<'_, '_, Table<'_>> {
+    /// The object type of the table, with `pack` and `unpack` methods.
+    pub(super) fn object_tokens(&self) -> TokenStream {
+        let Table { id: table_id, .. } = self.item;
+        let object_id = object_id(table_id);
+        let args = format_ident!("{}Args", table_id.raw);
+        let fields = self
+            .item
+            .fields
+            .iter()
+            .filter(|field| !field.is_deprecated())
+            .collect::<Vec<_>>();
+
+        // The owned fields mirror the accessors of the table: fields with a default are always
+        // present, required fields are present once the buffer is verified, and every other field
+        // may be missing.
+        let object_fields = fields.iter().map(|field| {
+            let Field { id, ty, .. } = field;
+            let object_type = self.object_type(ty);
+            if self.default_value(field).is_some()
+                || self.union_of(ty).is_some()
+                || field.is_required()
+            {
+                quote!(pub #id: #object_type)
+            } else {
+                quote!(pub #id: Option<#object_type>)
+            }
+        });
+
+        let object_defaults = fields.iter().map(|field| {
+            let Field { id, ty, .. } = field;
+            if let Some(default) = self.default_value(field) {
+                quote!(#id: #default)
+            } else if let Some(Type::Ident(ident)) = self.union_of(ty).map(|_| ty) {
+                let path = self.object_path(ident);
+                quote!(#id: #path::NONE)
+            } else if field.is_required() {
+                quote!(#id: Default::default())
+            } else {
+                quote!(#id: None)
+            }
+        });
+
+        let unpack_fields = fields.iter().map(|field| {
+            let Field { id, ty, .. } = field;
+            let snake_name = rust_ident(&id.as_ref().to_snake_case());
+            if let Some((symbol, union)) = self.union_of(ty) {
+                // a variant added by a newer version of the schema can't be unpacked
+                let type_snake_name = format_ident!("{}_type", snake_name);
+                let union_type = self.union_type_path(&symbol, union);
+                let object_path = match ty {
+                    Type::Ident(ident) => self.object_path(ident),
+                    _ => unreachable!(),
+                };
+                let variants = union.values.iter().map(|variant| {
+                    let variant_id = variant.id;
+                    let method_name =
+                        format_ident!("{}_as_{}", snake_name, variant_id.as_ref().to_snake_case());
+                    quote! {
+                        #union_type::#variant_id => self
+                            .#method_name()
+                            .map(|x| #object_path::#variant_id(Box::new(x.unpack())))
+                    }
+                });
+                return quote! {
+                    #id: match self.#type_snake_name() {
+                        #(#variants,)*
+                        _ => None,
+                    }
+                    .unwrap_or(#object_path::NONE)
+                };
+            }
+            match self.unpack_value(ty) {
+                Some(unpack) if self.default_value(field).is_none() && field.is_required() => {
+                    quote!(#id: { let x = self.#snake_name(); #unpack })
+                }
+                Some(unpack) if self.default_value(field).is_none() => {
+                    quote!(#id: self.#snake_name().map(|x| #unpack))
+                }
+                _ => quote!(#id: self.#snake_name()),
+            }
+        });
+
+        // Everything stored outside of the table is written before it.
+        let pack_values = fields.iter().filter_map(|field| {
+            let Field { id, ty, .. } = field;
+            if self.union_of(ty).is_some() {
+                return Some(quote!(let #id = self.#id.pack(fbb);));
+            }
+            let pack = self.pack_value(ty)?;
+            Some(if field.is_required() {
+                quote!(let #id = Some({ let x = &self.#id; #pack });)
+            } else {
+                quote!(let #id = self.#id.as_ref().map(|x| #pack);)
+            })
+        });
+
+        let pack_args = fields.iter().map(|field| {
+            let Field { id, ty, .. } = field;
+            if let Some((_, union)) = self.union_of(ty) {
+                let field_type_id = format_ident!("{}_type", id.raw);
+                let type_method = format_ident!("{}_type", union.id.raw.to_snake_case());
+                quote! {
+                    #field_type_id: self.#id.#type_method(),
+                    #id
+                }
+            } else if self.pack_value(ty).is_some() {
+                quote!(#id)
+            } else if self.default_value(field).is_none() && field.is_required() {
+                quote!(#id: Some(self.#id))
+            } else {
+                quote!(#id: self.#id)
+            }
+        });
+
+        // missing fields are deserialized as they are by default
+        let serde = if self.context.options.serde {
+            let derive = serde::object_derive(table_id);
+            quote! {
+                #derive
+                #[serde(default)]
+            }
+        } else {
+            quote!()
+        };
+
+        quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            #serde
+            pub struct #object_id {
+                #(#object_fields),*
+            }
+
+            impl Default for #object_id {
+                #[inline]
+                fn default() -> Self {
+                    Self {
+                        #(#object_defaults),*
+                    }
+                }
+            }
+
+            impl #object_id {
+                /// Write the table to `fbb`, along with everything it contains.
+                pub fn pack<'b>(
+                    &self,
+                    fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+                ) -> flatbuffers::WIPOffset<#table_id<'b>> {
+                    #(#pack_values)*
+                    #table_id::create(fbb, &#args {
+                        #(#pack_args),*
+                    })
+                }
+            }
+
+            impl #table_id<'_> {
+                /// Copy the table, along with everything it contains, out of the buffer.
+                pub fn unpack(&self) -> #object_id {
+                    #object_id {
+                        #(#unpack_fields),*
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl InContext<'_, '_, Union<'_>> {
+    /// The object type of the union, an enum with a variant holding each of the tables of the
+    /// union.
+    pub(super) fn object_tokens(&self) -> TokenStream {
+        let union = self.item;
+        let object_id = object_id(&union.id);
+        let union_type = union_type_id(union);
+        let type_method = format_ident!("{}_type", union.id.raw.to_snake_case());
+        let variants = union
+            .values
+            .iter()
+            .map(|variant| {
+                (
+                    variant.id,
+                    self.object_path(&DottedIdent::from(vec![variant.id])),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let object_variants = variants.iter().map(|(id, path)| quote!(#id(Box<#path>)));
+        let variant_types = variants
+            .iter()
+            .map(|(id, _)| quote!(Self::#id(_) => #union_type::#id));
+        let pack_variants = variants
+            .iter()
+            .map(|(id, _)| quote!(Self::#id(x) => Some(x.pack(fbb).as_union_value())));
+
+        let serde = if self.context.options.serde {
+            serde::object_derive(&union.id)
+        } else {
+            quote!()
+        };
+
+        quote! {
+            #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+            #[derive(Debug, Clone, PartialEq)]
+            #serde
+            pub enum #object_id {
+                NONE,
+                #(#object_variants),*
+            }
+
+            impl Default for #object_id {
+                #[inline]
+                fn default() -> Self {
+                    Self::NONE
+                }
+            }
+
+            impl #object_id {
+                /// The type of the value of the union.
+                pub fn #type_method(&self) -> #union_type {
+                    match self {
+                        Self::NONE => #union_type::NONE,
+                        #(#variant_types),*
+                    }
+                }
+
+                /// Write the value of the union to `fbb`, or return `None` if it has no value.
+                pub fn pack(
+                    &self,
+                    fbb: &mut flatbuffers::FlatBufferBuilder,
+                ) -> Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>> {
+                    match self {
+                        Self::NONE => None,
+                        #(#pack_variants),*
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod object_api_tests {
+    use super::*;
+    use crate::parser::schema_decl;
+
+    fn to_object_code(input: &str) -> String {
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let options = Options {
+            object_api: true,
+            ..Options::default()
+        };
+        to_code(Context::with_options(&schemas, options))
+    }
+
+    #[test]
+    fn test_no_object_api_by_default() {
+        let (_, schema) = schema_decl("table Monster { name: string; }").unwrap();
+        let result = to_code(schema);
+        assert!(!result.contains("MonsterT"));
+        assert!(!result.contains("fn unpack"));
+    }
+
+    #[test]
+    fn test_object_table() {
+        let input = "\
+struct Vec2 { x: float; y: float; }
+table Weapon { damage: short; }
+table Monster {
+  name: string (required);
+  hp: short = 100;
+  mana: int = null;
+  pos: Vec2;
+  weapon: Weapon;
+  inventory: [ubyte];
+  names: [string];
+  weapons: [Weapon];
+  old: int (deprecated);
+}";
+        let result = to_object_code(input);
+        assert!(result.contains("pub struct MonsterT {"));
+        assert!(result.contains("pub name : String ,"));
+        assert!(result.contains("pub hp : i16 ,"));
+        assert!(result.contains("pub mana : Option < i32 > ,"));
+        assert!(result.contains("pub pos : Option < Vec2 > ,"));
+        assert!(result.contains("pub weapon : Option < Box < WeaponT > > ,"));
+        assert!(result.contains("pub inventory : Option < Vec < u8 > > ,"));
+        assert!(result.contains("pub names : Option < Vec < String > > ,"));
+        assert!(result.contains("pub weapons : Option < Vec < WeaponT > >"));
+        assert!(!result.contains("pub old"));
+        assert!(result.contains("hp : 100_i16"));
+        assert!(result.contains("pub fn unpack (& self) -> MonsterT"));
+        assert!(result.contains("name : { let x = self . name () ; x . to_string () }"));
+        assert!(
+            result.contains("weapon : self . weapon () . map (| x | Box :: new (x . unpack ()))")
+        );
+        assert!(result
+            .contains("let name = Some ({ let x = & self . name ; fbb . create_string (x) }) ;"));
+        assert!(result.contains("Monster :: create (fbb , & MonsterArgs {"));
+    }
+
+    #[test]
+    fn test_object_union() {
+        let input = "\
+table Cat { lives: int; }
+namespace zoo;
+table Dog { name: string; }
+union Animal { Cat, Dog }
+table Pen { animal: Animal; }";
+        let result = to_object_code(input);
+        assert!(result.contains(
+            "pub enum AnimalT { NONE , Cat (Box < super :: CatT >) , Dog (Box < DogT >) }"
+        ));
+        assert!(result.contains("pub fn animal_type (& self) -> AnimalType"));
+        assert!(result.contains("pub animal : AnimalT"));
+        assert!(result.contains("animal : AnimalT :: NONE"));
+        assert!(result.contains("animal_type : self . animal . animal_type () , animal"));
+    }
+}
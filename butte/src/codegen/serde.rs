@@ -0,0 +1,327 @@
+/// `serde` support: `Serialize` for the types that read a buffer, and `Deserialize` as well for the
+/// types that own their values.
+///
+/// Fields are named as they are in the schema. Enums are the names of their variants, and bit
+/// flags are the names of the flags set in them separated by spaces, either of which is a number
+/// when the value can't be named. A union is an enum with a variant for each of its tables, the
+/// way the object type of the union is derived, so that a table read from a buffer can be
+/// deserialized as its object type.
+///
+/// The generated code uses the `serde` crate, with its `derive` feature, and the `serde` feature
+/// of the flatbuffers runtime.
+use super::*;
+
+impl InContext<'_, '_, Table<'_>> {
+    /// Serialize the table as a struct holding the fields present in the buffer, along with every
+    /// field that has a default.
+    pub(super) fn serde_tokens(&self) -> TokenStream {
+        let Table { id: table_id, .. } = self.item;
+        let raw_table_name = table_id.raw;
+        let fields = self
+            .item
+            .fields
+            .iter()
+            .filter(|field| !field.is_deprecated())
+            .collect::<Vec<_>>();
+
+        let serialize_fields = fields.iter().map(|field| {
+            let name = field.id.raw;
+            let snake_name = rust_ident(&field.id.as_ref().to_snake_case());
+            if let Some((_, union)) = self.union_of(&field.ty) {
+                // a variant added by a newer version of the schema is left out
+                let raw_union_name = union.id.raw;
+                let variants = union.values.iter().enumerate().map(|(i, variant)| {
+                    let method_name =
+                        format_ident!("{}_as_{}", snake_name, variant.id.as_ref().to_snake_case());
+                    let variant_index = i as u32 + 1;
+                    let raw_variant_name = variant.id.raw;
+                    quote! {
+                        if let Some(x) = self.#method_name() {
+                            s.serialize_field(#name, &flatbuffers::UnionValue {
+                                union_name: #raw_union_name,
+                                variant_index: #variant_index,
+                                variant_name: #raw_variant_name,
+                                value: &x,
+                            })?;
+                        } else
+                    }
+                });
+                return quote! {
+                    #(#variants)* {
+                        s.skip_field(#name)?;
+                    }
+                };
+            }
+            if self.default_value(field).is_some() || field.is_required() {
+                quote!(s.serialize_field(#name, &self.#snake_name())?;)
+            } else {
+                quote! {
+                    match self.#snake_name() {
+                        Some(x) => s.serialize_field(#name, &x)?,
+                        None => s.skip_field(#name)?,
+                    }
+                }
+            }
+        });
+        let serialize = if fields.is_empty() {
+            quote! {
+                serde::ser::SerializeStruct::end(serializer.serialize_struct(#raw_table_name, 0)?)
+            }
+        } else {
+            let len = fields.len();
+            quote! {
+                use serde::ser::SerializeStruct;
+                let mut s = serializer.serialize_struct(#raw_table_name, #len)?;
+                #(#serialize_fields)*
+                s.end()
+            }
+        };
+
+        quote! {
+            impl serde::Serialize for #table_id<'_> {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    #serialize
+                }
+            }
+        }
+    }
+}
+
+impl InContext<'_, '_, Struct<'_>> {
+    /// Serialize the struct with its accessors, and deserialize it by passing every field to its
+    /// constructor.
+    pub(super) fn serde_tokens(&self) -> TokenStream {
+        let Struct {
+            id: struct_id,
+            fields,
+            ..
+        } = self.item;
+        let raw_struct_name = struct_id.raw;
+        let len = fields.len();
+        let serialize_fields = fields.iter().map(|field| {
+            let name = field.id.raw;
+            let snake_name = rust_ident(&field.id.as_ref().to_snake_case());
+            quote!(s.serialize_field(#name, &self.#snake_name())?;)
+        });
+        let field_ids = fields.iter().map(|field| &field.id).collect::<Vec<_>>();
+        let field_types = fields.iter().map(|field| self.ty(&field.ty));
+
+        quote! {
+            impl serde::Serialize for #struct_id {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    use serde::ser::SerializeStruct;
+                    let mut s = serializer.serialize_struct(#raw_struct_name, #len)?;
+                    #(#serialize_fields)*
+                    s.end()
+                }
+            }
+
+            // Every field must be given, as a struct has no defaults.
+            impl<'de> serde::Deserialize<'de> for #struct_id {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    #[derive(serde::Deserialize)]
+                    #[serde(rename = #raw_struct_name)]
+                    struct Fields {
+                        #(#field_ids: #field_types),*
+                    }
+                    let Fields { #(#field_ids),* } = <Fields as serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self::new(#(#field_ids),*))
+                }
+            }
+        }
+    }
+}
+
+/// The attributes deriving `Serialize` and `Deserialize` for the object type of the table or
+/// union `id`, which is named after it.
+pub(super) fn object_derive(id: &Ident) -> TokenStream {
+    let raw_name = id.raw;
+    quote! {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename = #raw_name)]
+    }
+}
+
+/// `Serialize` and `Deserialize` for an enum or a set of bit flags.
+pub(super) fn enum_serde_tokens(e: &Enum) -> TokenStream {
+    let enum_id = rust_ident(e.id.raw);
+    let base_type = e.base_type.to_token_stream();
+    if e.is_bit_flags() {
+        let keys = e.values.iter().map(|value| &value.id).collect::<Vec<_>>();
+        bit_flags_serde_tokens(&enum_id, &keys, base_type)
+    } else {
+        variants_serde_tokens(&enum_id, base_type)
+    }
+}
+
+/// `Serialize` and `Deserialize` for the type of the values of a union, which is an enum.
+pub(super) fn union_type_serde_tokens(union: &Union) -> TokenStream {
+    variants_serde_tokens(&union_type_id(union), quote!(u8))
+}
+
+/// An enum is the name of its variant, or a number when the value isn't one of the variants.
+fn variants_serde_tokens(enum_id: &syn::Ident, base_type: TokenStream) -> TokenStream {
+    quote! {
+        impl serde::Serialize for #enum_id {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self.variant_name() {
+                    Some(name) => serializer.serialize_str(name),
+                    None => serde::Serialize::serialize(&self.0, serializer),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for #enum_id {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(serde::Deserialize)]
+                #[serde(untagged)]
+                enum Repr {
+                    Name(String),
+                    Value(#base_type),
+                }
+                match <Repr as serde::Deserialize>::deserialize(deserializer)? {
+                    Repr::Name(name) => name.parse().map_err(serde::de::Error::custom),
+                    Repr::Value(value) => Ok(Self(value)),
+                }
+            }
+        }
+    }
+}
+
+/// Bit flags are the names of the flags set in them separated by spaces, or a number when no
+/// flag is set or some of the bits set aren't flags.
+fn bit_flags_serde_tokens(
+    flags_id: &syn::Ident,
+    keys: &[&Ident],
+    base_type: TokenStream,
+) -> TokenStream {
+    let raw_flags_name = flags_id.to_string();
+    let names = keys.iter().map(|key| key.raw).collect::<Vec<_>>();
+
+    quote! {
+        impl serde::Serialize for #flags_id {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut names = vec![];
+                let mut rest = self.0;
+                for (name, flag) in [#((#names, Self::#keys)),*].iter() {
+                    if flag.0 != 0 && self.contains(*flag) {
+                        names.push(*name);
+                        rest &= !flag.0;
+                    }
+                }
+                if names.is_empty() || rest != 0 {
+                    serde::Serialize::serialize(&self.0, serializer)
+                } else {
+                    serializer.serialize_str(&names.join(" "))
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for #flags_id {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(serde::Deserialize)]
+                #[serde(untagged)]
+                enum Repr {
+                    Names(String),
+                    Bits(#base_type),
+                }
+                match <Repr as serde::Deserialize>::deserialize(deserializer)? {
+                    Repr::Names(names) => names.split_whitespace().try_fold(Self::empty(), |flags, name| {
+                        match name {
+                            #(#names => Ok(flags | Self::#keys),)*
+                            _ => Err(serde::de::Error::custom(flatbuffers::UnknownVariant {
+                                enum_name: #raw_flags_name,
+                                name: name.to_string(),
+                            })),
+                        }
+                    }),
+                    Repr::Bits(bits) => Ok(Self(bits)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+    use crate::parser::schema_decl;
+
+    fn to_serde_code(input: &str, object_api: bool) -> String {
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let options = Options {
+            object_api,
+            serde: true,
+        };
+        to_code(Context::with_options(&schemas, options))
+    }
+
+    #[test]
+    fn test_no_serde_by_default() {
+        let (_, schema) = schema_decl("table Monster { hp: short; }").unwrap();
+        let result = to_code(schema);
+        assert!(!result.contains("serde"));
+    }
+
+    #[test]
+    fn test_serde_table() {
+        let input = "\
+struct Vec3 { x: float; y: float; z: float; }
+table Monster { pos: Vec3; hp: short = 100; name: string (required); old: int (deprecated); }";
+        let result = to_serde_code(input, false);
+        assert!(result.contains("impl serde :: Serialize for Monster < '_ >"));
+        assert!(result.contains("serializer . serialize_struct (\"Monster\" , 3usize) ?"));
+        assert!(result.contains(
+            "match self . pos () { Some (x) => s . serialize_field (\"pos\" , & x) ? , \
+             None => s . skip_field (\"pos\") ? , }"
+        ));
+        assert!(result.contains("s . serialize_field (\"hp\" , & self . hp ()) ?"));
+        assert!(result.contains("s . serialize_field (\"name\" , & self . name ()) ?"));
+        assert!(!result.contains("\"old\""));
+        assert!(result.contains("impl < 'de > serde :: Deserialize < 'de > for Vec3"));
+        assert!(result.contains("Ok (Self :: new (x , y , z))"));
+        // the owned type is only generated with the object API
+        assert!(!result.contains("MonsterT"));
+    }
+
+    #[test]
+    fn test_serde_enums() {
+        let input = "\
+enum Color : byte { Red, Green }
+enum Perm : ubyte (bit_flags) { Read, Write }";
+        let result = to_serde_code(input, false);
+        assert!(result.contains("impl serde :: Serialize for Color"));
+        assert!(result.contains("enum Repr { Name (String) , Value (i8) , }"));
+        assert!(result.contains("impl serde :: Serialize for Perm"));
+        assert!(result.contains("\"Write\" => Ok (flags | Self :: Write)"));
+    }
+
+    #[test]
+    fn test_serde_union() {
+        let input = "\
+table Cat { lives: int; }
+table Dog { name: string; }
+union Animal { Cat, Dog }
+table Pen { animal: Animal; }";
+        let result = to_serde_code(input, true);
+        assert!(result.contains("impl serde :: Serialize for AnimalType"));
+        assert!(result.contains(
+            "if let Some (x) = self . animal_as_dog () { \
+             s . serialize_field (\"animal\" , & flatbuffers :: UnionValue { \
+             union_name : \"Animal\" , variant_index : 2u32 , variant_name : \"Dog\" , value : & x , \
+             }) ? ; } else { s . skip_field (\"animal\") ? ; }"
+        ));
+        assert!(result.contains(
+            "# [derive (serde :: Serialize , serde :: Deserialize)] \
+             # [serde (rename = \"Animal\")] \
+             pub enum AnimalT"
+        ));
+        assert!(result.contains(
+            "# [derive (serde :: Serialize , serde :: Deserialize)] \
+             # [serde (rename = \"Pen\")] \
+             # [serde (default)] \
+             pub struct PenT"
+        ));
+    }
+}
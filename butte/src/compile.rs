@@ -6,38 +6,72 @@ use std::{
 };
 
 use anyhow::{anyhow, Result};
+use itertools::Itertools;
 use quote::ToTokens;
 
+use crate::{codegen::Options, include::Source};
+
 /// Generate Rust code for a single flatbuffer schema file from arbitrary input and to arbitrary
 /// output.
 pub fn compile_fbs_generic(
     ugly: bool,
+    input: Box<dyn io::Read>,
+    output: Box<dyn io::Write>,
+) -> Result<()> {
+    compile_fbs_generic_with_include_dirs(ugly, &[], input, output)
+}
+
+/// Generate Rust code for a single flatbuffer schema file from arbitrary input and to arbitrary
+/// output, along with every file it includes.
+///
+/// Files included by the schema are searched for relative to the current directory, then in each
+/// of `include_dirs`. Use [`compile_fbs_with_include_dirs`](crate::compile_fbs_with_include_dirs)
+/// to search relative to the directory of the schema file instead.
+pub fn compile_fbs_generic_with_include_dirs(
+    ugly: bool,
+    include_dirs: &[PathBuf],
+    input: Box<dyn io::Read>,
+    output: Box<dyn io::Write>,
+) -> Result<()> {
+    compile_fbs_generic_with_options(ugly, include_dirs, &Options::default(), input, output)
+}
+
+/// Generate Rust code for a single flatbuffer schema file from arbitrary input and to arbitrary
+/// output, along with every file it includes, with the optional parts of the code chosen by
+/// `options`.
+pub fn compile_fbs_generic_with_options(
+    ugly: bool,
+    include_dirs: &[PathBuf],
+    options: &Options,
     mut input: Box<dyn io::Read>,
-    mut output: Box<dyn io::Write>,
+    output: Box<dyn io::Write>,
 ) -> Result<()> {
     let mut schema_text = String::new();
     input.read_to_string(&mut schema_text)?;
+    let sources = crate::include::load(None, schema_text, include_dirs)?;
+    compile_sources(ugly, &sources, options, output)
+}
+
+/// Generate Rust code for a schema and everything it includes.
+fn compile_sources(
+    ugly: bool,
+    sources: &[Source],
+    options: &Options,
+    mut output: Box<dyn io::Write>,
+) -> Result<()> {
+    // parse the schemas
+    let schemas = sources
+        .iter()
+        .map(Source::parse)
+        .collect::<Result<Vec<_>>>()?;
 
-    // parse the schema
-    let (_, schema) =
-        crate::parser::schema_decl(schema_text.as_str()).map_err(|_| anyhow!("parse failed"))?;
+    let context = crate::codegen::Context::with_options(&schemas, options.clone());
+    validate(sources, &context)?;
 
-    let code = format!("{}", schema.to_token_stream());
+    let code = format!("{}", context.to_token_stream());
 
     let text_output = if !ugly {
-        let mut cmd = Command::new("rustfmt")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .arg("--edition")
-            .arg("2018")
-            .arg("--config")
-            .arg("normalize_doc_attributes=true")
-            .spawn()?;
-        cmd.stdin
-            .as_mut()
-            .ok_or_else(|| anyhow!("cannot access stdin"))?
-            .write_all(code.as_bytes())?;
-        cmd.wait_with_output()?.stdout
+        rustfmt(&code)?
     } else {
         Vec::from(code)
     };
@@ -45,8 +79,90 @@ pub fn compile_fbs_generic(
     Ok(())
 }
 
+/// Format `code` with `rustfmt`.
+pub(crate) fn rustfmt(code: &str) -> Result<Vec<u8>> {
+    let mut cmd = Command::new("rustfmt")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .arg("--edition")
+        .arg("2018")
+        .arg("--config")
+        .arg("normalize_doc_attributes=true")
+        .spawn()?;
+    cmd.stdin
+        .as_mut()
+        .ok_or_else(|| anyhow!("cannot access stdin"))?
+        .write_all(code.as_bytes())?;
+    Ok(cmd.wait_with_output()?.stdout)
+}
+
+/// Write the binary schema (the contents of a `.bfbs` file) of a single flatbuffer schema file
+/// from arbitrary input to arbitrary output.
+///
+/// Files included by the schema are searched for as they are by
+/// [`compile_fbs_generic_with_include_dirs`](crate::compile_fbs_generic_with_include_dirs).
+pub fn compile_bfbs_generic(
+    include_dirs: &[PathBuf],
+    mut input: Box<dyn io::Read>,
+    mut output: Box<dyn io::Write>,
+) -> Result<()> {
+    let mut schema_text = String::new();
+    input.read_to_string(&mut schema_text)?;
+    let sources = crate::include::load(None, schema_text, include_dirs)?;
+    let schemas = sources
+        .iter()
+        .map(Source::parse)
+        .collect::<Result<Vec<_>>>()?;
+    validate(&sources, &crate::codegen::Context::new(&schemas))?;
+    output.write_all(&crate::reflection::binary_schema(&schemas)?)?;
+    Ok(())
+}
+
+/// Check the schemas of `context`, describing every error found along with where it is.
+fn validate(sources: &[Source], context: &crate::codegen::Context) -> Result<()> {
+    let errors = context.validate();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(errors
+            .iter()
+            .map(|error| describe(sources, error))
+            .join("\n\n")))
+    }
+}
+
+/// Describe `error` along with the part of the schema it was found in.
+fn describe(sources: &[Source], error: &crate::validate::Error) -> String {
+    sources
+        .iter()
+        .find_map(|source| Some(source.describe(error, error.span(&source.text)?)))
+        .unwrap_or_else(|| error.to_string())
+}
+
 /// Generate Rust code for a single flatbuffer schema file.
 pub fn compile_fbs(path: impl AsRef<Path>) -> Result<()> {
+    compile_fbs_with_include_dirs(path, &[])
+}
+
+/// Generate Rust code for a single flatbuffer schema file, along with every file it includes.
+///
+/// Included files are searched for relative to the directory of the file including them, then in
+/// each of `include_dirs`. Each file is only compiled once, however many times it's included, and
+/// every namespace ends up in a single module tree in the output.
+pub fn compile_fbs_with_include_dirs(
+    path: impl AsRef<Path>,
+    include_dirs: &[PathBuf],
+) -> Result<()> {
+    compile_fbs_with_options(path, include_dirs, &Options::default())
+}
+
+/// Generate Rust code for a single flatbuffer schema file, along with every file it includes, with
+/// the optional parts of the code chosen by `options`.
+pub fn compile_fbs_with_options(
+    path: impl AsRef<Path>,
+    include_dirs: &[PathBuf],
+    options: &Options,
+) -> Result<()> {
     let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
     let path_ref = path.as_ref();
     let output_path = out_dir.join(
@@ -56,10 +172,12 @@ pub fn compile_fbs(path: impl AsRef<Path>) -> Result<()> {
             .ok_or_else(|| anyhow!("path has no file_name: {:?}", path_ref))?,
     );
     let ugly = false;
-    compile_fbs_generic(
+    let schema_text = std::fs::read_to_string(path_ref)?;
+    let sources = crate::include::load(Some(path_ref), schema_text, include_dirs)?;
+    compile_sources(
         ugly,
-        Box::new(std::fs::File::open(path_ref)?),
+        &sources,
+        options,
         Box::new(std::fs::File::create(output_path)?),
-    )?;
-    Ok(())
+    )
 }
@@ -0,0 +1,148 @@
+/// Point at the location of an error in the text of a schema.
+use std::{fmt, path::Path};
+
+/// A range of bytes in the text of a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Return the span of `part` in `text`, if `part` is a slice of `text`.
+    pub fn of(text: &str, part: &str) -> Option<Self> {
+        let text_start = text.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+        if part_start < text_start || part_start + part.len() > text_start + text.len() {
+            None
+        } else {
+            let start = part_start - text_start;
+            Some(Self {
+                start,
+                end: start + part.len(),
+            })
+        }
+    }
+}
+
+/// An error message along with the part of a schema it refers to.
+///
+/// Displaying a diagnostic shows the message, the file, line and column of the error and the
+/// offending line with the span underlined, in the same format as `rustc`:
+///
+/// ```text
+/// error: expected `;`, found `}`
+///  --> schema.fbs:3:1
+///   |
+/// 3 | }
+///   | ^
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic<'a> {
+    pub message: String,
+
+    /// The file containing `text`, or `None` if the schema wasn't read from a file.
+    pub path: Option<&'a Path>,
+
+    /// The text of the schema.
+    pub text: &'a str,
+
+    pub span: Span,
+}
+
+impl Diagnostic<'_> {
+    /// The line and column `span` starts at, both counting from 1.
+    pub fn line_column(&self) -> (usize, usize) {
+        let before = &self.text[..self.span.start];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        (line, column)
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (line, column) = self.line_column();
+        let line_start = self.text[..self.span.start]
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+        let line_end = self.text[self.span.start..]
+            .find('\n')
+            .map_or(self.text.len(), |i| self.span.start + i);
+        let source_line = self.text[line_start..line_end].trim_end_matches('\r');
+
+        // underline at least one character, even at the end of the input, and stop at the end of
+        // the line when the span covers several lines
+        let width = self.text[self.span.start..self.span.end.min(line_end)]
+            .chars()
+            .count()
+            .max(1);
+        let number = line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        writeln!(f, "error: {}", self.message)?;
+        let path = self
+            .path
+            .map_or_else(|| "<stdin>".to_string(), |path| path.display().to_string());
+        writeln!(f, "{}--> {}:{}:{}", gutter, path, line, column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl std::error::Error for Diagnostic<'_> {}
+
+#[cfg(test)]
+mod diagnostic_tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let text = "table A {\n  x: int\n}\n";
+        let start = text.find('}').unwrap();
+        let diagnostic = Diagnostic {
+            message: "expected `;`, found `}`".to_string(),
+            path: Some(Path::new("a.fbs")),
+            text,
+            span: Span {
+                start,
+                end: start + 1,
+            },
+        };
+        let expected = "\
+error: expected `;`, found `}`
+ --> a.fbs:3:1
+  |
+3 | }
+  | ^";
+        assert_eq!(diagnostic.to_string(), expected);
+    }
+
+    #[test]
+    fn test_display_end_of_input() {
+        let text = "table Monster { name: string";
+        let diagnostic = Diagnostic {
+            message: "expected `;`, found end of file".to_string(),
+            path: None,
+            text,
+            span: Span {
+                start: text.len(),
+                end: text.len(),
+            },
+        };
+        let expected = "\
+error: expected `;`, found end of file
+ --> <stdin>:1:29
+  |
+1 | table Monster { name: string
+  |                             ^";
+        assert_eq!(diagnostic.to_string(), expected);
+    }
+}
@@ -0,0 +1,236 @@
+/// Load schema files along with every file they include.
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use crate::{
+    diagnostic::{Diagnostic, Span},
+    types::Schema,
+};
+
+/// The text of a schema file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Source {
+    /// The path to the file, or `None` if the schema wasn't read from a file.
+    pub path: Option<PathBuf>,
+
+    /// The contents of the file.
+    pub text: String,
+}
+
+impl Source {
+    /// Parse the schema, pointing at the place parsing failed if it isn't valid.
+    pub fn parse(&self) -> Result<Schema<'_>> {
+        crate::parser::parse_schema(&self.text).map_err(|error| {
+            let message = match Span::of(&self.text, error.found()) {
+                Some(span) => self.describe(&error, span),
+                None => error.to_string(),
+            };
+            anyhow!(message)
+        })
+    }
+
+    /// Describe an error found at `span` of the schema, along with the line it was found on.
+    pub fn describe(&self, error: &impl ToString, span: Span) -> String {
+        Diagnostic {
+            message: error.to_string(),
+            path: self.path.as_deref(),
+            text: &self.text,
+            span,
+        }
+        .to_string()
+    }
+}
+
+/// Load the schema in `text` along with every file it transitively includes.
+///
+/// Included files are looked up relative to the directory of the file including them first, and
+/// then in each of `include_dirs` in order. A file included more than once is only loaded once,
+/// no matter how it was included.
+///
+/// Every file is preceded by the files it includes, so the schema itself comes last.
+pub fn load(path: Option<&Path>, text: String, include_dirs: &[PathBuf]) -> Result<Vec<Source>> {
+    let path = path.map(Path::canonicalize).transpose()?;
+    let mut loader = Loader {
+        include_dirs,
+        sources: vec![],
+        stack: path.iter().cloned().collect(),
+    };
+    loader.visit(Source { path, text })?;
+    Ok(loader.sources)
+}
+
+struct Loader<'a> {
+    include_dirs: &'a [PathBuf],
+
+    /// The files loaded so far, in the order they should be compiled.
+    sources: Vec<Source>,
+
+    /// The chain of includes leading to the file currently being loaded.
+    stack: Vec<PathBuf>,
+}
+
+impl Loader<'_> {
+    fn visit(&mut self, source: Source) -> Result<()> {
+        for include in includes(&source)? {
+            let path = self.find(&source, &include)?;
+            if let Some(position) = self.stack.iter().position(|p| *p == path) {
+                return Err(anyhow!(
+                    "include cycle: {}",
+                    self.stack[position..]
+                        .iter()
+                        .chain(std::iter::once(&path))
+                        .map(|p| p.display())
+                        .join(" -> ")
+                ));
+            }
+            if self
+                .sources
+                .iter()
+                .any(|loaded| loaded.path.as_ref() == Some(&path))
+            {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path)?;
+            self.stack.push(path.clone());
+            self.visit(Source {
+                path: Some(path),
+                text,
+            })?;
+            self.stack.pop();
+        }
+        self.sources.push(source);
+        Ok(())
+    }
+
+    /// Find the file `include` refers to when included from `source`.
+    fn find(&self, source: &Source, include: &Path) -> Result<PathBuf> {
+        let source_dir = source
+            .path
+            .as_ref()
+            .and_then(|path| path.parent())
+            .unwrap_or_else(|| Path::new(""));
+        std::iter::once(source_dir)
+            .chain(self.include_dirs.iter().map(PathBuf::as_path))
+            .map(|dir| dir.join(include))
+            .find(|candidate| candidate.is_file())
+            .ok_or_else(|| anyhow!("cannot find included file {}", include.display()))?
+            .canonicalize()
+            .map_err(Into::into)
+    }
+}
+
+/// Return the paths of the files included by `source`.
+fn includes(source: &Source) -> Result<Vec<PathBuf>> {
+    Ok(source
+        .parse()?
+        .includes
+        .iter()
+        .map(|include| include.path.to_path_buf())
+        .collect())
+}
+
+#[cfg(test)]
+mod load_tests {
+    use super::*;
+
+    /// Write `files` to a fresh directory named after `test`, returning the directory.
+    fn write_files(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("butte-{}-{}", test, std::process::id()));
+        for (name, text) in files {
+            let path = dir.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        }
+        dir.canonicalize().unwrap()
+    }
+
+    fn load_file(path: &Path, include_dirs: &[PathBuf]) -> Result<Vec<Source>> {
+        let text = std::fs::read_to_string(path)?;
+        load(Some(path), text, include_dirs)
+    }
+
+    fn file_names(sources: &[Source]) -> Vec<&str> {
+        sources
+            .iter()
+            .map(|source| {
+                source
+                    .path
+                    .as_ref()
+                    .and_then(|path| path.file_name())
+                    .and_then(|name| name.to_str())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_load_diamond() {
+        let dir = write_files(
+            "diamond",
+            &[
+                ("a.fbs", "include \"b.fbs\"; include \"c.fbs\";"),
+                ("b.fbs", "include \"d.fbs\";"),
+                ("c.fbs", "include \"./d.fbs\";"),
+                ("d.fbs", "table D { x: int; }"),
+            ],
+        );
+        let result = load_file(&dir.join("a.fbs"), &[]).unwrap();
+        assert_eq!(
+            file_names(&result),
+            vec!["d.fbs", "b.fbs", "c.fbs", "a.fbs"]
+        );
+    }
+
+    #[test]
+    fn test_load_include_dirs() {
+        let dir = write_files(
+            "include_dirs",
+            &[
+                ("schema/a.fbs", "include \"b.fbs\";"),
+                ("first/b.fbs", "include \"c.fbs\";"),
+                ("second/b.fbs", ""),
+                ("second/c.fbs", ""),
+            ],
+        );
+        let include_dirs = vec![dir.join("first"), dir.join("second")];
+        let result = load_file(&dir.join("schema").join("a.fbs"), &include_dirs).unwrap();
+        let paths = result
+            .iter()
+            .map(|source| source.path.clone().unwrap())
+            .collect::<Vec<_>>();
+        let expected = vec![
+            dir.join("second").join("c.fbs"),
+            dir.join("first").join("b.fbs"),
+            dir.join("schema").join("a.fbs"),
+        ];
+        assert_eq!(paths, expected);
+
+        let result = load_file(&dir.join("schema").join("a.fbs"), &[]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "cannot find included file b.fbs"
+        );
+    }
+
+    #[test]
+    fn test_load_cycle() {
+        let dir = write_files(
+            "cycle",
+            &[
+                ("a.fbs", "include \"b.fbs\";"),
+                ("b.fbs", "include \"c.fbs\";"),
+                ("c.fbs", "include \"b.fbs\";"),
+            ],
+        );
+        let result = load_file(&dir.join("a.fbs"), &[]);
+        let expected = format!(
+            "include cycle: {} -> {} -> {}",
+            dir.join("b.fbs").display(),
+            dir.join("c.fbs").display(),
+            dir.join("b.fbs").display(),
+        );
+        assert_eq!(result.unwrap_err().to_string(), expected);
+    }
+}
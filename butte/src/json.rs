@@ -0,0 +1,1070 @@
+//! Convert flatbuffers to and from the JSON text format `flatc` reads and writes.
+//!
+//! A buffer is converted by walking it with a schema: tables and structs are objects, vectors and
+//! fixed-length arrays are lists, and enums are the names of their variants, or of the flags set
+//! in them separated by spaces. A union field is a pair of fields: `<field>_type`, holding the name
+//! of the variant, and the value itself. A `[ubyte]` field with the `nested_flatbuffer` attribute
+//! is the table stored in it.
+//!
+//! Only the fields present in a buffer are written, and a field equal to its default is left out
+//! of the buffers built from JSON, as the generated builders do. The JSON written is strict JSON,
+//! apart from floats that aren't finite, which are written as `nan`, `inf` and `-inf` like `flatc`
+//! does. The JSON read may also have unquoted keys and comments.
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use flatbuffers::{EndianScalar, FlatBufferBuilder, UOffsetT, UnionWIPOffset, VOffsetT, WIPOffset};
+use itertools::Itertools;
+use nom::sequence::delimited;
+use quote::ToTokens;
+
+use crate::{
+    codegen::{enum_values, Context, InContext},
+    diagnostic::{Diagnostic, Span},
+    parser::{comment_or_space0, object},
+    resolver::{Declaration, Symbol},
+    types::*,
+};
+
+/// Converts buffers whose root is a table of a schema to and from JSON.
+pub struct Converter<'a> {
+    context: Context<'a>,
+
+    /// The table at the root of the buffers.
+    root: Symbol<'a>,
+
+    /// The file identifier of the buffers built from JSON, if the schema declares one.
+    file_identifier: Option<String>,
+}
+
+impl<'a> Converter<'a> {
+    /// Convert buffers whose root is the `root_type` of the last of `schemas`, which are in the
+    /// order they include one another.
+    pub fn new(schemas: &'a [Schema<'a>]) -> Result<Self> {
+        let (namespace, root) = schemas
+            .last()
+            .into_iter()
+            .flat_map(Schema::namespaced_elements)
+            .filter_map(|(namespace, element)| match element {
+                Element::Root(root) => Some((namespace, root)),
+                _ => None,
+            })
+            .last()
+            .ok_or_else(|| anyhow!("the schema doesn't declare a root_type"))?;
+        let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+        Self::with_root_in(schemas, namespace, &vec![root.typename].into())
+    }
+
+    /// Convert buffers whose root is the table `root`, given by its fully-qualified name such as
+    /// `zoo.Pen`.
+    pub fn with_root(schemas: &'a [Schema<'a>], root: &str) -> Result<Self> {
+        let root = root.split('.').map(Ident::from).collect::<Vec<_>>();
+        Self::with_root_in(schemas, &[], &root.into())
+    }
+
+    fn with_root_in(
+        schemas: &'a [Schema<'a>],
+        namespace: &'a [Ident<'a>],
+        root: &DottedIdent,
+    ) -> Result<Self> {
+        let context = Context::new(schemas);
+        let errors = context.validate();
+        if !errors.is_empty() {
+            bail!(errors.iter().join("\n"));
+        }
+        let name = root.parts.iter().map(|part| part.raw).join(".");
+        let root = match context.with(namespace, &()).resolve(root) {
+            Some(
+                symbol @ Symbol {
+                    declaration: Declaration::Table(_),
+                    ..
+                },
+            ) => symbol,
+            Some(_) => bail!("the root type `{}` isn't a table", name),
+            None => bail!("the root type `{}` isn't declared", name),
+        };
+        let file_identifier = schemas
+            .last()
+            .into_iter()
+            .flat_map(|schema| &schema.elements)
+            .find_map(|element| match element {
+                Element::FileIdentifier(identifier) => Some(identifier.id.iter().collect()),
+                _ => None,
+            });
+        Ok(Self {
+            context,
+            root,
+            file_identifier,
+        })
+    }
+
+    fn root(&self) -> InContext<'_, 'a, Table<'a>> {
+        match self.root.declaration {
+            Declaration::Table(table) => self.context.with(self.root.namespace, table),
+            _ => unreachable!("the root type is always a table"),
+        }
+    }
+
+    /// Convert `buf` to JSON.
+    ///
+    /// Every offset and length in the buffer is checked as it's followed, so an invalid buffer is
+    /// an error rather than a panic.
+    pub fn to_json(&self, buf: &[u8]) -> Result<String> {
+        let json = table_json(&self.root(), buf, follow(buf, 0)?)?;
+        let mut out = String::new();
+        json.write(&mut out, 0);
+        out.push('\n');
+        Ok(out)
+    }
+
+    /// Build a buffer out of the JSON in `text`.
+    pub fn from_json(&self, text: &str) -> Result<Vec<u8>> {
+        let value = Value::Object(parse_json(text)?);
+        let mut fbb = FlatBufferBuilder::new();
+        let root = build_table(&mut fbb, &self.root(), &value)?;
+        fbb.finish(
+            WIPOffset::<UnionWIPOffset>::new(root),
+            self.file_identifier.as_deref(),
+        );
+        Ok(fbb.finished_data().to_vec())
+    }
+}
+
+/// Parse a JSON object, pointing at the place parsing failed if it isn't valid.
+fn parse_json(text: &str) -> Result<Object<'_>> {
+    let fail = |found: &str, message: String| {
+        let span = Span::of(text, found).unwrap_or(Span {
+            start: text.len(),
+            end: text.len(),
+        });
+        anyhow!(Diagnostic {
+            message,
+            path: None,
+            text,
+            span,
+        }
+        .to_string())
+    };
+    match delimited(comment_or_space0, object, comment_or_space0)(text) {
+        Ok(("", object)) => Ok(object),
+        Ok((rest, _)) => Err(fail(
+            rest,
+            "expected the end of the JSON object".to_string(),
+        )),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => Err(fail(e.found(), e.to_string())),
+        Err(nom::Err::Incomplete(_)) => Err(fail("", "unexpected end of the JSON".to_string())),
+    }
+}
+
+/// What a type is, with references to user-defined types resolved.
+#[derive(Clone, Copy)]
+enum Kind<'a, 't> {
+    Scalar(&'t Type<'a>),
+    Enum(&'a Enum<'a>),
+    Struct(Symbol<'a>, &'a Struct<'a>),
+    Table(Symbol<'a>, &'a Table<'a>),
+    Union(Symbol<'a>, &'a Union<'a>),
+    String,
+    Vector(&'t Type<'a>),
+    FixedArray(&'t Type<'a>, usize),
+}
+
+fn kind_of<'a, 't, T>(scope: &InContext<'_, 'a, T>, ty: &'t Type<'a>) -> Result<Kind<'a, 't>> {
+    Ok(match ty {
+        Type::String => Kind::String,
+        Type::Array(element) => Kind::Vector(element),
+        Type::FixedArray(element, length) => Kind::FixedArray(element, *length),
+        Type::Ident(ident) => {
+            let symbol = scope.resolve(ident).ok_or_else(|| {
+                anyhow!(
+                    "the type `{}` isn't declared",
+                    ident.parts.iter().map(|part| part.raw).join(".")
+                )
+            })?;
+            match symbol.declaration {
+                Declaration::Enum(e) => Kind::Enum(e),
+                Declaration::Struct(s) => Kind::Struct(symbol, s),
+                Declaration::Table(t) => Kind::Table(symbol, t),
+                Declaration::Union(u) => Kind::Union(symbol, u),
+            }
+        }
+        scalar => Kind::Scalar(scalar),
+    })
+}
+
+/// The variants of `union`, along with their types and the tables they refer to.
+fn union_variants<'a>(
+    context: &Context<'a>,
+    symbol: &Symbol<'a>,
+    union: &'a Union<'a>,
+) -> impl Iterator<Item = (Ident<'a>, IntegerConstant, Option<Symbol<'a>>)> + 'a {
+    let scope = context.with(symbol.namespace, union);
+    let variants = enum_values(&union.values, 1)
+        .map(|(id, value)| (id, value, scope.resolve(&vec![id].into())))
+        .collect::<Vec<_>>();
+    variants.into_iter()
+}
+
+/// The table stored in a field with the `nested_flatbuffer` attribute, if the field has it.
+fn nested_root<'a>(
+    scope: &InContext<'_, 'a, Table<'a>>,
+    field: &Field,
+) -> Result<Option<(Symbol<'a>, &'a Table<'a>)>> {
+    let name = match field
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.values.get(&Ident::from("nested_flatbuffer")))
+    {
+        Some(Some(Single::String(name))) => name,
+        _ => return Ok(None),
+    };
+    let ident = name.split('.').map(Ident::from).collect::<Vec<_>>().into();
+    match scope.resolve(&ident) {
+        Some(
+            symbol @ Symbol {
+                declaration: Declaration::Table(table),
+                ..
+            },
+        ) => Ok(Some((symbol, table))),
+        _ => bail!("the nested flatbuffer type `{}` isn't a table", name),
+    }
+}
+
+/// A JSON value, as read from a buffer.
+enum Json {
+    Bool(bool),
+    Number(String),
+    String(String),
+    List(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Write the value, with every element of a list or an object on its own line.
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = |out: &mut String, depth: usize| {
+            out.push('\n');
+            for _ in 0..depth {
+                out.push_str("  ");
+            }
+        };
+        match self {
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Json::Number(number) => out.push_str(number),
+            Json::String(string) => write_string(out, string),
+            Json::List(items) if items.is_empty() => out.push_str("[]"),
+            Json::List(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    indent(out, depth + 1);
+                    item.write(out, depth + 1);
+                }
+                indent(out, depth);
+                out.push(']');
+            }
+            Json::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Object(fields) => {
+                out.push('{');
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    indent(out, depth + 1);
+                    write_string(out, name);
+                    out.push_str(": ");
+                    value.write(out, depth + 1);
+                }
+                indent(out, depth);
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// Write `string` as a JSON string, escaping quotes, backslashes and control characters.
+fn write_string(out: &mut String, string: &str) {
+    out.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Replace the escape sequences in a JSON string with the characters they stand for.
+fn unescape(string: &str) -> Result<String> {
+    fn hex(chars: &mut std::str::Chars) -> Option<u32> {
+        let digits = chars.by_ref().take(4).collect::<String>();
+        if digits.len() == 4 {
+            u32::from_str_radix(&digits, 16).ok()
+        } else {
+            None
+        }
+    }
+
+    let invalid = || anyhow!("invalid escape sequence in \"{}\"", string);
+    let mut out = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        out.push(match chars.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let mut code = hex(&mut chars).ok_or_else(invalid)?;
+                // characters outside the basic multilingual plane are written as a surrogate pair
+                if (0xd800..0xdc00).contains(&code) {
+                    if chars.next() != Some('\\') || chars.next() != Some('u') {
+                        return Err(invalid());
+                    }
+                    let low = hex(&mut chars)
+                        .filter(|low| (0xdc00..0xe000).contains(low))
+                        .ok_or_else(invalid)?;
+                    code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                }
+                std::char::from_u32(code).ok_or_else(invalid)?
+            }
+            _ => return Err(invalid()),
+        });
+    }
+    Ok(out)
+}
+
+/// Read a scalar at `loc`, failing if it isn't inside `buf`.
+fn read<T: EndianScalar>(buf: &[u8], loc: usize) -> Result<T> {
+    match loc.checked_add(std::mem::size_of::<T>()) {
+        Some(end) if end <= buf.len() => Ok(flatbuffers::read_scalar_at::<T>(buf, loc)),
+        _ => bail!("the buffer ends before the value at {}", loc),
+    }
+}
+
+/// Follow the offset stored at `loc` to the data it points to.
+fn follow(buf: &[u8], loc: usize) -> Result<usize> {
+    Ok(loc + read::<UOffsetT>(buf, loc)? as usize)
+}
+
+/// The location and length of the vector `loc` points to, checking all of it is inside `buf`.
+fn vector_at(buf: &[u8], loc: usize, element_size: usize) -> Result<(usize, usize)> {
+    let vector = follow(buf, loc)?;
+    let len = read::<UOffsetT>(buf, vector)? as usize;
+    let start = vector + flatbuffers::SIZE_UOFFSET;
+    if start + len * element_size > buf.len() {
+        bail!("the buffer ends before the vector at {}", vector);
+    }
+    Ok((start, len))
+}
+
+fn string_at(buf: &[u8], loc: usize) -> Result<&str> {
+    let (start, len) = vector_at(buf, loc, 1)?;
+    if buf.get(start + len) != Some(&0) {
+        bail!("the string at {} isn't null terminated", start);
+    }
+    std::str::from_utf8(&buf[start..start + len])
+        .map_err(|_| anyhow!("the string at {} isn't valid UTF-8", start))
+}
+
+/// Format a float the way `flatc` does, which isn't valid JSON when it isn't finite.
+fn float_text<F: Into<f64> + std::fmt::Debug + Copy>(value: F) -> String {
+    let wide = value.into();
+    if wide.is_nan() {
+        "nan".to_string()
+    } else if wide.is_infinite() {
+        if wide > 0.0 { "inf" } else { "-inf" }.to_string()
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+/// Read the integer of type `ty` at `loc`.
+fn read_integer(buf: &[u8], loc: usize, ty: &Type) -> Result<i128> {
+    Ok(match ty {
+        Type::Bool | Type::UByte | Type::UInt8 => read::<u8>(buf, loc)?.into(),
+        Type::Byte | Type::Int8 => read::<i8>(buf, loc)?.into(),
+        Type::Short | Type::Int16 => read::<i16>(buf, loc)?.into(),
+        Type::UShort | Type::UInt16 => read::<u16>(buf, loc)?.into(),
+        Type::Int | Type::Int32 => read::<i32>(buf, loc)?.into(),
+        Type::UInt | Type::UInt32 => read::<u32>(buf, loc)?.into(),
+        Type::Long | Type::Int64 => read::<i64>(buf, loc)?.into(),
+        Type::ULong | Type::UInt64 => read::<u64>(buf, loc)?.into(),
+        _ => bail!("`{}` isn't an integer type", ty.to_token_stream()),
+    })
+}
+
+fn scalar_json(buf: &[u8], loc: usize, ty: &Type) -> Result<Json> {
+    Ok(match ty {
+        Type::Bool => Json::Bool(read::<u8>(buf, loc)? != 0),
+        Type::Float | Type::Float32 => Json::Number(float_text(read::<f32>(buf, loc)?)),
+        Type::Double | Type::Float64 => Json::Number(float_text(read::<f64>(buf, loc)?)),
+        _ => Json::Number(read_integer(buf, loc, ty)?.to_string()),
+    })
+}
+
+/// The values of the flags of bit flags, along with their names.
+fn flag_values<'a>(e: &'a Enum<'a>) -> impl Iterator<Item = (Ident<'a>, i128)> + 'a {
+    enum_values(&e.values, 0).map(|(id, bit)| {
+        debug_assert!(
+            (0..64).contains(&bit),
+            "validation rejects bits that don't fit in the underlying type"
+        );
+        (id, (1u64 << bit).into())
+    })
+}
+
+/// An enum is the name of its variant, and bit flags are the names of the flags set in them, or a
+/// number when the value isn't one of those.
+fn enum_json(e: &Enum, value: i128) -> Json {
+    if e.is_bit_flags() {
+        let flags = flag_values(e)
+            .filter(|(_, flag)| value & flag == *flag)
+            .collect::<Vec<_>>();
+        if value != 0 && flags.iter().fold(0, |all, (_, flag)| all | flag) == value {
+            return Json::String(flags.iter().map(|(id, _)| id.raw).join(" "));
+        }
+    } else if let Some((id, _)) = enum_values(&e.values, 0).find(|(_, v)| i128::from(*v) == value) {
+        return Json::String(id.raw.to_string());
+    }
+    Json::Number(value.to_string())
+}
+
+/// Read the value of type `kind` stored at `loc`, which for strings, tables and vectors is the
+/// location of the offset to them.
+fn value_json<'a, T>(
+    scope: &InContext<'_, 'a, T>,
+    kind: Kind<'a, '_>,
+    buf: &[u8],
+    loc: usize,
+) -> Result<Json> {
+    Ok(match kind {
+        Kind::Scalar(ty) => scalar_json(buf, loc, ty)?,
+        Kind::Enum(e) => enum_json(e, read_integer(buf, loc, &e.base_type)?),
+        Kind::Struct(symbol, s) => struct_json(&scope.context.with(symbol.namespace, s), buf, loc)?,
+        Kind::Table(symbol, t) => table_json(
+            &scope.context.with(symbol.namespace, t),
+            buf,
+            follow(buf, loc)?,
+        )?,
+        Kind::String => Json::String(string_at(buf, loc)?.to_string()),
+        Kind::Vector(element) => {
+            let size = scope
+                .inline_layout(element)
+                .map_or(flatbuffers::SIZE_UOFFSET, |(size, _)| size);
+            let (start, len) = vector_at(buf, loc, size)?;
+            let element = kind_of(scope, element)?;
+            Json::List(
+                (0..len)
+                    .map(|i| value_json(scope, element, buf, start + i * size))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        Kind::FixedArray(element, length) => {
+            let (size, _) = scope.inline_layout(element).unwrap_or((0, 1));
+            let element = kind_of(scope, element)?;
+            Json::List(
+                (0..length)
+                    .map(|i| value_json(scope, element, buf, loc + i * size))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        Kind::Union(..) => bail!("vectors of unions aren't supported"),
+    })
+}
+
+fn struct_json(scope: &InContext<Struct>, buf: &[u8], loc: usize) -> Result<Json> {
+    let (offsets, ..) = scope.layout();
+    let fields = scope
+        .item
+        .fields
+        .iter()
+        .zip(offsets)
+        .map(|(field, offset)| {
+            let value = value_json(scope, kind_of(scope, &field.ty)?, buf, loc + offset)?;
+            Ok((field.id.raw.to_string(), value))
+        })
+        .collect::<Result<_>>()?;
+    Ok(Json::Object(fields))
+}
+
+fn table_json<'a>(scope: &InContext<'_, 'a, Table<'a>>, buf: &[u8], loc: usize) -> Result<Json> {
+    let vtable = usize::try_from(loc as i64 - i64::from(read::<i32>(buf, loc)?))
+        .map_err(|_| anyhow!("the vtable of the table at {} is outside the buffer", loc))?;
+    let vtable_len = usize::from(read::<u16>(buf, vtable)?);
+    // the location of the field in `slot`, or `None` if it isn't set
+    let field_loc = |slot: VOffsetT| -> Result<Option<usize>> {
+        let voffset = flatbuffers::field_index_to_field_offset(slot) as usize;
+        if voffset + 2 > vtable_len {
+            return Ok(None);
+        }
+        Ok(match read::<u16>(buf, vtable + voffset)? {
+            0 => None,
+            offset => Some(loc + usize::from(offset)),
+        })
+    };
+
+    // like `flatc`, write the fields in the order of their ids
+    let mut slots = scope
+        .item
+        .fields
+        .iter()
+        .zip(scope.slots())
+        .collect::<Vec<_>>();
+    slots.sort_by_key(|(_, slot)| *slot);
+    let mut fields = vec![];
+    for (field, slot) in slots {
+        if field.is_deprecated() {
+            continue;
+        }
+        let name = field.id.raw;
+        (|| -> Result<()> {
+            match kind_of(scope, &field.ty)? {
+                Kind::Union(symbol, union) => {
+                    let tag = match field_loc(slot - 1)? {
+                        Some(loc) => read::<u8>(buf, loc)?,
+                        None => 0,
+                    };
+                    if tag == 0 {
+                        return Ok(());
+                    }
+                    // a variant added by a newer version of the schema only shows its type
+                    let variant = union_variants(scope.context, &symbol, union)
+                        .find(|(_, value, _)| *value == IntegerConstant::from(tag));
+                    let type_name = format!("{}_type", name);
+                    match variant {
+                        Some((id, _, table)) => {
+                            fields.push((type_name, Json::String(id.raw.to_string())));
+                            if let (
+                                Some(Symbol {
+                                    namespace,
+                                    declaration: Declaration::Table(table),
+                                    ..
+                                }),
+                                Some(loc),
+                            ) = (table, field_loc(slot)?)
+                            {
+                                let table = scope.context.with(namespace, table);
+                                fields.push((
+                                    name.to_string(),
+                                    table_json(&table, buf, follow(buf, loc)?)?,
+                                ));
+                            }
+                        }
+                        None => fields.push((type_name, Json::Number(tag.to_string()))),
+                    }
+                }
+                kind => {
+                    if let Some(loc) = field_loc(slot)? {
+                        let value = match nested_root(scope, field)? {
+                            Some((symbol, table)) => {
+                                let (start, len) = vector_at(buf, loc, 1)?;
+                                let nested = &buf[start..start + len];
+                                let table = scope.context.with(symbol.namespace, table);
+                                table_json(&table, nested, follow(nested, 0)?)?
+                            }
+                            None => value_json(scope, kind, buf, loc)?,
+                        };
+                        fields.push((name.to_string(), value));
+                    }
+                }
+            }
+            Ok(())
+        })()
+        .with_context(|| format!("reading field `{}` of `{}`", name, scope.item.id.raw))?;
+    }
+    Ok(Json::Object(fields))
+}
+
+/// The little-endian bytes of `value` as a scalar of type `ty`, failing if it doesn't fit.
+fn integer_bytes(ty: &Type, value: i128) -> Result<Vec<u8>> {
+    macro_rules! bytes {
+        ($t:ty) => {
+            <$t>::try_from(value)
+                .map(|value| value.to_le_bytes().to_vec())
+                .map_err(|_| anyhow!("{} doesn't fit in `{}`", value, ty.to_token_stream()))
+        };
+    }
+    match ty {
+        Type::Bool if value == 0 || value == 1 => Ok(vec![value as u8]),
+        Type::Bool => bail!("{} isn't a bool", value),
+        Type::Byte | Type::Int8 => bytes!(i8),
+        Type::UByte | Type::UInt8 => bytes!(u8),
+        Type::Short | Type::Int16 => bytes!(i16),
+        Type::UShort | Type::UInt16 => bytes!(u16),
+        Type::Int | Type::Int32 => bytes!(i32),
+        Type::UInt | Type::UInt32 => bytes!(u32),
+        Type::Long | Type::Int64 => bytes!(i64),
+        Type::ULong | Type::UInt64 => bytes!(u64),
+        Type::Float | Type::Float32 => Ok((value as f32).to_le_bytes().to_vec()),
+        Type::Double | Type::Float64 => Ok((value as f64).to_le_bytes().to_vec()),
+        _ => bail!("`{}` isn't a scalar type", ty.to_token_stream()),
+    }
+}
+
+fn scalar_bytes(ty: &Type, value: &Value) -> Result<Vec<u8>> {
+    match value {
+        Value::Single(Single::Scalar(Scalar::Integer(value))) => integer_bytes(ty, (*value).into()),
+        Value::Single(Single::Scalar(Scalar::Boolean(value))) => integer_bytes(ty, *value as i128),
+        Value::Single(Single::Scalar(Scalar::Float(value))) => match ty {
+            Type::Float | Type::Float32 => Ok((*value as f32).to_le_bytes().to_vec()),
+            Type::Double | Type::Float64 => Ok(value.to_le_bytes().to_vec()),
+            _ => bail!("expected an integer, found {}", value),
+        },
+        _ => bail!("expected a `{}`", ty.to_token_stream()),
+    }
+}
+
+/// The value of an enum given as a number or by name. Bit flags may be given as the names of the
+/// flags set in them, separated by spaces.
+fn enum_value(e: &Enum, value: &Value) -> Result<i128> {
+    let name = match value {
+        Value::Single(Single::Scalar(Scalar::Integer(value))) => return Ok((*value).into()),
+        Value::Single(Single::String(name)) => name,
+        _ => bail!("expected a variant of `{}`", e.id.raw),
+    };
+    let variant = |name: &str| -> Result<i128> {
+        let found = if e.is_bit_flags() {
+            flag_values(e).find(|(id, _)| id.raw == name)
+        } else {
+            enum_values(&e.values, 0)
+                .map(|(id, value)| (id, value.into()))
+                .find(|(id, _)| id.raw == name)
+        };
+        found
+            .map(|(_, value)| value)
+            .ok_or_else(|| anyhow!("`{}` isn't a variant of `{}`", name, e.id.raw))
+    };
+    if e.is_bit_flags() {
+        name.split_whitespace()
+            .try_fold(0, |all, flag| Ok(all | variant(flag)?))
+    } else {
+        variant(name)
+    }
+}
+
+/// The bytes of a value stored inline: a scalar, an enum, a struct or a fixed-length array.
+fn inline_bytes<'a, T>(
+    scope: &InContext<'_, 'a, T>,
+    kind: Kind<'a, '_>,
+    value: &Value,
+) -> Result<Vec<u8>> {
+    match kind {
+        Kind::Scalar(ty) => scalar_bytes(ty, value),
+        Kind::Enum(e) => integer_bytes(&e.base_type, enum_value(e, value)?),
+        Kind::Struct(symbol, s) => struct_bytes(&scope.context.with(symbol.namespace, s), value),
+        Kind::FixedArray(element, length) => match value {
+            Value::List(items) if items.len() == length => {
+                let element = kind_of(scope, element)?;
+                items.iter().try_fold(vec![], |mut bytes, item| {
+                    bytes.extend(inline_bytes(scope, element, item)?);
+                    Ok(bytes)
+                })
+            }
+            _ => bail!("expected a list of {} elements", length),
+        },
+        _ => bail!("expected a value stored inline"),
+    }
+}
+
+fn struct_bytes(scope: &InContext<Struct>, value: &Value) -> Result<Vec<u8>> {
+    let object = match value {
+        Value::Object(object) => object,
+        _ => bail!("expected an object for the struct `{}`", scope.item.id.raw),
+    };
+    check_keys(object, scope.item.id.raw, &scope.item.fields)?;
+    let (offsets, size, _) = scope.layout();
+    let mut bytes = vec![0; size];
+    for (field, offset) in scope.item.fields.iter().zip(offsets) {
+        let name = field.id.raw;
+        let value = member(object, name)
+            .ok_or_else(|| anyhow!("the struct `{}` is missing `{}`", scope.item.id.raw, name))?;
+        let field_bytes = inline_bytes(scope, kind_of(scope, &field.ty)?, value)
+            .with_context(|| format!("in field `{}` of `{}`", name, scope.item.id.raw))?;
+        bytes[offset..offset + field_bytes.len()].copy_from_slice(&field_bytes);
+    }
+    Ok(bytes)
+}
+
+/// The bytes of the default of a scalar or enum field of a table, or `None` for an optional
+/// scalar, which has no default.
+fn default_bytes<'a>(
+    scope: &InContext<'_, 'a, Table<'a>>,
+    field: &Field,
+    kind: Kind<'a, '_>,
+) -> Result<Option<Vec<u8>>> {
+    let default = match field.default {
+        Some(DefaultValue::Null) => return Ok(None),
+        Some(DefaultValue::Scalar(scalar)) => Value::Single(Single::Scalar(scalar)),
+        Some(DefaultValue::Ident(id)) => Value::Single(Single::String(id.raw)),
+        None => Value::Single(Single::Scalar(Scalar::Integer(0))),
+    };
+    inline_bytes(scope, kind, &default).map(Some)
+}
+
+fn member<'o, 'a>(object: &'o Object<'a>, key: &str) -> Option<&'o Value<'a>> {
+    object
+        .values
+        .iter()
+        .find(|(id, _)| id.raw == key)
+        .map(|(_, value)| value)
+}
+
+/// Fail if a key of `object` isn't one of `fields`, or the type of a union field.
+fn check_keys(object: &Object, parent: &str, fields: &[Field]) -> Result<()> {
+    let known = |key: &str| {
+        fields.iter().any(|field| {
+            let name = field.id.raw;
+            key == name || key.strip_suffix("_type") == Some(name)
+        })
+    };
+    match object.values.keys().find(|key| !known(key.raw)) {
+        Some(key) => bail!("`{}` has no field `{}`", parent, key.raw),
+        None => Ok(()),
+    }
+}
+
+/// Build a string, a table or a vector, returning its offset.
+fn build_offset<'a, T>(
+    fbb: &mut FlatBufferBuilder,
+    scope: &InContext<'_, 'a, T>,
+    kind: Kind<'a, '_>,
+    value: &Value,
+) -> Result<UOffsetT> {
+    Ok(match (kind, value) {
+        (Kind::String, Value::Single(Single::String(string))) => {
+            fbb.create_string(&unescape(string)?).value()
+        }
+        (Kind::String, _) => bail!("expected a string"),
+        (Kind::Table(symbol, t), _) => {
+            build_table(fbb, &scope.context.with(symbol.namespace, t), value)?
+        }
+        (Kind::Vector(element), Value::List(items)) => {
+            if let Some((_, alignment)) = scope.inline_layout(element) {
+                let element = kind_of(scope, element)?;
+                let bytes = items.iter().try_fold(vec![], |mut bytes, item| {
+                    bytes.extend(inline_bytes(scope, element, item)?);
+                    Ok::<_, anyhow::Error>(bytes)
+                })?;
+                fbb.create_vector_from_bytes(&bytes, items.len(), alignment)
+                    .value()
+            } else {
+                let element = kind_of(scope, element)?;
+                let offsets = items
+                    .iter()
+                    .map(|item| build_offset(fbb, scope, element, item))
+                    .collect::<Result<Vec<_>>>()?;
+                fbb.start_vector::<WIPOffset<UnionWIPOffset>>(offsets.len());
+                for offset in offsets.iter().rev() {
+                    fbb.push(WIPOffset::<UnionWIPOffset>::new(*offset));
+                }
+                fbb.end_vector::<WIPOffset<UnionWIPOffset>>(offsets.len())
+                    .value()
+            }
+        }
+        (Kind::Vector(_), _) => bail!("expected a list"),
+        (Kind::Union(..), _) => bail!("vectors of unions aren't supported"),
+        _ => bail!("expected a value stored as an offset"),
+    })
+}
+
+/// A field of a table being built, which is either stored inline or as an offset.
+enum Slot {
+    Inline(Vec<u8>, usize),
+    Offset(UOffsetT),
+}
+
+fn build_table<'a>(
+    fbb: &mut FlatBufferBuilder,
+    scope: &InContext<'_, 'a, Table<'a>>,
+    value: &Value,
+) -> Result<UOffsetT> {
+    let table_name = scope.item.id.raw;
+    let object = match value {
+        Value::Object(object) => object,
+        _ => bail!("expected an object for the table `{}`", table_name),
+    };
+    check_keys(object, table_name, &scope.item.fields)?;
+
+    // everything the table refers to has to be built before the table itself
+    let mut slots = vec![];
+    for (field, slot) in scope.item.fields.iter().zip(scope.slots()) {
+        if field.is_deprecated() {
+            continue;
+        }
+        let name = field.id.raw;
+        let value = member(object, name);
+        (|| -> Result<()> {
+            match (kind_of(scope, &field.ty)?, value) {
+                (Kind::Union(symbol, union), _) => {
+                    let type_name = format!("{}_type", name);
+                    let tag = match member(object, &type_name) {
+                        None => 0,
+                        Some(Value::Single(Single::String("NONE"))) => 0,
+                        Some(Value::Single(Single::String(variant))) => {
+                            union_variants(scope.context, &symbol, union)
+                                .find(|(id, ..)| id.raw == *variant)
+                                .map(|(_, value, _)| value)
+                                .ok_or_else(|| {
+                                    anyhow!("`{}` isn't a variant of `{}`", variant, union.id.raw)
+                                })?
+                        }
+                        Some(Value::Single(Single::Scalar(Scalar::Integer(tag)))) => *tag,
+                        Some(_) => bail!("expected a variant of `{}`", union.id.raw),
+                    };
+                    let table = union_variants(scope.context, &symbol, union)
+                        .find(|(_, value, _)| *value == tag)
+                        .and_then(|(.., table)| table);
+                    match (tag, value, table) {
+                        (0, None, _) => {}
+                        (0, Some(_), _) => bail!("`{}` is set without `{}`", name, type_name),
+                        (_, None, _) => bail!("`{}` is set without `{}`", type_name, name),
+                        (
+                            _,
+                            Some(value),
+                            Some(Symbol {
+                                namespace,
+                                declaration: Declaration::Table(table),
+                                ..
+                            }),
+                        ) => {
+                            let table = scope.context.with(namespace, table);
+                            let offset = build_table(fbb, &table, value)?;
+                            let tag = integer_bytes(&Type::UByte, tag.into())?;
+                            slots.push((slot - 1, Slot::Inline(tag, 1)));
+                            slots.push((slot, Slot::Offset(offset)));
+                        }
+                        (_, Some(_), _) => bail!("{} isn't a variant of `{}`", tag, union.id.raw),
+                    }
+                }
+                (_, None) if field.is_required() => bail!("the field is required"),
+                (_, None) => {}
+                (kind @ Kind::Scalar(_), Some(value)) | (kind @ Kind::Enum(_), Some(value)) => {
+                    let bytes = inline_bytes(scope, kind, value)?;
+                    // a field equal to its default is left out
+                    if default_bytes(scope, field, kind)?.as_ref() != Some(&bytes) {
+                        let alignment = bytes.len();
+                        slots.push((slot, Slot::Inline(bytes, alignment)));
+                    }
+                }
+                (kind @ Kind::Struct(..), Some(value)) => {
+                    let (_, alignment) = scope.inline_layout(&field.ty).unwrap_or((0, 1));
+                    slots.push((
+                        slot,
+                        Slot::Inline(inline_bytes(scope, kind, value)?, alignment),
+                    ));
+                }
+                (kind, Some(value)) => {
+                    let offset = match (nested_root(scope, field)?, value) {
+                        (Some((symbol, table)), Value::Object(_)) => {
+                            let mut nested = FlatBufferBuilder::new();
+                            let table = scope.context.with(symbol.namespace, table);
+                            let root = build_table(&mut nested, &table, value)?;
+                            nested.finish_minimal(WIPOffset::<UnionWIPOffset>::new(root));
+                            let bytes = nested.finished_data();
+                            // the nested buffer is aligned for the largest scalar it may hold
+                            fbb.create_vector_from_bytes(bytes, bytes.len(), 8).value()
+                        }
+                        _ => build_offset(fbb, scope, kind, value)?,
+                    };
+                    slots.push((slot, Slot::Offset(offset)));
+                }
+            }
+            Ok(())
+        })()
+        .with_context(|| format!("in field `{}` of `{}`", name, table_name))?;
+    }
+
+    // the most aligned fields go first, to leave as little padding between fields as possible
+    slots.sort_by_key(|(_, slot)| match slot {
+        Slot::Inline(_, alignment) => std::cmp::Reverse(*alignment),
+        Slot::Offset(_) => std::cmp::Reverse(flatbuffers::SIZE_UOFFSET),
+    });
+    let start = fbb.start_table();
+    for (slot, value) in slots {
+        let slot = flatbuffers::field_index_to_field_offset(slot);
+        match value {
+            Slot::Inline(bytes, alignment) => fbb.push_slot_bytes(slot, &bytes, alignment),
+            Slot::Offset(offset) => {
+                fbb.push_slot_always(slot, WIPOffset::<UnionWIPOffset>::new(offset))
+            }
+        }
+    }
+    let table = fbb.end_table(start);
+    Ok(table.value())
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::*;
+    use crate::parser::parse_schema;
+
+    const SCHEMA: &str = r#"
+namespace test;
+enum Color : byte { Red = -1, Green, Blue }
+enum Perm : ubyte (bit_flags) { Read, Write }
+struct Vec2 { x: float; y: float; }
+struct Cell { pos: Vec2; tags: [ubyte:2]; }
+table Dog { name: string; }
+table Cat { lives: int = 9; }
+union Pet { Dog, Cat }
+table Owner {
+  name: string (required);
+  age: ushort = 30;
+  color: Color = Blue;
+  perm: Perm;
+  cell: Cell;
+  scores: [double];
+  names: [string];
+  pets: [Dog];
+  pet: Pet;
+  inner: [ubyte] (nested_flatbuffer: "Dog");
+  old: int (deprecated);
+}
+root_type Owner;
+file_identifier "OWNR";
+"#;
+
+    fn round_trip(json: &str) -> Result<String> {
+        let schemas = [parse_schema(SCHEMA).unwrap()];
+        let converter = Converter::new(&schemas)?;
+        let buf = converter.from_json(json)?;
+        converter.to_json(&buf)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = r#"{
+  // unquoted keys and comments are fine
+  name: "Ann \"A\" é\n",
+  "age": 30,
+  color: "Red",
+  perm: "Write Read",
+  cell: { pos: { x: 1.5, y: -2 }, tags: [1, 2] },
+  scores: [0.5, 1e100],
+  names: ["a"],
+  pets: [{ name: "Rex" }, {}],
+  pet_type: "Cat",
+  pet: { lives: 7 },
+  inner: { name: "Nested" }
+}"#;
+        let expected = r#"{
+  "name": "Ann \"A\" é\n",
+  "color": "Red",
+  "perm": "Read Write",
+  "cell": {
+    "pos": {
+      "x": 1.5,
+      "y": -2.0
+    },
+    "tags": [
+      1,
+      2
+    ]
+  },
+  "scores": [
+    0.5,
+    1e100
+  ],
+  "names": [
+    "a"
+  ],
+  "pets": [
+    {
+      "name": "Rex"
+    },
+    {}
+  ],
+  "pet_type": "Cat",
+  "pet": {
+    "lives": 7
+  },
+  "inner": {
+    "name": "Nested"
+  }
+}
+"#;
+        assert_eq!(round_trip(input).unwrap(), expected);
+        assert_eq!(round_trip(expected).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_defaults_are_left_out() {
+        let result = round_trip(r#"{name: "", age: 30, color: "Blue", perm: 0, pet_type: "NONE"}"#);
+        assert_eq!(result.unwrap(), "{\n  \"name\": \"\"\n}\n");
+        let result = round_trip(r#"{name: "", age: 31, color: 0, perm: 3}"#);
+        assert_eq!(
+            result.unwrap(),
+            "{\n  \"name\": \"\",\n  \"age\": 31,\n  \"color\": \"Green\",\n  \"perm\": \"Read Write\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_file_identifier() {
+        let schemas = [parse_schema(SCHEMA).unwrap()];
+        let converter = Converter::new(&schemas).unwrap();
+        let buf = converter.from_json(r#"{name: "x"}"#).unwrap();
+        assert_eq!(&buf[4..8], b"OWNR");
+
+        let converter = Converter::with_root(&schemas, "test.Dog").unwrap();
+        let buf = converter.from_json(r#"{name: "x"}"#).unwrap();
+        assert_eq!(
+            converter.to_json(&buf).unwrap(),
+            "{\n  \"name\": \"x\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let error = |json| format!("{:#}", round_trip(json).unwrap_err());
+        assert!(error(r#"{name: "x", legs: 4}"#).contains("`Owner` has no field `legs`"));
+        assert!(error(r#"{age: 1}"#).contains("in field `name` of `Owner`: the field is required"));
+        assert!(error(r#"{name: "x", color: "Purple"}"#)
+            .contains("`Purple` isn't a variant of `Color`"));
+        assert!(error(r#"{name: "x", age: -1}"#).contains("-1 doesn't fit in `u16`"));
+        assert!(error(r#"{name: "x", pet: {}}"#).contains("`pet` is set without `pet_type`"));
+        assert!(error(r#"{name: "x", cell: {pos: {x: 1}}}"#)
+            .contains("the struct `Vec2` is missing `y`"));
+        assert!(error("{name: \"x\"} {").contains("expected the end of the JSON object"));
+        assert!(error("{name: \"x\",\n  age: }").contains("1:11"));
+    }
+
+    #[test]
+    fn test_invalid_buffer() {
+        let schemas = [parse_schema(SCHEMA).unwrap()];
+        let converter = Converter::new(&schemas).unwrap();
+        let buf = converter.from_json(r#"{name: "a longer name"}"#).unwrap();
+        // the last two bytes only pad the string to the buffer's alignment
+        assert_eq!(&buf[buf.len() - 3..], b"\0\0\0");
+        for len in 0..buf.len() - 2 {
+            assert!(converter.to_json(&buf[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape(r#"a\"b\\c\/\t"#).unwrap(), "a\"b\\c/\t");
+        assert_eq!(unescape(r"\ud83d\ude00").unwrap(), "\u{1f600}");
+        assert!(unescape(r"\ud83d").is_err());
+        assert!(unescape(r"\ud83d\u0041").is_err());
+        assert!(unescape(r"\ud83d\ud83d").is_err());
+        assert!(unescape(r"\u12").is_err());
+    }
+}
@@ -1,5 +1,8 @@
 pub mod codegen;
 mod compile;
+pub mod diagnostic;
+mod include;
+pub mod json;
 
 mod macros;
 
@@ -7,6 +10,16 @@ mod macros;
 mod parser_macros;
 
 pub mod parser;
+pub mod reflection;
+pub mod resolver;
 pub mod types;
+pub mod validate;
 
-pub use crate::compile::{compile_fbs, compile_fbs_generic};
+pub use crate::{
+    codegen::Options,
+    compile::{
+        compile_bfbs_generic, compile_fbs, compile_fbs_generic,
+        compile_fbs_generic_with_include_dirs, compile_fbs_generic_with_options,
+        compile_fbs_with_include_dirs, compile_fbs_with_options,
+    },
+};
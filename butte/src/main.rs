@@ -1,6 +1,6 @@
 /// Compile flatbuffers files from the command line.
 use anyhow;
-use butte::compile_fbs_generic;
+use butte::{compile_bfbs_generic, compile_fbs_generic_with_options, Options};
 
 use structopt::StructOpt;
 
@@ -21,12 +21,48 @@ struct Opt {
     /// Format generated code using `rustfmt`.
     #[structopt(short, long)]
     ugly: bool,
+
+    /// Directory to search for included files, after the directory of the input file. May be
+    /// given more than once.
+    #[structopt(
+        short = "I",
+        long = "include-dir",
+        parse(from_os_str),
+        number_of_values = 1
+    )]
+    include_dirs: Vec<std::path::PathBuf>,
+
+    /// Generate owned types for tables and unions, which can be converted to and from buffers.
+    #[structopt(long)]
+    object_api: bool,
+
+    /// Implement serde's `Serialize` for the generated types, and `Deserialize` for those that
+    /// own their values.
+    #[structopt(long)]
+    serde: bool,
+
+    /// Write the binary schema (a `.bfbs` file, as `flatc --binary --schema` does) instead of
+    /// Rust code.
+    #[structopt(long)]
+    binary_schema: bool,
 }
 
-fn main() -> anyhow::Result<()> {
-    let opt = Opt::from_args();
+fn main() {
+    // errors describe themselves in full, so don't let `main` prefix them with `Error: `
+    if let Err(error) = run(Opt::from_args()) {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
+
+fn run(opt: Opt) -> anyhow::Result<()> {
+    let mut include_dirs = opt.include_dirs;
 
     let input: Box<dyn std::io::Read> = if let Some(input) = opt.input {
+        // files included by the input are relative to it
+        if let Some(parent) = input.parent() {
+            include_dirs.insert(0, parent.to_path_buf());
+        }
         Box::new(std::fs::File::open(input)?)
     } else {
         Box::new(std::io::stdin())
@@ -38,5 +74,13 @@ fn main() -> anyhow::Result<()> {
         Box::new(std::io::stdout())
     };
 
-    compile_fbs_generic(opt.ugly, input, output)
+    if opt.binary_schema {
+        return compile_bfbs_generic(&include_dirs, input, output);
+    }
+
+    let options = Options {
+        object_api: opt.object_api,
+        serde: opt.serde,
+    };
+    compile_fbs_generic_with_options(opt.ugly, &include_dirs, &options, input, output)
 }
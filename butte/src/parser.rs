@@ -8,20 +8,157 @@ use crate::{
 };
 
 use hexf_parse::parse_hexf64;
+use itertools::Itertools;
 
 use nom::{
-    self, branch::alt, bytes::complete::*, character::complete::*, combinator::*, multi::*,
-    sequence::*, IResult,
+    self,
+    branch::alt,
+    bytes::complete::*,
+    character::complete::*,
+    combinator::*,
+    error::{context, ErrorKind, ParseError},
+    multi::*,
+    sequence::*,
+    InputLength,
 };
 
-use std::{path::Path, str::FromStr};
+use std::{cmp::Ordering, fmt, path::Path, str::FromStr};
+
+/// Something a parser expected to find where it failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expected {
+    Char(char),
+
+    /// A description of a construct, given using `nom::error::context`.
+    Context(&'static str),
+}
+
+/// The reason parsing failed.
+///
+/// When alternatives fail, the error of the alternative that got furthest into the input is kept,
+/// since that one is usually closest to the actual mistake.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error<I> {
+    /// The remaining input at the point of failure.
+    pub input: I,
+
+    /// The kind of parser that failed.
+    pub kind: ErrorKind,
+
+    /// Everything that would have allowed parsing to continue from `input`.
+    pub expected: Vec<Expected>,
+}
+
+impl<I: InputLength> ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind,
+            expected: vec![],
+        }
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Self {
+            input,
+            kind: ErrorKind::Char,
+            expected: vec![Expected::Char(c)],
+        }
+    }
+
+    fn or(self, mut other: Self) -> Self {
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => {
+                let mut expected = self.expected;
+                expected.retain(|e| !other.expected.contains(e));
+                expected.append(&mut other.expected);
+                Self { expected, ..other }
+            }
+        }
+    }
+
+    fn add_context(input: I, ctx: &'static str, other: Self) -> Self {
+        // Only describe the construct if nothing of it could be parsed, otherwise the error from
+        // inside of it is more precise.
+        if input.input_len() == other.input.input_len() {
+            Self {
+                expected: vec![Expected::Context(ctx)],
+                ..other
+            }
+        } else {
+            other
+        }
+    }
+}
+
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "`{}`", c),
+            Expected::Context(ctx) => f.write_str(ctx),
+        }
+    }
+}
+
+impl<'a> Error<&'a str> {
+    /// The token found where parsing failed, or an empty string at the end of the input.
+    pub fn found(&self) -> &'a str {
+        let word = self
+            .input
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(self.input.len());
+        match self.input.chars().next() {
+            Some(c) if word == 0 => &self.input[..c.len_utf8()],
+            _ => &self.input[..word],
+        }
+    }
+}
+
+impl fmt::Display for Error<&str> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = match self.found() {
+            "" => "end of file".to_string(),
+            token => format!("`{}`", token),
+        };
+        match self.expected.split_last() {
+            None => write!(f, "unexpected {}", found),
+            Some((last, [])) => write!(f, "expected {}, found {}", last, found),
+            Some((last, rest)) => write!(
+                f,
+                "expected one of {} or {}, found {}",
+                rest.iter().join(", "),
+                last,
+                found
+            ),
+        }
+    }
+}
+
+/// Match a regular expression at the start of the input, consuming only the part that matched.
+///
+/// `nom::re_match!` consumes the whole input when the expression matches anywhere in it, which
+/// swallows whatever follows a keyword, and always fails with nom's default error type.
+macro_rules! re_match {
+    ($input:expr, $re:expr) => {
+        nom::re_find!($input, concat!("^(?:", $re, ")"))
+            .map_err(|e| e.map(|(input, _)| Error::from_error_kind(input, ErrorKind::RegexpMatch)))
+    };
+}
 
 #[cfg(test)]
 macro_rules! assert_failed_parse {
     ($left:expr, $rest:expr, $error_kind:ident) => {
         assert_eq!(
-            $left,
-            Err(nom::Err::Error(($rest, nom::error::ErrorKind::$error_kind)))
+            $left.map_err(|e| e.map(|e| (e.input, e.kind))),
+            Err(nom::Err::Error(($rest, ErrorKind::$error_kind)))
         )
     };
 }
@@ -117,12 +254,15 @@ pub fn right_square_bracket(input: &str) -> IResult<&str, char> {
 }
 
 pub fn ident(input: &str) -> IResult<&str, Ident> {
-    map(
-        recognize(preceded(
-            take_while_m_n(1, 1, |c: char| c.is_alphabetic() || c == '_'),
-            take_while(|c: char| c.is_alphanumeric() || c == '_'),
-        )),
-        Ident::from,
+    context(
+        "an identifier",
+        map(
+            recognize(preceded(
+                take_while_m_n(1, 1, |c: char| c.is_alphabetic() || c == '_'),
+                take_while(|c: char| c.is_alphanumeric() || c == '_'),
+            )),
+            Ident::from,
+        ),
     )(input)
 }
 
@@ -168,6 +308,9 @@ mod ident_tests {
     }
 }
 
+/// Parse a string constant, leaving any escape sequences in it as they are.
+///
+/// The escape sequences are the ones JSON allows, so that JSON strings can be parsed too.
 pub fn string_constant(input: &str) -> IResult<&str, &str> {
     map(
         delimited(
@@ -175,7 +318,7 @@ pub fn string_constant(input: &str) -> IResult<&str, &str> {
             opt(escaped(
                 none_of("\\\""),
                 '\\',
-                alt((backslash, double_quote)),
+                alt((backslash, double_quote, one_of("/bfnrtu"))),
             )),
             double_quote,
         ),
@@ -193,6 +336,12 @@ mod string_constant_tests {
         assert_successful_parse!(result, "a b c D \\\"z1");
     }
 
+    #[test]
+    fn test_string_constant_json_escapes() {
+        let result = string_constant(r#""a\n\t\/\u00e9b""#);
+        assert_successful_parse!(result, r"a\n\t\/\u00e9b");
+    }
+
     #[test]
     fn test_empty_string_constant() {
         let result = string_constant("\"\"");
@@ -201,19 +350,22 @@ mod string_constant_tests {
 }
 
 pub fn element(input: &str) -> IResult<&str, Element> {
-    alt((
-        map(namespace_decl, Element::from),
-        map(table_decl, Element::from),
-        map(struct_decl, Element::from),
-        map(enum_decl, Element::from),
-        map(union_decl, Element::from),
-        map(root_decl, Element::from),
-        map(file_extension_decl, Element::from),
-        map(file_identifier_decl, Element::from),
-        map(attribute_decl, Element::from),
-        map(rpc_decl, Element::from),
-        map(object, Element::from),
-    ))(input)
+    context(
+        "a declaration",
+        alt((
+            map(namespace_decl, Element::from),
+            map(table_decl, Element::from),
+            map(struct_decl, Element::from),
+            map(enum_decl, Element::from),
+            map(union_decl, Element::from),
+            map(root_decl, Element::from),
+            map(file_extension_decl, Element::from),
+            map(file_identifier_decl, Element::from),
+            map(attribute_decl, Element::from),
+            map(rpc_decl, Element::from),
+            map(object, Element::from),
+        )),
+    )(input)
 }
 
 #[cfg(test)]
@@ -272,6 +424,82 @@ pub fn schema_decl(input: &str) -> IResult<&str, Schema> {
     )(input)
 }
 
+/// Parse a complete flatbuffer schema, failing unless all of `input` is a valid schema.
+pub fn parse_schema(input: &str) -> std::result::Result<Schema<'_>, Error<&str>> {
+    fn into_error(e: nom::Err<Error<&str>>) -> Error<&str> {
+        match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e,
+            nom::Err::Incomplete(_) => Error::from_error_kind("", ErrorKind::Complete),
+        }
+    }
+
+    let (rest, schema) = schema_decl(input).map_err(into_error)?;
+    if rest.is_empty() {
+        return Ok(schema);
+    }
+    // `schema_decl` stops at the first element it can't parse, so parse that element on its own to
+    // find out what's wrong with it.
+    match preceded(comment_or_space0, element)(rest) {
+        Err(e) => Err(into_error(e)),
+        Ok(_) => Err(Error::from_error_kind(rest, ErrorKind::Eof)),
+    }
+}
+
+#[cfg(test)]
+mod parse_schema_tests {
+    use super::*;
+
+    fn failure(input: &str) -> (usize, Vec<Expected>) {
+        let error = parse_schema(input).unwrap_err();
+        (input.len() - error.input.len(), error.expected)
+    }
+
+    #[test]
+    fn test_complete_schema() {
+        let input = "include \"a\";\n// comment\ntable A { x: int; }\n// trailing comment\n";
+        assert!(parse_schema(input).is_ok());
+    }
+
+    #[test]
+    fn test_missing_semicolon() {
+        let input = "table A { x: int; }\ntable B {\n  x: int\n}\ntable C { x: int; }";
+        assert_eq!(
+            failure(input),
+            (input.find("\n}").unwrap() + 1, vec![Expected::Char(';')])
+        );
+    }
+
+    #[test]
+    fn test_missing_type() {
+        let input = "table A { x: ; }";
+        assert_eq!(failure(input), (13, vec![Expected::Context("a type")]));
+    }
+
+    #[test]
+    fn test_unknown_declaration() {
+        let input = "table A { x: int; }\n\nstruc B { x: int; }";
+        assert_eq!(
+            failure(input),
+            (21, vec![Expected::Context("a declaration")])
+        );
+    }
+
+    #[test]
+    fn test_error_message() {
+        let message = |input| parse_schema(input).unwrap_err().to_string();
+        assert_eq!(message("table A {\n  x: int\n}"), "expected `;`, found `}`");
+        assert_eq!(
+            message("table A { x: int; } garbage"),
+            "expected a declaration, found `garbage`"
+        );
+        assert_eq!(
+            message("table A { x: int"),
+            "expected `;`, found end of file"
+        );
+        assert_eq!(message("table A { x: ; }"), "expected a type, found `;`");
+    }
+}
+
 #[cfg(test)]
 mod schema_tests {
     use super::*;
@@ -628,6 +856,7 @@ mod attribute_tests {
     }
 }
 
+/// Parse the values of an enum or union, which may be followed by a comma.
 pub fn enum_body(input: &str) -> IResult<&str, Vec<EnumVal>> {
     delimited(
         delimited(comment_or_space0, left_brace, comment_or_space0),
@@ -635,7 +864,11 @@ pub fn enum_body(input: &str) -> IResult<&str, Vec<EnumVal>> {
             delimited(comment_or_space0, comma, comment_or_space0),
             enumval_decl,
         ),
-        preceded(comment_or_space0, right_brace),
+        tuple((
+            comment_or_space0,
+            opt(terminated(comma, comment_or_space0)),
+            right_brace,
+        )),
     )(input)
 }
 
@@ -646,7 +879,10 @@ pub fn enum_decl(input: &str) -> IResult<&str, Enum> {
             tag("enum"),
             delimited(comment_or_space1, ident, comment_or_space0),
         ),
-        preceded(colon, preceded(comment_or_space0, type_)),
+        preceded(
+            colon,
+            delimited(comment_or_space0, type_, comment_or_space0),
+        ),
         metadata,
         enum_body,
     ));
@@ -679,6 +915,19 @@ mod enum_tests {
         assert_successful_parse!(result, expected);
     }
 
+    #[test]
+    fn test_enum_with_metadata() {
+        let input = "enum Flags : ubyte (bit_flags) { A, B }";
+        let result = enum_decl(input);
+        let expected = Enum::builder()
+            .id(Ident::from("Flags"))
+            .base_type(Type::UByte)
+            .values(vec![e_item!(A), e_item!(B)])
+            .metadata(Some(Metadata::from(vec![meta!(bit_flags)])))
+            .build();
+        assert_successful_parse!(result, expected);
+    }
+
     #[test]
     fn test_simple_enum() {
         let input = "enum MyEnum : int32 { foo = 1, bar }";
@@ -686,6 +935,16 @@ mod enum_tests {
         let expected = enum_!(MyEnum, Int32, [e_item!(foo = 1), e_item!(bar)]);
         assert_successful_parse!(result, expected);
     }
+
+    #[test]
+    fn test_enum_trailing_comma() {
+        let input = "enum MyEnum : int32 {\n  foo = 1,\n  bar, // last\n}";
+        let result = enum_decl(input);
+        let expected = enum_!(MyEnum, Int32, [e_item!(foo = 1), e_item!(bar)]);
+        assert_successful_parse!(result, expected);
+
+        assert!(enum_decl("enum MyEnum : int32 { foo,, }").is_err());
+    }
 }
 
 pub fn union_decl(input: &str) -> IResult<&str, Union> {
@@ -771,21 +1030,21 @@ pub fn field_decl(input: &str) -> IResult<&str, Field> {
             tuple((
                 doc_comment,
                 terminated(ident, tuple((comment_or_space0, colon, comment_or_space0))),
-                type_,
+                terminated(type_, comment_or_space0),
                 opt(preceded(
                     tuple((comment_or_space0, equals, comment_or_space0)),
-                    terminated(scalar, comment_or_space0),
+                    terminated(default_value, comment_or_space0),
                 )),
                 metadata,
             )),
             tuple((comment_or_space0, semicolon)),
         ),
-        |(comment, name, ty, scalar, metadata)| {
+        |(comment, name, ty, default, metadata)| {
             Field::builder()
                 .doc(comment)
                 .id(name)
                 .ty(ty)
-                .scalar(scalar)
+                .default(default)
                 .metadata(metadata)
                 .build()
         },
@@ -814,6 +1073,18 @@ foo // bar
         assert_successful_parse!(result, expected);
     }
 
+    #[test]
+    fn test_field_decl_doc_comment() {
+        let input = "table A {\n  /// The first field\n  x: int;\n  y: int;  // not a doc comment\n  /// The second field\n  z: int;\n}";
+        let (_, table) = table_decl(input).unwrap();
+        assert_eq!(table.fields[0].doc, Comment::from(vec![" The first field"]));
+        assert_eq!(table.fields[1].doc, Comment::from(vec![]));
+        assert_eq!(
+            table.fields[2].doc,
+            Comment::from(vec![" The second field"])
+        );
+    }
+
     #[test]
     fn test_field_decl() {
         let input = "foo: float64 = 2.0;";
@@ -830,6 +1101,64 @@ foo // bar
         assert_successful_parse!(result, expected);
     }
 
+    #[test]
+    fn test_field_decl_null() {
+        let input = "foo: int = null;";
+        let result = field_decl(input);
+        let expected = Field::builder()
+            .id(Ident::from("foo"))
+            .ty(Type::Int)
+            .default(Some(DefaultValue::Null))
+            .build();
+        assert_successful_parse!(result, expected);
+    }
+
+    #[test]
+    fn test_field_decl_metadata() {
+        let input = "foo: string (required);";
+        let result = field_decl(input);
+        let expected = Field::builder()
+            .id(Ident::from("foo"))
+            .ty(Type::String)
+            .metadata(Some(Metadata::from(vec![meta!(required)])))
+            .build();
+        assert_successful_parse!(result, expected);
+    }
+
+    #[test]
+    fn test_field_decl_keyword_default() {
+        let input = "foo: bool = true;";
+        let result = field_decl(input);
+        let expected = field!(foo, Bool = true);
+        assert_successful_parse!(result, expected);
+
+        let input = "foo: double = -inf;";
+        let result = field_decl(input);
+        let expected = field!(foo, Double = std::f64::NEG_INFINITY);
+        assert_successful_parse!(result, expected);
+    }
+
+    #[test]
+    fn test_field_decl_enum_default() {
+        let input = "color: Color = Blue;";
+        let result = field_decl(input);
+        let expected = Field::builder()
+            .id(Ident::from("color"))
+            .ty(Type::Ident(DottedIdent::from(vec![Ident::from("Color")])))
+            .default(Some(DefaultValue::Ident(Ident::from("Blue"))))
+            .build();
+        assert_successful_parse!(result, expected);
+
+        let input = "color: Color = truest;";
+        let result = field_decl(input);
+        let expected = Field::builder()
+            .id(Ident::from("color"))
+            .ty(Type::Ident(DottedIdent::from(vec![Ident::from("Color")])))
+            .default(Some(DefaultValue::Ident(Ident::from("truest"))))
+            .build();
+        assert_successful_parse!(result, expected);
+    }
+
     #[test]
     fn test_field_decl_no_scalar() {
         let input = "foo:float64    //faz\n;";
@@ -1031,37 +1360,88 @@ SayHello // foo
 }
 
 pub fn type_(input: &str) -> IResult<&str, Type> {
-    alt((
+    context(
+        "a type",
         alt((
-            value(Type::Bool, tag("bool")),
-            value(Type::Byte, tag("byte")),
-            value(Type::UByte, tag("ubyte")),
-            value(Type::Short, tag("short")),
-            value(Type::UShort, tag("ushort")),
-            value(Type::Long, tag("long")),
-            value(Type::ULong, tag("ulong")),
-            value(Type::Double, tag("double")),
-            value(Type::Int8, tag("int8")),
-            value(Type::UInt8, tag("uint8")),
-            value(Type::Int16, tag("int16")),
-            value(Type::UInt16, tag("uint16")),
-            value(Type::Int32, tag("int32")),
-            value(Type::UInt32, tag("uint32")),
-            value(Type::Int64, tag("int64")),
-            value(Type::UInt64, tag("uint64")),
-            value(Type::Float32, tag("float32")),
-            value(Type::Float64, tag("float64")),
-            value(Type::Int, tag("int")),
-            value(Type::UInt, tag("uint")),
-            value(Type::Float, tag("float")),
+            alt((
+                value(Type::Bool, tag("bool")),
+                value(Type::Byte, tag("byte")),
+                value(Type::UByte, tag("ubyte")),
+                value(Type::Short, tag("short")),
+                value(Type::UShort, tag("ushort")),
+                value(Type::Long, tag("long")),
+                value(Type::ULong, tag("ulong")),
+                value(Type::Double, tag("double")),
+                value(Type::Int8, tag("int8")),
+                value(Type::UInt8, tag("uint8")),
+                value(Type::Int16, tag("int16")),
+                value(Type::UInt16, tag("uint16")),
+                value(Type::Int32, tag("int32")),
+                value(Type::UInt32, tag("uint32")),
+                value(Type::Int64, tag("int64")),
+                value(Type::UInt64, tag("uint64")),
+                value(Type::Float32, tag("float32")),
+                value(Type::Float64, tag("float64")),
+                value(Type::Int, tag("int")),
+                value(Type::UInt, tag("uint")),
+                value(Type::Float, tag("float")),
+            )),
+            value(Type::String, tag("string")),
+            map(
+                delimited(
+                    left_square_bracket,
+                    pair(
+                        type_,
+                        opt(preceded(
+                            tuple((comment_or_space0, colon, comment_or_space0)),
+                            verify(
+                                map_res(digit1, |length: &str| length.parse::<usize>()),
+                                |length| *length > 0,
+                            ),
+                        )),
+                    ),
+                    right_square_bracket,
+                ),
+                |(t, length)| match length {
+                    Some(length) => Type::FixedArray(Box::new(t), length),
+                    None => Type::from([t]),
+                },
+            ),
+            map(dotted_ident, Type::Ident),
         )),
-        value(Type::String, tag("string")),
-        map(
-            delimited(left_square_bracket, type_, right_square_bracket),
-            |t| Type::from([t]),
-        ),
-        map(dotted_ident, Type::Ident),
-    ))(input)
+    )(input)
+}
+
+#[cfg(test)]
+mod type_tests {
+    use super::*;
+
+    #[test]
+    fn test_array_type() {
+        let result = type_("[int]");
+        assert_successful_parse!(result, Type::Array(Box::new(Type::Int)));
+    }
+
+    #[test]
+    fn test_fixed_array_type() {
+        let result = type_("[float:16]");
+        assert_successful_parse!(result, Type::FixedArray(Box::new(Type::Float), 16));
+
+        let result = type_("[foo.Vec3 : 2]");
+        let expected = Type::FixedArray(
+            Box::new(Type::Ident(DottedIdent::from(vec![
+                Ident::from("foo"),
+                Ident::from("Vec3"),
+            ]))),
+            2,
+        );
+        assert_successful_parse!(result, expected);
+    }
+
+    #[test]
+    fn test_empty_fixed_array_type() {
+        assert!(type_("[int:0]").is_err());
+    }
 }
 
 /// Parse the individual items of an enum or union.
@@ -1175,7 +1555,19 @@ pub fn scalar(input: &str) -> IResult<&str, Scalar> {
     ))(input)
 }
 
-/// Parse JSON object-like data.
+/// Parse the default value of a field: a scalar, the name of an enum variant, or `null` for
+/// optional scalars.
+pub fn default_value(input: &str) -> IResult<&str, DefaultValue> {
+    alt((
+        value(DefaultValue::Null, |input: &str| {
+            re_match!(input, r"\bnull\b")
+        }),
+        map(scalar, DefaultValue::from),
+        map(ident, DefaultValue::from),
+    ))(input)
+}
+
+/// Parse JSON object-like data. Keys may be identifiers or, as in JSON, strings.
 pub fn object(input: &str) -> IResult<&str, Object> {
     map(
         delimited(
@@ -1183,7 +1575,7 @@ pub fn object(input: &str) -> IResult<&str, Object> {
             separated_list(
                 delimited(comment_or_space0, comma, comment_or_space0),
                 separated_pair(
-                    ident,
+                    alt((ident, map(string_constant, Ident::from))),
                     delimited(comment_or_space0, colon, comment_or_space0),
                     value_,
                 ),
@@ -1208,6 +1600,17 @@ mod object_tests {
         assert_successful_parse!(result, expected);
     }
 
+    #[test]
+    fn test_object_with_string_keys() {
+        let input = r#"{"a": 1, b: "c"}"#;
+        let result = object(input);
+        let expected = obj!({
+            a => 1,
+            b => "c"
+        });
+        assert_successful_parse!(result, expected);
+    }
+
     #[test]
     fn test_empty_object() {
         let input = "{}";
@@ -1537,11 +1940,11 @@ mod hex_integer_constant_tests {
 }
 
 pub fn true_(input: &str) -> IResult<&str, BooleanConstant> {
-    value(true, |input: &str| nom::re_match!(input, r"\btrue\b"))(input)
+    value(true, |input: &str| re_match!(input, r"\btrue\b"))(input)
 }
 
 pub fn false_(input: &str) -> IResult<&str, BooleanConstant> {
-    value(false, |input: &str| nom::re_match!(input, r"\bfalse\b"))(input)
+    value(false, |input: &str| re_match!(input, r"\bfalse\b"))(input)
 }
 
 #[cfg(test)]
@@ -1734,9 +2137,7 @@ pub fn hex_float_constant(input: &str) -> IResult<&str, FloatingConstant> {
 /// Parse `nan`
 pub fn nan(input: &str) -> IResult<&str, FloatingConstant> {
     map(
-        terminated(opt(plus_or_minus), |input| {
-            nom::re_match!(input, r"\bnan\b")
-        }),
+        terminated(opt(plus_or_minus), |input| re_match!(input, r"\bnan\b")),
         |sign| {
             if let Some('-') = sign {
                 -std::f64::NAN
@@ -1783,7 +2184,7 @@ mod nan_tests {
 pub fn inf_or_infinity(input: &str) -> IResult<&str, FloatingConstant> {
     map(
         terminated(opt(plus_or_minus), |input| {
-            nom::re_match!(input, r"\binf(inity)?\b")
+            re_match!(input, r"\binf(inity)?\b")
         }),
         |sign| {
             if let Some('-') = sign {
@@ -2027,9 +2428,14 @@ pub fn raw_doc_comment(input: &str) -> IResult<&str, &str> {
     preceded(tag("///"), not_line_ending)(input)
 }
 
-/// Parse zero or more lines of documentation comments
+/// Parse zero or more lines of documentation comments, each of which may be indented, along with
+/// the indentation of the line following them.
 pub fn doc_comment_lines(input: &str) -> IResult<&str, Vec<&str>> {
-    many0(terminated(raw_doc_comment, line_ending))(input)
+    many0(delimited(
+        space0,
+        raw_doc_comment,
+        tuple((line_ending, space0)),
+    ))(input)
 }
 
 /// Wrap zero or more lines of documentation comments in an AST node.
@@ -2103,4 +2509,17 @@ mod comment_tests {
         let result = doc_comment(input);
         assert_successful_parse!(result, "a", expected);
     }
+
+    #[test]
+    fn test_doc_comment_indented() {
+        let input = "  /// The x\n\t/// coordinate\n  x";
+        let expected = Comment::from(vec![" The x", " coordinate"]);
+        let result = doc_comment(input);
+        assert_successful_parse!(result, "x", expected);
+
+        // indentation without a comment is left alone
+        let input = "  x";
+        let result = doc_comment(input);
+        assert_successful_parse!(result, "  x", Comment::from(vec![]));
+    }
 }
@@ -0,0 +1,852 @@
+//! Lower schemas to binary schemas: the tables of `reflection.fbs`, which `flatc` writes to
+//! `.bfbs` files for tools that work with any schema.
+//!
+//! Every table and struct is an object and every enum and union is an enum, each named by its
+//! fully-qualified name, such as `zoo.Pen`. Objects and enums are sorted by name, and a type
+//! refers to a user-defined type by its position among them. A union field is two fields, as it's
+//! stored: `<field>_type`, holding the type of the value, followed by the value itself.
+//!
+//! Like `flatc`, only the attributes a schema declares are kept, since the meaning of the
+//! built-in ones is already part of the fields they're on.
+use std::iter::once;
+
+use anyhow::{anyhow, bail, Result};
+use flatbuffers::{
+    field_index_to_field_offset,
+    reflection::{
+        self, AdvancedFeatures, BaseType, EnumT, EnumValT, FieldT, KeyValueT, ObjectT, RPCCallT,
+        SchemaT, ServiceT, TypeT,
+    },
+    FlatBufferBuilder, VOffsetT,
+};
+use itertools::Itertools;
+
+use crate::{
+    codegen::{enum_values, Context, InContext},
+    resolver::{Declaration, Symbol},
+    types::*,
+};
+
+/// The attributes `flatc` gives a meaning to, which are left out of binary schemas.
+const BUILT_IN_ATTRIBUTES: &[&str] = &[
+    "bit_flags",
+    "deprecated",
+    "flexbuffer",
+    "force_align",
+    "hash",
+    "id",
+    "idempotent",
+    "key",
+    "nested_flatbuffer",
+    "original_order",
+    "private",
+    "required",
+    "shared",
+    "streaming",
+];
+
+/// Lower `schemas`, which are in the order they include one another, to a binary schema. The root
+/// table, file identifier and file extension are those of the last schema.
+pub fn lower<'a>(schemas: &'a [Schema<'a>]) -> Result<SchemaT> {
+    let context = Context::new(schemas);
+    let errors = context.validate();
+    if !errors.is_empty() {
+        bail!(errors.iter().join("\n"));
+    }
+    Lowering::new(&context, schemas).schema()
+}
+
+/// Lower `schemas` to a binary schema, serialized as the contents of a `.bfbs` file.
+pub fn binary_schema<'a>(schemas: &'a [Schema<'a>]) -> Result<Vec<u8>> {
+    let schema = lower(schemas)?;
+    let mut fbb = FlatBufferBuilder::new();
+    let root = schema.pack(&mut fbb);
+    reflection::finish_schema_buffer(&mut fbb, root);
+    Ok(fbb.finished_data().to_vec())
+}
+
+/// The fully-qualified name of `id`, declared in `namespace`.
+fn qualified_name(namespace: &[Ident], id: &Ident) -> String {
+    namespace
+        .iter()
+        .chain(once(id))
+        .map(|part| part.raw)
+        .join(".")
+}
+
+fn symbol_name(symbol: &Symbol) -> String {
+    qualified_name(symbol.namespace, &symbol.id)
+}
+
+/// The type of a scalar, or `None` if `ty` isn't one.
+fn scalar_type(ty: &Type) -> Option<BaseType> {
+    Some(match ty {
+        Type::Bool => BaseType::Bool,
+        Type::Byte | Type::Int8 => BaseType::Byte,
+        Type::UByte | Type::UInt8 => BaseType::UByte,
+        Type::Short | Type::Int16 => BaseType::Short,
+        Type::UShort | Type::UInt16 => BaseType::UShort,
+        Type::Int | Type::Int32 => BaseType::Int,
+        Type::UInt | Type::UInt32 => BaseType::UInt,
+        Type::Long | Type::Int64 => BaseType::Long,
+        Type::ULong | Type::UInt64 => BaseType::ULong,
+        Type::Float | Type::Float32 => BaseType::Float,
+        Type::Double | Type::Float64 => BaseType::Double,
+        _ => return None,
+    })
+}
+
+/// The size of a value of `base_type` stored inline, which for everything that isn't a scalar is
+/// the size of an offset, as `flatc` has it.
+fn base_size(base_type: BaseType) -> u32 {
+    match base_type {
+        BaseType::None | BaseType::UType | BaseType::Bool | BaseType::Byte | BaseType::UByte => 1,
+        BaseType::Short | BaseType::UShort => 2,
+        BaseType::Long | BaseType::ULong | BaseType::Double | BaseType::Vector64 => 8,
+        _ => 4,
+    }
+}
+
+/// The attributes of `metadata` the schema declared, sorted by name. An attribute without a value
+/// has the value `0`, as in `flatc`.
+fn attributes(metadata: &Option<Metadata>) -> Option<Vec<KeyValueT>> {
+    let attributes = metadata
+        .iter()
+        .flat_map(|metadata| &metadata.values)
+        .filter(|(key, _)| !BUILT_IN_ATTRIBUTES.contains(&key.raw))
+        .map(|(key, value)| KeyValueT {
+            key: key.raw.to_string(),
+            value: Some(match value {
+                Some(Single::String(value)) => value.to_string(),
+                Some(Single::Scalar(Scalar::Integer(value))) => value.to_string(),
+                Some(Single::Scalar(Scalar::Float(value))) => value.to_string(),
+                Some(Single::Scalar(Scalar::Boolean(value))) => value.to_string(),
+                None => "0".to_string(),
+            }),
+        })
+        .sorted_by(|a, b| a.key.cmp(&b.key))
+        .collect::<Vec<_>>();
+    if attributes.is_empty() {
+        None
+    } else {
+        Some(attributes)
+    }
+}
+
+fn documentation(doc: &Comment) -> Option<Vec<String>> {
+    if doc.lines.is_empty() {
+        None
+    } else {
+        Some(doc.lines.iter().map(|line| line.to_string()).collect())
+    }
+}
+
+/// The values of the variants of an enum, which for bit flags are the flags rather than the
+/// positions of their bits.
+fn variant_values<'a>(e: &'a Enum<'a>) -> impl Iterator<Item = (Ident<'a>, i64)> + 'a {
+    let bit_flags = e.is_bit_flags();
+    enum_values(&e.values, 0).map(move |(id, value)| {
+        if bit_flags {
+            debug_assert!(
+                (0..64).contains(&value),
+                "validation rejects bits that don't fit in the underlying type"
+            );
+            (id, (1u64 << value) as i64)
+        } else {
+            (id, value)
+        }
+    })
+}
+
+/// The declarations of a set of schemas, ready to be lowered.
+struct Lowering<'c, 'a> {
+    context: &'c Context<'a>,
+    schemas: &'a [Schema<'a>],
+
+    /// The tables and structs, sorted by name.
+    objects: Vec<Symbol<'a>>,
+
+    /// The enums and unions, sorted by name.
+    enums: Vec<Symbol<'a>>,
+}
+
+impl<'c, 'a> Lowering<'c, 'a> {
+    fn new(context: &'c Context<'a>, schemas: &'a [Schema<'a>]) -> Self {
+        let (mut objects, mut enums): (Vec<_>, Vec<_>) = schemas
+            .iter()
+            .flat_map(Schema::namespaced_elements)
+            .filter_map(|(namespace, element)| {
+                let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+                let (id, declaration) = match element {
+                    Element::Table(t) => (t.id, Declaration::Table(t)),
+                    Element::Struct(s) => (s.id, Declaration::Struct(s)),
+                    Element::Enum(e) => (e.id, Declaration::Enum(e)),
+                    Element::Union(u) => (u.id, Declaration::Union(u)),
+                    _ => return None,
+                };
+                Some(Symbol {
+                    namespace,
+                    id,
+                    declaration,
+                })
+            })
+            .partition(|symbol| match symbol.declaration {
+                Declaration::Table(_) | Declaration::Struct(_) => true,
+                Declaration::Enum(_) | Declaration::Union(_) => false,
+            });
+        objects.sort_by_key(symbol_name);
+        enums.sort_by_key(symbol_name);
+        Self {
+            context,
+            schemas,
+            objects,
+            enums,
+        }
+    }
+
+    /// The position of `symbol` among the objects or the enums.
+    fn index(&self, symbol: &Symbol) -> i32 {
+        let symbols = match symbol.declaration {
+            Declaration::Table(_) | Declaration::Struct(_) => &self.objects,
+            Declaration::Enum(_) | Declaration::Union(_) => &self.enums,
+        };
+        symbols
+            .binary_search_by_key(&symbol_name(symbol), symbol_name)
+            .map_or(-1, |index| index as i32)
+    }
+
+    fn resolve<T>(&self, scope: &InContext<'_, 'a, T>, ident: &DottedIdent) -> Result<Symbol<'a>> {
+        scope.resolve(ident).ok_or_else(|| {
+            anyhow!(
+                "the type `{}` isn't declared",
+                ident.parts.iter().map(|part| part.raw).join(".")
+            )
+        })
+    }
+
+    /// The type of a single value of type `ty`, along with the index of the object or enum it
+    /// refers to, or -1.
+    fn element_type<T>(&self, scope: &InContext<'_, 'a, T>, ty: &Type) -> Result<(BaseType, i32)> {
+        Ok(match ty {
+            Type::String => (BaseType::String, -1),
+            Type::Ident(ident) => {
+                let symbol = self.resolve(scope, ident)?;
+                let base_type = match symbol.declaration {
+                    Declaration::Table(_) | Declaration::Struct(_) => BaseType::Obj,
+                    Declaration::Union(_) => BaseType::Union,
+                    Declaration::Enum(e) => scalar_type(&e.base_type)
+                        .ok_or_else(|| anyhow!("the enum `{}` isn't an integer", e.id.raw))?,
+                };
+                (base_type, self.index(&symbol))
+            }
+            Type::Array(_) | Type::FixedArray(..) => bail!("an array can't hold arrays"),
+            scalar => (scalar_type(scalar).unwrap_or(BaseType::None), -1),
+        })
+    }
+
+    /// The size of an element of a vector or array. Only structs are stored inline with the size
+    /// of the struct, everything else takes the size of its base type.
+    fn element_size(&self, element_type: BaseType, index: i32) -> u32 {
+        match self.objects.get(index as usize) {
+            Some(Symbol {
+                namespace,
+                declaration: Declaration::Struct(s),
+                ..
+            }) if element_type == BaseType::Obj => {
+                self.context.with(namespace, *s).layout().1 as u32
+            }
+            _ => base_size(element_type),
+        }
+    }
+
+    fn lower_type<T>(&self, scope: &InContext<'_, 'a, T>, ty: &Type) -> Result<TypeT> {
+        Ok(match ty {
+            Type::Array(element) => {
+                let (element_type, index) = self.element_type(scope, element)?;
+                TypeT {
+                    base_type: BaseType::Vector,
+                    element: element_type,
+                    index,
+                    fixed_length: 0,
+                    base_size: base_size(BaseType::Vector),
+                    element_size: self.element_size(element_type, index),
+                }
+            }
+            Type::FixedArray(element, length) => {
+                let (element_type, index) = self.element_type(scope, element)?;
+                TypeT {
+                    base_type: BaseType::Array,
+                    element: element_type,
+                    index,
+                    fixed_length: *length as u16,
+                    base_size: base_size(BaseType::Array),
+                    element_size: self.element_size(element_type, index),
+                }
+            }
+            _ => {
+                let (base_type, index) = self.element_type(scope, ty)?;
+                TypeT {
+                    base_type,
+                    element: BaseType::None,
+                    index,
+                    fixed_length: 0,
+                    base_size: base_size(base_type),
+                    element_size: 0,
+                }
+            }
+        })
+    }
+
+    /// The default of a field as an integer and as a float, only one of which is used.
+    fn default_value<T>(
+        &self,
+        scope: &InContext<'_, 'a, T>,
+        field: &Field,
+        ty: &TypeT,
+    ) -> Result<(i64, f64)> {
+        let is_float = ty.base_type == BaseType::Float || ty.base_type == BaseType::Double;
+        Ok(match &field.default {
+            None | Some(DefaultValue::Null) => (0, 0.0),
+            Some(DefaultValue::Scalar(Scalar::Integer(value))) if is_float => (0, *value as f64),
+            Some(DefaultValue::Scalar(Scalar::Integer(value))) => (*value, 0.0),
+            Some(DefaultValue::Scalar(Scalar::Float(value))) => (0, *value),
+            Some(DefaultValue::Scalar(Scalar::Boolean(value))) => (*value as i64, 0.0),
+            Some(DefaultValue::Ident(name)) => {
+                let e = match &field.ty {
+                    Type::Ident(ident) => match self.resolve(scope, ident)?.declaration {
+                        Declaration::Enum(e) => e,
+                        _ => bail!("the default of `{}` isn't a variant", field.id.raw),
+                    },
+                    _ => bail!("the default of `{}` isn't a variant", field.id.raw),
+                };
+                let value = variant_values(e)
+                    .find(|(id, _)| id == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| anyhow!("`{}` isn't a variant of `{}`", name.raw, e.id.raw))?;
+                (value, 0.0)
+            }
+        })
+    }
+
+    fn field<T>(
+        &self,
+        scope: &InContext<'_, 'a, T>,
+        field: &Field,
+        id: VOffsetT,
+        offset: usize,
+    ) -> Result<FieldT> {
+        let ty = self.lower_type(scope, &field.ty)?;
+        let (default_integer, default_real) = self.default_value(scope, field, &ty)?;
+        Ok(FieldT {
+            name: field.id.raw.to_string(),
+            r#type: Box::new(ty),
+            id: id as u16,
+            offset: offset as u16,
+            default_integer,
+            default_real,
+            deprecated: field.is_deprecated(),
+            required: field.is_required(),
+            key: field.has_attribute("key"),
+            attributes: attributes(&field.metadata),
+            documentation: documentation(&field.doc),
+            optional: field.default == Some(DefaultValue::Null),
+            ..FieldT::default()
+        })
+    }
+
+    fn table(&self, scope: &InContext<'_, 'a, Table<'a>>) -> Result<ObjectT> {
+        let mut fields = vec![];
+        for (field, slot) in scope.item.fields.iter().zip(scope.slots()) {
+            if let Some((symbol, _)) = scope.union_of(&field.ty) {
+                fields.push(FieldT {
+                    name: format!("{}_type", field.id.raw),
+                    r#type: Box::new(TypeT {
+                        base_type: BaseType::UType,
+                        index: self.index(&symbol),
+                        base_size: base_size(BaseType::UType),
+                        ..TypeT::default()
+                    }),
+                    id: slot as u16 - 1,
+                    offset: field_index_to_field_offset(slot - 1) as u16,
+                    deprecated: field.is_deprecated(),
+                    ..FieldT::default()
+                });
+            }
+            let offset = field_index_to_field_offset(slot) as usize;
+            fields.push(self.field(scope, field, slot, offset)?);
+        }
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(ObjectT {
+            name: qualified_name(scope.namespace, &scope.item.id),
+            fields,
+            is_struct: false,
+            minalign: 1,
+            bytesize: 0,
+            attributes: attributes(&scope.item.metadata),
+            documentation: documentation(&scope.item.doc),
+            declaration_file: None,
+        })
+    }
+
+    fn struct_(&self, scope: &InContext<'_, 'a, Struct<'a>>) -> Result<ObjectT> {
+        let (offsets, size, align) = scope.layout();
+        let mut fields = vec![];
+        for (id, (field, offset)) in scope.item.fields.iter().zip(&offsets).enumerate() {
+            let mut lowered = self.field(scope, field, id as VOffsetT, *offset)?;
+            // the padding fills the space up to the next field, or the end of the struct
+            let field_size = scope.inline_layout(&field.ty).map_or(0, |(size, _)| size);
+            let end = offsets.get(id + 1).copied().unwrap_or(size);
+            lowered.padding = (end - offset - field_size) as u16;
+            fields.push(lowered);
+        }
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(ObjectT {
+            name: qualified_name(scope.namespace, &scope.item.id),
+            fields,
+            is_struct: true,
+            minalign: align as i32,
+            bytesize: size as i32,
+            attributes: attributes(&scope.item.metadata),
+            documentation: documentation(&scope.item.doc),
+            declaration_file: None,
+        })
+    }
+
+    fn enum_(&self, symbol: &Symbol<'a>, e: &'a Enum<'a>) -> Result<EnumT> {
+        let base_type = scalar_type(&e.base_type)
+            .ok_or_else(|| anyhow!("the enum `{}` isn't an integer", e.id.raw))?;
+        Ok(EnumT {
+            name: symbol_name(symbol),
+            values: variant_values(e)
+                .map(|(id, value)| EnumValT {
+                    name: id.raw.to_string(),
+                    value,
+                    ..EnumValT::default()
+                })
+                .collect(),
+            is_union: false,
+            underlying_type: Box::new(TypeT {
+                base_type,
+                index: self.index(symbol),
+                base_size: base_size(base_type),
+                ..TypeT::default()
+            }),
+            attributes: attributes(&e.metadata),
+            documentation: documentation(&e.doc),
+            declaration_file: None,
+        })
+    }
+
+    fn union(&self, symbol: &Symbol<'a>, union: &'a Union<'a>) -> Result<EnumT> {
+        let scope = self.context.with(symbol.namespace, union);
+        let none = EnumValT {
+            name: "NONE".to_string(),
+            value: 0,
+            union_type: Some(Box::new(TypeT {
+                base_type: BaseType::None,
+                base_size: base_size(BaseType::None),
+                ..TypeT::default()
+            })),
+            ..EnumValT::default()
+        };
+        let variants = enum_values(&union.values, 1).map(|(id, value)| {
+            let table = self.resolve(&scope, &vec![id].into())?;
+            Ok(EnumValT {
+                name: id.raw.to_string(),
+                value,
+                union_type: Some(Box::new(TypeT {
+                    base_type: BaseType::Obj,
+                    index: self.index(&table),
+                    ..TypeT::default()
+                })),
+                ..EnumValT::default()
+            })
+        });
+        Ok(EnumT {
+            name: symbol_name(symbol),
+            values: once(Ok(none)).chain(variants).collect::<Result<_>>()?,
+            is_union: true,
+            underlying_type: Box::new(TypeT {
+                base_type: BaseType::UType,
+                index: self.index(symbol),
+                base_size: base_size(BaseType::UType),
+                ..TypeT::default()
+            }),
+            attributes: attributes(&union.metadata),
+            documentation: documentation(&union.doc),
+            declaration_file: None,
+        })
+    }
+
+    fn service(&self, scope: &InContext<'_, 'a, Rpc<'a>>, objects: &[ObjectT]) -> Result<ServiceT> {
+        let object = |ident: &DottedIdent| -> Result<Box<ObjectT>> {
+            let symbol = self.resolve(scope, ident)?;
+            match symbol.declaration {
+                Declaration::Table(_) => {
+                    Ok(Box::new(objects[self.index(&symbol) as usize].clone()))
+                }
+                _ => bail!("`{}` isn't a table", symbol_name(&symbol)),
+            }
+        };
+        let calls = scope
+            .item
+            .methods
+            .iter()
+            .map(|method| {
+                Ok(RPCCallT {
+                    name: method.id.raw.to_string(),
+                    request: object(&method.request_type)?,
+                    response: object(&method.response_type)?,
+                    attributes: attributes(&method.metadata),
+                    documentation: documentation(&method.doc),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ServiceT {
+            name: qualified_name(scope.namespace, &scope.item.id),
+            calls: Some(calls),
+            attributes: None,
+            documentation: documentation(&scope.item.doc),
+            declaration_file: None,
+        })
+    }
+
+    fn schema(&self) -> Result<SchemaT> {
+        let objects = self
+            .objects
+            .iter()
+            .map(|symbol| match symbol.declaration {
+                Declaration::Table(t) => self.table(&self.context.with(symbol.namespace, t)),
+                Declaration::Struct(s) => self.struct_(&self.context.with(symbol.namespace, s)),
+                _ => unreachable!("objects are tables and structs"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let enums = self
+            .enums
+            .iter()
+            .map(|symbol| match symbol.declaration {
+                Declaration::Enum(e) => self.enum_(symbol, e),
+                Declaration::Union(u) => self.union(symbol, u),
+                _ => unreachable!("enums are enums and unions"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let elements = || self.schemas.iter().flat_map(Schema::namespaced_elements);
+        let mut services = elements()
+            .filter_map(|(namespace, element)| match element {
+                Element::Rpc(rpc) => {
+                    let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+                    Some(self.service(&self.context.with(namespace, rpc), &objects))
+                }
+                _ => None,
+            })
+            .collect::<Result<Vec<_>>>()?;
+        services.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let last = self
+            .schemas
+            .last()
+            .into_iter()
+            .flat_map(Schema::namespaced_elements);
+        let mut root_table = None;
+        let mut file_ident = None;
+        let mut file_ext = None;
+        for (namespace, element) in last {
+            match element {
+                Element::Root(root) => {
+                    let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+                    let scope = self.context.with(namespace, &());
+                    let symbol = self.resolve(&scope, &vec![root.typename].into())?;
+                    root_table = Some(Box::new(objects[self.index(&symbol) as usize].clone()));
+                }
+                Element::FileIdentifier(identifier) => {
+                    file_ident = Some(identifier.id.iter().collect())
+                }
+                Element::FileExtension(extension) => file_ext = Some(extension.ext.to_string()),
+                _ => {}
+            }
+        }
+
+        let mut advanced_features = AdvancedFeatures::empty();
+        for field in objects.iter().flat_map(|object| &object.fields) {
+            if field.optional {
+                advanced_features |= AdvancedFeatures::OptionalScalars;
+            }
+            if field.r#type.base_type == BaseType::Array {
+                advanced_features |= AdvancedFeatures::AdvancedArrayFeatures;
+            }
+        }
+
+        Ok(SchemaT {
+            objects,
+            enums,
+            file_ident,
+            file_ext,
+            root_table,
+            services: if services.is_empty() {
+                None
+            } else {
+                Some(services)
+            },
+            advanced_features,
+            fbs_files: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod reflection_tests {
+    use super::*;
+    use crate::{codegen::Options, parser::parse_schema};
+    use quote::ToTokens;
+
+    const SCHEMA: &str = r#"
+namespace test.shapes;
+/// A point.
+struct Vec2 { x: float; y: double; }
+struct Cell { pos: Vec2; tags: [ubyte:3]; }
+
+namespace test;
+enum Color : byte { Red = -1, Green, Blue }
+enum Perm : ubyte (bit_flags) { Read, Write }
+table Dog { name: string (key); }
+table Cat { lives: int = 9; }
+union Pet { Dog, Cat }
+table Owner (tagged: "yes") {
+  name: string (required);
+  color: Color = Blue;
+  perm: Perm = Write;
+  cell: shapes.Cell;
+  cells: [shapes.Cell];
+  pet: Pet;
+  ratio: float = 2;
+  nickname: [ubyte] (deprecated);
+  age: ushort = null (secret);
+}
+rpc_service Kennel { Adopt(Owner): Dog (streaming: "server"); }
+root_type Owner;
+file_identifier "OWNR";
+file_extension "own";
+"#;
+
+    fn object<'s>(schema: &'s SchemaT, name: &str) -> &'s ObjectT {
+        schema
+            .objects
+            .iter()
+            .find(|object| object.name == name)
+            .unwrap()
+    }
+
+    fn field<'s>(object: &'s ObjectT, name: &str) -> &'s FieldT {
+        object
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_lower() {
+        let schemas = [parse_schema(SCHEMA).unwrap()];
+        let schema = lower(&schemas).unwrap();
+
+        let names = |names: &mut dyn Iterator<Item = &String>| names.cloned().collect::<Vec<_>>();
+        assert_eq!(
+            names(&mut schema.objects.iter().map(|object| &object.name)),
+            [
+                "test.Cat",
+                "test.Dog",
+                "test.Owner",
+                "test.shapes.Cell",
+                "test.shapes.Vec2"
+            ]
+        );
+        assert_eq!(
+            names(&mut schema.enums.iter().map(|e| &e.name)),
+            ["test.Color", "test.Perm", "test.Pet"]
+        );
+
+        let owner = object(&schema, "test.Owner");
+        assert!(!owner.is_struct);
+        assert_eq!(
+            names(&mut owner.fields.iter().map(|field| &field.name)),
+            [
+                "age", "cell", "cells", "color", "name", "nickname", "perm", "pet", "pet_type",
+                "ratio"
+            ]
+        );
+        let attributes = owner.attributes.as_ref().unwrap();
+        assert_eq!(attributes[0].key, "tagged");
+        assert_eq!(attributes[0].value.as_deref(), Some("yes"));
+
+        let name = field(owner, "name");
+        assert_eq!((name.id, name.offset), (0, 4));
+        assert!(name.required);
+        assert_eq!(name.r#type.base_type, BaseType::String);
+
+        let color = field(owner, "color");
+        assert_eq!(color.r#type.base_type, BaseType::Byte);
+        assert_eq!((color.r#type.index, color.default_integer), (0, 1));
+        assert_eq!(field(owner, "perm").default_integer, 2);
+
+        let cells = field(owner, "cells");
+        assert_eq!(cells.r#type.base_type, BaseType::Vector);
+        assert_eq!(cells.r#type.element, BaseType::Obj);
+        assert_eq!((cells.r#type.index, cells.r#type.element_size), (3, 24));
+
+        let pet_type = field(owner, "pet_type");
+        assert_eq!((pet_type.id, pet_type.offset), (5, 14));
+        assert_eq!(pet_type.r#type.base_type, BaseType::UType);
+        assert_eq!(pet_type.r#type.index, 2);
+        let pet = field(owner, "pet");
+        assert_eq!((pet.id, pet.offset), (6, 16));
+        assert_eq!(
+            (pet.r#type.base_type, pet.r#type.index),
+            (BaseType::Union, 2)
+        );
+
+        assert_eq!(field(owner, "ratio").default_real, 2.0);
+        assert!(field(owner, "nickname").deprecated);
+        let age = field(owner, "age");
+        assert_eq!(age.id, 9);
+        assert!(age.optional);
+        assert_eq!(
+            age.attributes.as_ref().unwrap()[0].value.as_deref(),
+            Some("0")
+        );
+        assert!(field(object(&schema, "test.Dog"), "name").key);
+
+        let vec2 = object(&schema, "test.shapes.Vec2");
+        assert!(vec2.is_struct);
+        assert_eq!((vec2.minalign, vec2.bytesize), (8, 16));
+        assert_eq!(vec2.documentation.as_ref().unwrap(), &[" A point."]);
+        let x = field(vec2, "x");
+        assert_eq!((x.id, x.offset, x.padding), (0, 0, 4));
+        let cell = object(&schema, "test.shapes.Cell");
+        let tags = field(cell, "tags");
+        assert_eq!((tags.offset, tags.padding), (16, 5));
+        assert_eq!(tags.r#type.base_type, BaseType::Array);
+        assert_eq!(
+            (tags.r#type.element, tags.r#type.fixed_length),
+            (BaseType::UByte, 3)
+        );
+
+        let perm = &schema.enums[1];
+        assert_eq!(
+            perm.values
+                .iter()
+                .map(|value| (value.name.as_str(), value.value))
+                .collect::<Vec<_>>(),
+            [("Read", 1), ("Write", 2)]
+        );
+        let pet = &schema.enums[2];
+        assert!(pet.is_union);
+        assert_eq!(pet.underlying_type.base_type, BaseType::UType);
+        assert_eq!(
+            pet.values
+                .iter()
+                .map(|value| (
+                    value.name.as_str(),
+                    value.union_type.as_ref().unwrap().index
+                ))
+                .collect::<Vec<_>>(),
+            [("NONE", -1), ("Dog", 1), ("Cat", 0)]
+        );
+
+        let kennel = &schema.services.as_ref().unwrap()[0];
+        assert_eq!(kennel.name, "test.Kennel");
+        let adopt = &kennel.calls.as_ref().unwrap()[0];
+        assert_eq!(adopt.request.name, "test.Owner");
+        assert_eq!(adopt.response.name, "test.Dog");
+
+        assert_eq!(schema.root_table.as_ref().unwrap().name, "test.Owner");
+        assert_eq!(schema.file_ident.as_deref(), Some("OWNR"));
+        assert_eq!(schema.file_ext.as_deref(), Some("own"));
+        assert_eq!(
+            schema.advanced_features,
+            AdvancedFeatures::OptionalScalars | AdvancedFeatures::AdvancedArrayFeatures
+        );
+    }
+
+    #[test]
+    fn test_binary_schema() {
+        let schemas = [parse_schema(SCHEMA).unwrap()];
+        let buf = binary_schema(&schemas).unwrap();
+        assert_eq!(&buf[4..8], b"BFBS");
+        let schema = reflection::root_as_schema(&buf).unwrap().unpack();
+        assert_eq!(schema, lower(&schemas).unwrap());
+    }
+
+    #[test]
+    fn test_binary_schema_array_of_structs() {
+        // flatc gives the elements of an array of structs the size of the struct, as for vectors
+        let input = "\
+struct Vec3 { x: float; y: float; z: float; }
+struct Sample { time: double; level: byte; }
+struct Triangle { corners: [Vec3:3]; axes: [ubyte:2]; samples: [Sample:2]; }";
+        let buf = binary_schema(&[parse_schema(input).unwrap()]).unwrap();
+        let schema = reflection::root_as_schema(&buf).unwrap().unpack();
+
+        let triangle = object(&schema, "Triangle");
+        assert_eq!((triangle.minalign, triangle.bytesize), (8, 72));
+        let corners = field(triangle, "corners");
+        assert_eq!((corners.offset, corners.padding), (0, 0));
+        let ty = &corners.r#type;
+        assert_eq!((ty.base_type, ty.element), (BaseType::Array, BaseType::Obj));
+        assert_eq!(schema.objects[ty.index as usize].name, "Vec3");
+        assert_eq!(ty.fixed_length, 3);
+        assert_eq!(ty.element_size as i32, object(&schema, "Vec3").bytesize);
+        let axes = field(triangle, "axes");
+        assert_eq!((axes.offset, axes.padding), (36, 2));
+        assert_eq!(axes.r#type.element_size, 1);
+
+        // the padding at the end of a struct is part of its size
+        let samples = &field(triangle, "samples").r#type;
+        assert_eq!(schema.objects[samples.index as usize].name, "Sample");
+        assert_eq!(object(&schema, "Sample").bytesize, 16);
+        assert_eq!(
+            samples.element_size as i32,
+            object(&schema, "Sample").bytesize
+        );
+    }
+
+    #[test]
+    fn test_lower_errors() {
+        let schemas = [parse_schema("table A { b: B; }").unwrap()];
+        assert!(lower(&schemas).is_err());
+    }
+
+    const REFLECTION_SCHEMA: &str = include_str!("../../butte-flatbuffers/fbs/reflection.fbs");
+
+    #[test]
+    fn test_lower_reflection_schema() {
+        let schemas = [parse_schema(REFLECTION_SCHEMA).unwrap()];
+        let schema = lower(&schemas).unwrap();
+        assert_eq!(
+            schema.root_table.as_ref().unwrap().name,
+            "reflection.Schema"
+        );
+        assert_eq!(schema.file_ident.as_deref(), Some("BFBS"));
+        let field = object(&schema, "reflection.Field");
+        let ty = self::field(field, "type");
+        assert!(ty.required);
+        assert_eq!(ty.r#type.base_type, BaseType::Obj);
+    }
+
+    /// The reflection types of the runtime are generated from `reflection.fbs`, and need
+    /// regenerating whenever the code generated for it changes.
+    #[test]
+    fn test_reflection_generated_up_to_date() {
+        let schemas = [parse_schema(REFLECTION_SCHEMA).unwrap()];
+        let options = Options {
+            object_api: true,
+            ..Options::default()
+        };
+        let code = Context::with_options(&schemas, options).to_token_stream();
+        let generated = crate::compile::rustfmt(&code.to_string()).unwrap();
+        assert_eq!(
+            String::from_utf8(generated).unwrap(),
+            include_str!("../../butte-flatbuffers/src/reflection_generated.rs")
+        );
+    }
+}
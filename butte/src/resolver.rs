@@ -0,0 +1,181 @@
+/// Resolve references to user-defined types.
+///
+/// Every table, struct, enum and union is known by its fully-qualified name: the parts of the
+/// namespace it was declared in, followed by its own name. A reference to a type from inside a
+/// namespace is resolved the way `flatc` does it, by looking for the type in the namespace of the
+/// reference first and then in each of its parents.
+use crate::types::*;
+use std::collections::HashMap;
+
+/// A user-defined type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Declaration<'a> {
+    Table(&'a Table<'a>),
+    Struct(&'a Struct<'a>),
+    Enum(&'a Enum<'a>),
+    Union(&'a Union<'a>),
+}
+
+/// A user-defined type along with the namespace it was declared in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Symbol<'a> {
+    /// The namespace containing the declaration.
+    pub namespace: &'a [Ident<'a>],
+
+    /// The name of the type.
+    pub id: Ident<'a>,
+
+    pub declaration: Declaration<'a>,
+}
+
+impl<'a> Symbol<'a> {
+    /// The path to the type from inside the module generated for `namespace`.
+    ///
+    /// Generated modules mirror namespaces, so the path climbs out of `namespace` until it reaches
+    /// the closest namespace shared with the type and then descends into the type's namespace.
+    pub fn path_from(&self, namespace: &[Ident]) -> Vec<Ident<'a>> {
+        let common = namespace
+            .iter()
+            .zip(self.namespace)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let mut path = vec![Ident::from("super"); namespace.len() - common];
+        path.extend_from_slice(&self.namespace[common..]);
+        path.push(self.id);
+        path
+    }
+}
+
+/// Every user-defined type in a set of schemas, keyed by fully-qualified name.
+#[derive(Debug, Default)]
+pub struct SymbolTable<'a> {
+    symbols: HashMap<Vec<&'a str>, Symbol<'a>>,
+}
+
+impl<'a> SymbolTable<'a> {
+    /// Collect the type declarations of `schemas`.
+    pub fn new(schemas: &'a [Schema<'a>]) -> Self {
+        let symbols = schemas
+            .iter()
+            .flat_map(Schema::namespaced_elements)
+            .filter_map(|(namespace, element)| {
+                let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+                let (id, declaration) = match element {
+                    Element::Table(t) => (t.id, Declaration::Table(t)),
+                    Element::Struct(s) => (s.id, Declaration::Struct(s)),
+                    Element::Enum(e) => (e.id, Declaration::Enum(e)),
+                    Element::Union(u) => (u.id, Declaration::Union(u)),
+                    _ => return None,
+                };
+                let name = namespace
+                    .iter()
+                    .chain(std::iter::once(&id))
+                    .map(|part| part.raw)
+                    .collect();
+                Some((
+                    name,
+                    Symbol {
+                        namespace,
+                        id,
+                        declaration,
+                    },
+                ))
+            })
+            .collect();
+        Self { symbols }
+    }
+
+    /// Find the type `ident` refers to when used in `namespace`.
+    pub fn resolve(&self, namespace: &[Ident], ident: &DottedIdent) -> Option<Symbol<'a>> {
+        (0..=namespace.len()).rev().find_map(|depth| {
+            let name = namespace[..depth]
+                .iter()
+                .chain(ident.parts.iter())
+                .map(|part| part.raw)
+                .collect::<Vec<_>>();
+            self.symbols.get(&name).copied()
+        })
+    }
+}
+
+#[cfg(test)]
+mod symbol_table_tests {
+    use super::*;
+    use crate::parser::schema_decl;
+    use itertools::Itertools;
+
+    fn path_string(path: Vec<Ident>) -> String {
+        path.iter().map(|part| part.raw).join("::")
+    }
+
+    #[test]
+    fn test_resolve() {
+        let input = "\
+table A { x: int; }
+namespace foo.bar;
+table B { x: int; }
+namespace foo;
+union C { A }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let symbols = SymbolTable::new(&schemas);
+        let foo_bar = [Ident::from("foo"), Ident::from("bar")];
+
+        let result = symbols.resolve(&foo_bar, &DottedIdent::from(vec!["B".into()]));
+        assert_eq!(result.map(|symbol| symbol.namespace), Some(&foo_bar[..]));
+
+        let result = symbols.resolve(&foo_bar, &DottedIdent::from(vec!["A".into()]));
+        assert_eq!(result.map(|symbol| symbol.namespace), Some(&[][..]));
+
+        // C lives in a parent of foo.bar, so it's visible from there but not from the root
+        let result = symbols.resolve(&foo_bar, &DottedIdent::from(vec!["C".into()]));
+        assert!(result.is_some());
+
+        let result = symbols.resolve(&[], &DottedIdent::from(vec!["C".into()]));
+        assert!(result.is_none());
+
+        let result = symbols.resolve(&[], &DottedIdent::from(vec!["foo".into(), "C".into()]));
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_path_from() {
+        let input = "\
+table A { x: int; }
+namespace foo.bar;
+table B { x: int; }
+namespace foo.baz;
+table C { x: int; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let symbols = SymbolTable::new(&schemas);
+        let resolve = |name: Vec<&'static str>| {
+            let ident = DottedIdent::from(name.into_iter().map(Ident::from).collect::<Vec<_>>());
+            symbols.resolve(&[], &ident).unwrap()
+        };
+        let foo_bar = [Ident::from("foo"), Ident::from("bar")];
+        let foo_baz = [Ident::from("foo"), Ident::from("baz")];
+
+        assert_eq!(path_string(resolve(vec!["A"]).path_from(&[])), "A");
+        assert_eq!(
+            path_string(resolve(vec!["A"]).path_from(&foo_bar)),
+            "super::super::A"
+        );
+        assert_eq!(
+            path_string(resolve(vec!["foo", "bar", "B"]).path_from(&[])),
+            "foo::bar::B"
+        );
+        assert_eq!(
+            path_string(resolve(vec!["foo", "bar", "B"]).path_from(&foo_bar)),
+            "B"
+        );
+        assert_eq!(
+            path_string(resolve(vec!["foo", "baz", "C"]).path_from(&foo_bar)),
+            "super::baz::C"
+        );
+        assert_eq!(
+            path_string(resolve(vec!["foo", "bar", "B"]).path_from(&foo_baz[..1])),
+            "bar::B"
+        );
+    }
+}
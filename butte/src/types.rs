@@ -15,6 +15,63 @@ pub struct Schema<'a> {
     pub elements: Vec<Element<'a>>,
 }
 
+impl<'a> Schema<'a> {
+    /// Pair each element of the schema that isn't a namespace with the namespace it resides in.
+    ///
+    /// Namespaces precede all of their contents, so the current namespace changes every time a
+    /// namespace element is encountered.
+    pub fn namespaced_elements(
+        &self,
+    ) -> impl Iterator<Item = (Option<&Namespace<'a>>, &Element<'a>)> {
+        self.elements
+            .iter()
+            .scan(None, |namespace, element| {
+                Some(if let Some(ns) = element.namespace() {
+                    *namespace = Some(ns);
+                    None
+                } else {
+                    Some((*namespace, element))
+                })
+            })
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod schema_impl_tests {
+    use super::*;
+    use crate::{namespace, schema, table};
+    use itertools::Itertools;
+
+    #[test]
+    fn test_namespaced_elements() {
+        let schema = schema! {
+            table!(A, []),
+            namespace!(foo::bar),
+            table!(B, []),
+            table!(C, []),
+            namespace!(baz),
+            table!(D, [])
+        };
+        let result = schema
+            .namespaced_elements()
+            .map(|(ns, element)| {
+                (
+                    ns.map(|ns| ns.ident.parts.iter().map(|part| part.raw).join(".")),
+                    element,
+                )
+            })
+            .collect::<Vec<_>>();
+        let expected = vec![
+            (None, &schema.elements[0]),
+            (Some("foo.bar".to_string()), &schema.elements[2]),
+            (Some("foo.bar".to_string()), &schema.elements[3]),
+            (Some("baz".to_string()), &schema.elements[5]),
+        ];
+        assert_eq!(result, expected);
+    }
+}
+
 /// A single include.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, From, TypedBuilder)]
 pub struct Include<'a> {
@@ -44,14 +101,14 @@ pub enum Element<'a> {
     Object(Object<'a>),
 }
 
-impl Element<'_> {
+impl<'a> Element<'a> {
     /// Check whether an element is a namespace.
     pub fn is_namespace(&self) -> bool {
         self.namespace().is_some()
     }
 
     /// Return the underlying `Namespace` object from the element if `self` is a `Namespace`.
-    pub fn namespace(&self) -> Option<&Namespace> {
+    pub fn namespace(&self) -> Option<&Namespace<'a>> {
         match self {
             Element::Namespace(ns) => Some(ns),
             _ => None,
@@ -168,6 +225,16 @@ pub struct Enum<'a> {
     pub doc: Comment<'a>,
 }
 
+impl Enum<'_> {
+    /// Check whether the enum has the `bit_flags` attribute, making its values bit positions.
+    pub fn is_bit_flags(&self) -> bool {
+        match &self.metadata {
+            Some(metadata) => metadata.values.contains_key(&Ident::from("bit_flags")),
+            None => false,
+        }
+    }
+}
+
 /// Union type.
 #[derive(Debug, Clone, PartialEq, TypedBuilder)]
 pub struct Union<'a> {
@@ -187,8 +254,9 @@ pub struct Field<'a> {
     pub id: Ident<'a>,
     pub ty: Type<'a>,
 
+    /// The default value of the field, if one was given.
     #[builder(default)]
-    pub scalar: Option<Scalar>,
+    pub default: Option<DefaultValue<'a>>,
 
     #[builder(default)]
     pub metadata: Option<Metadata<'a>>,
@@ -197,6 +265,72 @@ pub struct Field<'a> {
     pub doc: Comment<'a>,
 }
 
+impl Field<'_> {
+    /// Check whether the field has the attribute `name`, whatever its value.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        match &self.metadata {
+            Some(metadata) => metadata.values.contains_key(&Ident::from(name)),
+            None => false,
+        }
+    }
+
+    /// Check whether the field has the `required` attribute.
+    pub fn is_required(&self) -> bool {
+        self.has_attribute("required")
+    }
+
+    /// Check whether the field has the `deprecated` attribute.
+    pub fn is_deprecated(&self) -> bool {
+        self.has_attribute("deprecated")
+    }
+
+    /// The value of the `id` attribute, if the field has one and it's an integer.
+    ///
+    /// The id may be given either as an integer or as a string holding one.
+    pub fn explicit_id(&self) -> Option<IntegerConstant> {
+        let value = self
+            .metadata
+            .as_ref()?
+            .values
+            .get(&Ident::from("id"))?
+            .as_ref()?;
+        match value {
+            Single::Scalar(Scalar::Integer(id)) => Some(*id),
+            Single::String(id) => id.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod field_impl_tests {
+    use super::*;
+    use crate::{field, meta};
+
+    fn with_metadata<'a>(values: Vec<(Ident<'a>, Option<Single<'a>>)>) -> Field<'a> {
+        Field {
+            metadata: Some(Metadata::from(values)),
+            ..field!(foo, Int)
+        }
+    }
+
+    #[test]
+    fn test_is_required() {
+        assert!(with_metadata(vec![meta!(required)]).is_required());
+        assert!(!with_metadata(vec![meta!(id, 1)]).is_required());
+        assert!(!field!(foo, Int).is_required());
+    }
+
+    #[test]
+    fn test_explicit_id() {
+        assert_eq!(with_metadata(vec![meta!(id, 3)]).explicit_id(), Some(3));
+        assert_eq!(with_metadata(vec![meta!(id, "4")]).explicit_id(), Some(4));
+        assert_eq!(with_metadata(vec![meta!(id, 1.5)]).explicit_id(), None);
+        assert_eq!(with_metadata(vec![meta!(id)]).explicit_id(), None);
+        assert_eq!(field!(foo, Int).explicit_id(), None);
+    }
+}
+
 /// An RPC service.
 #[derive(Debug, Clone, PartialEq, TypedBuilder)]
 pub struct Rpc<'a> {
@@ -253,6 +387,9 @@ pub enum Type<'a> {
     Float64,
     String,
     Array(Box<Type<'a>>),
+
+    /// An array of a fixed number of elements, `[T:N]`, stored inline. Only structs have these.
+    FixedArray(Box<Type<'a>>, usize),
     Ident(DottedIdent<'a>),
 }
 
@@ -262,10 +399,31 @@ impl Type<'_> {
         // If it's a string, array type, or type name (UDT) it's not a scalar.
         // Otherwise it is.
         match self {
-            Type::String | Type::Array(_) | Type::Ident(_) => false,
+            Type::String | Type::Array(_) | Type::FixedArray(..) | Type::Ident(_) => false,
             _ => true,
         }
     }
+
+    /// The size in bytes of a scalar type, or `None` if the type isn't a scalar.
+    pub fn size(&self) -> Option<usize> {
+        Some(match self {
+            Type::Bool | Type::Byte | Type::UByte | Type::Int8 | Type::UInt8 => 1,
+            Type::Short | Type::UShort | Type::Int16 | Type::UInt16 => 2,
+            Type::Int | Type::UInt | Type::Float | Type::Int32 | Type::UInt32 | Type::Float32 => 4,
+            Type::Long
+            | Type::ULong
+            | Type::Double
+            | Type::Int64
+            | Type::UInt64
+            | Type::Float64 => 8,
+            Type::String | Type::Array(_) | Type::FixedArray(..) | Type::Ident(_) => return None,
+        })
+    }
+
+    /// Check whether a `Type` is stored as an offset to data elsewhere in the buffer.
+    pub fn is_reference(&self) -> bool {
+        matches!(self, Type::String | Type::Array(_))
+    }
 }
 
 impl<'a> From<[Type<'a>; 1]> for Type<'a> {
@@ -331,6 +489,18 @@ impl<'a> From<Vec<(Ident<'a>, Value<'a>)>> for Object<'a> {
     }
 }
 
+/// The default value of a table field.
+#[derive(Debug, Copy, Clone, PartialEq, From)]
+pub enum DefaultValue<'a> {
+    Scalar(Scalar),
+
+    /// The name of a variant, for a field whose type is an enum.
+    Ident(Ident<'a>),
+
+    /// `= null`: the field has no default, and reading it yields `None` when it isn't set.
+    Null,
+}
+
 /// A `Scalar` or string literal
 #[derive(Debug, Clone, PartialEq, PartialOrd, From)]
 pub enum Single<'a> {
@@ -431,4 +601,22 @@ mod type_tests {
         assert!(!Type::Ident(vec!["foobar".into()].into()).is_scalar());
         assert!(!Type::Array(Box::new(Type::Byte)).is_scalar());
     }
+
+    #[test]
+    fn test_is_reference() {
+        assert!(Type::String.is_reference());
+        assert!(Type::Array(Box::new(Type::Byte)).is_reference());
+        assert!(!Type::Float32.is_reference());
+        assert!(!Type::Ident(vec!["foobar".into()].into()).is_reference());
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(Type::Bool.size(), Some(1));
+        assert_eq!(Type::UShort.size(), Some(2));
+        assert_eq!(Type::Float32.size(), Some(4));
+        assert_eq!(Type::Double.size(), Some(8));
+        assert_eq!(Type::String.size(), None);
+        assert_eq!(Type::Ident(vec!["foobar".into()].into()).size(), None);
+    }
 }
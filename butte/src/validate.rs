@@ -0,0 +1,1151 @@
+/// Check schemas for mistakes that would otherwise produce invalid Rust code.
+use crate::{
+    diagnostic::Span,
+    resolver::{Declaration, Symbol, SymbolTable},
+    types::*,
+};
+use flatbuffers::VOffsetT;
+use itertools::Itertools;
+use std::{collections::HashSet, convert::TryFrom, fmt};
+
+/// The kinds of mistakes found by [`validate`](crate::validate::validate).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    DuplicateType {
+        name: String,
+    },
+    DuplicateField {
+        parent: String,
+        field: String,
+    },
+    DuplicateEnumValue {
+        parent: String,
+        value: String,
+    },
+    UnknownType {
+        name: String,
+    },
+    InvalidEnumBaseType {
+        parent: String,
+    },
+    EnumValueOutOfRange {
+        parent: String,
+        value: String,
+        constant: IntegerConstant,
+        base_type: String,
+    },
+    EnumValuesNotAscending {
+        parent: String,
+        value: String,
+        constant: IntegerConstant,
+    },
+    InvalidStructField {
+        parent: String,
+        field: String,
+    },
+    RecursiveStruct {
+        parent: String,
+        field: String,
+    },
+    UnionVariantNotTable {
+        parent: String,
+        value: String,
+    },
+    VectorOfUnions {
+        parent: String,
+        field: String,
+    },
+    RpcTypeNotTable {
+        method: String,
+        name: String,
+    },
+    RootTypeNotTable {
+        name: String,
+    },
+    DefaultNotAllowed {
+        parent: String,
+        field: String,
+    },
+    InvalidDefault {
+        parent: String,
+        field: String,
+        ty: String,
+    },
+    RequiredScalar {
+        parent: String,
+        field: String,
+    },
+    MissingIds {
+        parent: String,
+    },
+    InvalidId {
+        parent: String,
+        field: String,
+    },
+    UnionIdZero {
+        parent: String,
+        field: String,
+    },
+    DuplicateId {
+        parent: String,
+        field: String,
+        id: IntegerConstant,
+    },
+    NonContiguousIds {
+        parent: String,
+        missing: IntegerConstant,
+    },
+    MultipleKeys {
+        parent: String,
+    },
+    InvalidKey {
+        parent: String,
+        field: String,
+    },
+    FixedArrayInTable {
+        parent: String,
+        field: String,
+    },
+    SignedBitFlags {
+        parent: String,
+    },
+    BitFlagOutOfRange {
+        parent: String,
+        value: String,
+        bit: IntegerConstant,
+        base_type: String,
+    },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::DuplicateType { name } => {
+                write!(f, "type {} is defined more than once", name)
+            }
+            ErrorKind::DuplicateField { parent, field } => {
+                write!(f, "field {} of {} is defined more than once", field, parent)
+            }
+            ErrorKind::DuplicateEnumValue { parent, value } => {
+                write!(f, "value {} of {} is defined more than once", value, parent)
+            }
+            ErrorKind::UnknownType { name } => write!(f, "unknown type {}", name),
+            ErrorKind::InvalidEnumBaseType { parent } => {
+                write!(
+                    f,
+                    "the underlying type of {} must be an integer type",
+                    parent
+                )
+            }
+            ErrorKind::EnumValueOutOfRange {
+                parent,
+                value,
+                constant,
+                base_type,
+            } => write!(
+                f,
+                "value {} of {} is {}, which doesn't fit in {}",
+                value, parent, constant, base_type
+            ),
+            ErrorKind::EnumValuesNotAscending {
+                parent,
+                value,
+                constant,
+            } => write!(
+                f,
+                "value {} of {} is {}, which isn't greater than the value before it",
+                value, parent, constant
+            ),
+            ErrorKind::InvalidStructField { parent, field } => write!(
+                f,
+                "field {} of struct {} must be a scalar, an enum or a struct",
+                field, parent
+            ),
+            ErrorKind::RecursiveStruct { parent, field } => write!(
+                f,
+                "field {} of struct {} contains {} itself, so it would be infinitely large",
+                field, parent, parent
+            ),
+            ErrorKind::UnionVariantNotTable { parent, value } => {
+                write!(f, "variant {} of union {} is not a table", value, parent)
+            }
+            ErrorKind::VectorOfUnions { parent, field } => write!(
+                f,
+                "field {} of table {} is a vector of unions, which isn't supported",
+                field, parent
+            ),
+            ErrorKind::RpcTypeNotTable { method, name } => write!(
+                f,
+                "{} is used as a request or response type of {}, but isn't a table",
+                name, method
+            ),
+            ErrorKind::RootTypeNotTable { name } => {
+                write!(f, "root type {} is not a table", name)
+            }
+            ErrorKind::DefaultNotAllowed { parent, field } => write!(
+                f,
+                "field {} of {} can't have a default value, only scalar and enum fields of tables can",
+                field, parent
+            ),
+            ErrorKind::InvalidDefault { parent, field, ty } => write!(
+                f,
+                "the default value of field {} of {} isn't a valid {}",
+                field, parent, ty
+            ),
+            ErrorKind::RequiredScalar { parent, field } => write!(
+                f,
+                "field {} of {} is a scalar, so it can't be required",
+                field, parent
+            ),
+            ErrorKind::MissingIds { parent } => write!(
+                f,
+                "either all fields of {} must have an id, or none of them",
+                parent
+            ),
+            ErrorKind::InvalidId { parent, field } => write!(
+                f,
+                "the id of field {} of {} must be a non-negative integer",
+                field, parent
+            ),
+            ErrorKind::UnionIdZero { parent, field } => write!(
+                f,
+                "field {} of {} is a union, which also takes the id before its own, so its id can't be 0",
+                field, parent
+            ),
+            ErrorKind::DuplicateId { parent, field, id } => write!(
+                f,
+                "id {} of field {} of {} is already taken by another field",
+                id, field, parent
+            ),
+            ErrorKind::NonContiguousIds { parent, missing } => write!(
+                f,
+                "the ids of the fields of {} must be contiguous from 0, but {} is missing",
+                parent, missing
+            ),
+            ErrorKind::MultipleKeys { parent } => {
+                write!(f, "only one field of {} can be its key", parent)
+            }
+            ErrorKind::InvalidKey { parent, field } => write!(
+                f,
+                "field {} of {} can't be a key, only scalars, enums and strings can",
+                field, parent
+            ),
+            ErrorKind::FixedArrayInTable { parent, field } => write!(
+                f,
+                "field {} of table {} is a fixed-length array, which only structs can have",
+                field, parent
+            ),
+            ErrorKind::SignedBitFlags { parent } => write!(
+                f,
+                "the underlying type of bit flags {} must be an unsigned integer type",
+                parent
+            ),
+            ErrorKind::BitFlagOutOfRange {
+                parent,
+                value,
+                bit,
+                base_type,
+            } => write!(
+                f,
+                "value {} of {} is bit {}, which doesn't fit in {}",
+                value, parent, bit, base_type
+            ),
+        }
+    }
+}
+
+/// A mistake in a schema, along with the identifiers it was found at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error<'a> {
+    pub kind: ErrorKind,
+
+    /// The parts of the identifier the error refers to.
+    pub at: &'a [Ident<'a>],
+}
+
+impl Error<'_> {
+    /// Return the span of the error in `text`, if the error was found in `text`.
+    pub fn span(&self, text: &str) -> Option<Span> {
+        let first = Span::of(text, self.at.first()?.raw)?;
+        let last = Span::of(text, self.at.last()?.raw)?;
+        Some(Span {
+            start: first.start,
+            end: last.end,
+        })
+    }
+}
+
+impl fmt::Display for Error<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+impl std::error::Error for Error<'_> {}
+
+fn dotted(parts: &[Ident]) -> String {
+    parts.iter().map(|part| part.raw).join(".")
+}
+
+/// The range of values of an integer type.
+fn integer_range(ty: &Type) -> Option<(i128, i128)> {
+    let (bits, signed) = match ty {
+        Type::Byte | Type::Int8 => (8, true),
+        Type::UByte | Type::UInt8 => (8, false),
+        Type::Short | Type::Int16 => (16, true),
+        Type::UShort | Type::UInt16 => (16, false),
+        Type::Int | Type::Int32 => (32, true),
+        Type::UInt | Type::UInt32 => (32, false),
+        Type::Long | Type::Int64 => (64, true),
+        Type::ULong | Type::UInt64 => (64, false),
+        _ => return None,
+    };
+    Some(if signed {
+        (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+    } else {
+        (0, (1 << bits) - 1)
+    })
+}
+
+/// Check `schemas` for mistakes, returning every one found in the order they appear.
+pub fn validate<'a>(schemas: &'a [Schema<'a>], symbols: &SymbolTable<'a>) -> Vec<Error<'a>> {
+    let mut validator = Validator {
+        symbols,
+        defined: HashSet::new(),
+        errors: vec![],
+    };
+    for (namespace, element) in schemas.iter().flat_map(Schema::namespaced_elements) {
+        let namespace = namespace.map_or(&[][..], |ns| &ns.ident.parts[..]);
+        validator.element(namespace, element);
+    }
+    validator.errors
+}
+
+struct Validator<'s, 'a> {
+    symbols: &'s SymbolTable<'a>,
+
+    /// The fully-qualified names of the types seen so far.
+    defined: HashSet<Vec<&'a str>>,
+
+    errors: Vec<Error<'a>>,
+}
+
+impl<'a> Validator<'_, 'a> {
+    fn error(&mut self, kind: ErrorKind, at: &'a [Ident<'a>]) {
+        self.errors.push(Error { kind, at });
+    }
+
+    fn element(&mut self, namespace: &'a [Ident<'a>], element: &'a Element<'a>) {
+        match element {
+            Element::Table(Table { id, fields, .. }) => {
+                self.define(namespace, id);
+                self.fields(namespace, id, fields);
+                for field in fields {
+                    self.table_field(namespace, id, field);
+                }
+                self.field_ids(namespace, id, fields);
+                if fields
+                    .iter()
+                    .filter(|field| field.has_attribute("key"))
+                    .count()
+                    > 1
+                {
+                    self.error(
+                        ErrorKind::MultipleKeys {
+                            parent: id.raw.to_string(),
+                        },
+                        std::slice::from_ref(id),
+                    );
+                }
+            }
+            Element::Struct(s @ Struct { id, fields, .. }) => {
+                self.define(namespace, id);
+                self.fields(namespace, id, fields);
+                for field in fields {
+                    if field.default.is_some() {
+                        self.error(
+                            ErrorKind::DefaultNotAllowed {
+                                parent: id.raw.to_string(),
+                                field: field.id.raw.to_string(),
+                            },
+                            std::slice::from_ref(&field.id),
+                        );
+                    }
+                    // fixed-length arrays hold the same things as structs do, apart from arrays
+                    let ty = match &field.ty {
+                        Type::FixedArray(element, _) => element,
+                        ty => ty,
+                    };
+                    let valid = match ty {
+                        Type::Ident(ident) => match self.symbols.resolve(namespace, ident) {
+                            Some(symbol) => matches!(
+                                symbol.declaration,
+                                Declaration::Struct(_) | Declaration::Enum(_)
+                            ),
+                            // reported as an unknown type
+                            None => true,
+                        },
+                        ty => ty.is_scalar(),
+                    };
+                    if !valid {
+                        self.error(
+                            ErrorKind::InvalidStructField {
+                                parent: id.raw.to_string(),
+                                field: field.id.raw.to_string(),
+                            },
+                            std::slice::from_ref(&field.id),
+                        );
+                    } else if self.contains_struct(namespace, ty, s, &mut HashSet::new()) {
+                        self.error(
+                            ErrorKind::RecursiveStruct {
+                                parent: id.raw.to_string(),
+                                field: field.id.raw.to_string(),
+                            },
+                            std::slice::from_ref(&field.id),
+                        );
+                    }
+                }
+            }
+            Element::Enum(
+                e @ Enum {
+                    id,
+                    values,
+                    base_type,
+                    ..
+                },
+            ) => {
+                self.define(namespace, id);
+                match integer_range(base_type) {
+                    Some((min, _)) if e.is_bit_flags() && min < 0 => self.error(
+                        ErrorKind::SignedBitFlags {
+                            parent: id.raw.to_string(),
+                        },
+                        std::slice::from_ref(id),
+                    ),
+                    // the values of bit flags are the positions of their bits
+                    Some((_, max)) if e.is_bit_flags() => {
+                        let bits = (max + 1).trailing_zeros();
+                        self.enum_values(id, values, 0, base_type, (0, i128::from(bits) - 1), true)
+                    }
+                    Some(range) => self.enum_values(id, values, 0, base_type, range, false),
+                    None => self.error(
+                        ErrorKind::InvalidEnumBaseType {
+                            parent: id.raw.to_string(),
+                        },
+                        std::slice::from_ref(id),
+                    ),
+                }
+            }
+            Element::Union(Union { id, values, .. }) => {
+                self.define(namespace, id);
+                // zero is taken by the empty union
+                self.enum_values(id, values, 1, &Type::UByte, (1, 255), false);
+                for EnumVal { id: variant, .. } in values {
+                    let at = std::slice::from_ref(variant);
+                    match self
+                        .symbols
+                        .resolve(namespace, &DottedIdent::from(at.to_vec()))
+                    {
+                        Some(symbol) => match symbol.declaration {
+                            Declaration::Table(_) => {}
+                            _ => self.error(
+                                ErrorKind::UnionVariantNotTable {
+                                    parent: id.raw.to_string(),
+                                    value: variant.raw.to_string(),
+                                },
+                                at,
+                            ),
+                        },
+                        None => self.unknown_type(at),
+                    }
+                }
+            }
+            Element::Rpc(Rpc { id, methods, .. }) => {
+                self.define(namespace, id);
+                for RpcMethod {
+                    id: method,
+                    request_type,
+                    response_type,
+                    ..
+                } in methods
+                {
+                    for ty in &[request_type, response_type] {
+                        match self.symbols.resolve(namespace, ty) {
+                            Some(symbol) => match symbol.declaration {
+                                Declaration::Table(_) => {}
+                                _ => self.error(
+                                    ErrorKind::RpcTypeNotTable {
+                                        method: format!("{}.{}", id.raw, method.raw),
+                                        name: dotted(&ty.parts),
+                                    },
+                                    &ty.parts,
+                                ),
+                            },
+                            None => self.unknown_type(&ty.parts),
+                        }
+                    }
+                }
+            }
+            Element::Root(Root { typename, .. }) => {
+                let at = std::slice::from_ref(typename);
+                match self
+                    .symbols
+                    .resolve(namespace, &DottedIdent::from(at.to_vec()))
+                {
+                    Some(symbol) => match symbol.declaration {
+                        Declaration::Table(_) => {}
+                        _ => self.error(
+                            ErrorKind::RootTypeNotTable {
+                                name: typename.raw.to_string(),
+                            },
+                            at,
+                        ),
+                    },
+                    None => self.unknown_type(at),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the definition of a type, checking that it's the only one.
+    fn define(&mut self, namespace: &[Ident<'a>], id: &'a Ident<'a>) {
+        let name = namespace
+            .iter()
+            .chain(std::iter::once(id))
+            .map(|part| part.raw)
+            .collect::<Vec<_>>();
+        if !self.defined.insert(name.clone()) {
+            self.error(
+                ErrorKind::DuplicateType {
+                    name: name.join("."),
+                },
+                std::slice::from_ref(id),
+            );
+        }
+    }
+
+    fn unknown_type(&mut self, at: &'a [Ident<'a>]) {
+        self.error(ErrorKind::UnknownType { name: dotted(at) }, at);
+    }
+
+    fn fields(&mut self, namespace: &[Ident], parent: &Ident, fields: &'a [Field<'a>]) {
+        let mut seen = HashSet::new();
+        for Field { id, ty, .. } in fields {
+            if !seen.insert(id.raw) {
+                self.error(
+                    ErrorKind::DuplicateField {
+                        parent: parent.raw.to_string(),
+                        field: id.raw.to_string(),
+                    },
+                    std::slice::from_ref(id),
+                );
+            }
+            let mut ty = ty;
+            while let Type::Array(element) | Type::FixedArray(element, _) = ty {
+                ty = element;
+            }
+            if let Type::Ident(ident) = ty {
+                if self.symbols.resolve(namespace, ident).is_none() {
+                    self.unknown_type(&ident.parts);
+                }
+            }
+        }
+    }
+
+    /// Check whether a struct field of type `ty` contains `target`, either as the field itself or
+    /// nested in the fields of other structs. `seen` holds the structs already looked through.
+    fn contains_struct(
+        &self,
+        namespace: &[Ident],
+        ty: &Type,
+        target: &Struct,
+        seen: &mut HashSet<*const Struct<'a>>,
+    ) -> bool {
+        let ty = match ty {
+            Type::FixedArray(element, _) => element,
+            ty => ty,
+        };
+        let (namespace, s) = match ty {
+            Type::Ident(ident) => match self.symbols.resolve(namespace, ident) {
+                Some(Symbol {
+                    namespace,
+                    declaration: Declaration::Struct(s),
+                    ..
+                }) => (namespace, s),
+                _ => return false,
+            },
+            _ => return false,
+        };
+        if std::ptr::eq(s, target) {
+            return true;
+        }
+        seen.insert(s)
+            && s.fields
+                .iter()
+                .any(|field| self.contains_struct(namespace, &field.ty, target, seen))
+    }
+
+    /// Check that the default value and attributes of a table field suit its type.
+    fn table_field(&mut self, namespace: &[Ident], parent: &Ident, field: &'a Field<'a>) {
+        let Field {
+            id, ty, default, ..
+        } = field;
+        let at = std::slice::from_ref(id);
+        if let Type::FixedArray(..) = ty {
+            return self.error(
+                ErrorKind::FixedArrayInTable {
+                    parent: parent.raw.to_string(),
+                    field: id.raw.to_string(),
+                },
+                at,
+            );
+        }
+        if let Type::Array(element) = ty {
+            if let Type::Ident(ident) = &**element {
+                if let Some(Symbol {
+                    declaration: Declaration::Union(_),
+                    ..
+                }) = self.symbols.resolve(namespace, ident)
+                {
+                    return self.error(
+                        ErrorKind::VectorOfUnions {
+                            parent: parent.raw.to_string(),
+                            field: id.raw.to_string(),
+                        },
+                        at,
+                    );
+                }
+            }
+        }
+        let declaration = match ty {
+            Type::Ident(ident) => match self.symbols.resolve(namespace, ident) {
+                Some(symbol) => Some(symbol.declaration),
+                // reported as an unknown type
+                None => return,
+            },
+            _ => None,
+        };
+        let is_scalar = match declaration {
+            Some(Declaration::Enum(_)) => true,
+            Some(_) => false,
+            None => ty.is_scalar(),
+        };
+        if is_scalar && field.is_required() {
+            self.error(
+                ErrorKind::RequiredScalar {
+                    parent: parent.raw.to_string(),
+                    field: id.raw.to_string(),
+                },
+                at,
+            );
+        }
+        if field.has_attribute("key") && !(is_scalar || *ty == Type::String) {
+            self.error(
+                ErrorKind::InvalidKey {
+                    parent: parent.raw.to_string(),
+                    field: id.raw.to_string(),
+                },
+                at,
+            );
+        }
+        let default = match default {
+            Some(DefaultValue::Null) if is_scalar => return,
+            Some(_) if !is_scalar => {
+                return self.error(
+                    ErrorKind::DefaultNotAllowed {
+                        parent: parent.raw.to_string(),
+                        field: id.raw.to_string(),
+                    },
+                    at,
+                )
+            }
+            Some(default) => *default,
+            // fields without a default are zero when they're not set
+            None => DefaultValue::Scalar(Scalar::Integer(0)),
+        };
+        let (valid, ty) = match declaration {
+            Some(Declaration::Enum(e)) => {
+                let mut values = e.values.iter().scan(0, |next, EnumVal { value, .. }| {
+                    let value = value.unwrap_or(*next);
+                    *next = value.saturating_add(1);
+                    Some(value)
+                });
+                let valid = match default {
+                    // any combination of flags will do
+                    DefaultValue::Scalar(Scalar::Integer(default)) if e.is_bit_flags() => {
+                        let all = values.fold(0, |all, bit| {
+                            all | 1i128.checked_shl(bit as u32).unwrap_or(0)
+                        });
+                        let default = i128::from(default);
+                        default >= 0 && default & !all == 0
+                    }
+                    DefaultValue::Scalar(Scalar::Integer(default)) => {
+                        values.any(|value| value == default)
+                    }
+                    DefaultValue::Ident(name) => e.values.iter().any(|value| value.id == name),
+                    _ => false,
+                };
+                (valid, e.id.raw.to_string())
+            }
+            _ => {
+                let default = match default {
+                    DefaultValue::Scalar(scalar) => scalar,
+                    // only enums have named values
+                    _ => {
+                        return self.error(
+                            ErrorKind::InvalidDefault {
+                                parent: parent.raw.to_string(),
+                                field: id.raw.to_string(),
+                                ty: quote::ToTokens::to_token_stream(ty).to_string(),
+                            },
+                            at,
+                        )
+                    }
+                };
+                let valid = match (integer_range(ty), default) {
+                    (Some((min, max)), Scalar::Integer(value)) => {
+                        i128::from(value) >= min && i128::from(value) <= max
+                    }
+                    (Some(_), _) => false,
+                    (None, Scalar::Boolean(_)) => *ty == Type::Bool,
+                    (None, Scalar::Integer(_)) => true,
+                    (None, Scalar::Float(_)) => *ty != Type::Bool,
+                };
+                (valid, quote::ToTokens::to_token_stream(ty).to_string())
+            }
+        };
+        if !valid {
+            self.error(
+                ErrorKind::InvalidDefault {
+                    parent: parent.raw.to_string(),
+                    field: id.raw.to_string(),
+                    ty,
+                },
+                at,
+            );
+        }
+    }
+
+    /// Check that the fields of a table either all have ids or none do, and that their ids cover
+    /// every vtable slot exactly once. A union takes the slot before its id for its type.
+    fn field_ids(&mut self, namespace: &[Ident], parent: &'a Ident<'a>, fields: &'a [Field<'a>]) {
+        let with_id = fields
+            .iter()
+            .filter(|field| field.has_attribute("id"))
+            .count();
+        if with_id == 0 {
+            return;
+        }
+        if with_id < fields.len() {
+            return self.error(
+                ErrorKind::MissingIds {
+                    parent: parent.raw.to_string(),
+                },
+                std::slice::from_ref(parent),
+            );
+        }
+        let mut taken = HashSet::new();
+        for field in fields {
+            let at = std::slice::from_ref(&field.id);
+            let id = match field.explicit_id() {
+                Some(id) if id >= 0 && VOffsetT::try_from(id).is_ok() => id,
+                _ => {
+                    self.error(
+                        ErrorKind::InvalidId {
+                            parent: parent.raw.to_string(),
+                            field: field.id.raw.to_string(),
+                        },
+                        at,
+                    );
+                    continue;
+                }
+            };
+            let is_union = match &field.ty {
+                Type::Ident(ident) => match self.symbols.resolve(namespace, ident) {
+                    Some(symbol) => matches!(symbol.declaration, Declaration::Union(_)),
+                    None => false,
+                },
+                _ => false,
+            };
+            let slots = if is_union {
+                if id == 0 {
+                    self.error(
+                        ErrorKind::UnionIdZero {
+                            parent: parent.raw.to_string(),
+                            field: field.id.raw.to_string(),
+                        },
+                        at,
+                    );
+                    continue;
+                }
+                vec![id - 1, id]
+            } else {
+                vec![id]
+            };
+            for slot in slots {
+                if !taken.insert(slot) {
+                    self.error(
+                        ErrorKind::DuplicateId {
+                            parent: parent.raw.to_string(),
+                            field: field.id.raw.to_string(),
+                            id: slot,
+                        },
+                        at,
+                    );
+                }
+            }
+        }
+        if let Some(missing) = (0..taken.len() as IntegerConstant).find(|id| !taken.contains(id)) {
+            self.error(
+                ErrorKind::NonContiguousIds {
+                    parent: parent.raw.to_string(),
+                    missing,
+                },
+                std::slice::from_ref(parent),
+            );
+        }
+    }
+
+    /// Check that the values of an enum or union are unique, ascending, and fit in `base_type`.
+    ///
+    /// The values of bit flags are bit positions, which have to be less than the number of bits of
+    /// `base_type`.
+    fn enum_values(
+        &mut self,
+        parent: &Ident,
+        values: &'a [EnumVal<'a>],
+        first: IntegerConstant,
+        base_type: &Type,
+        (min, max): (i128, i128),
+        bit_flags: bool,
+    ) {
+        let mut seen = HashSet::new();
+        let mut previous: Option<IntegerConstant> = None;
+        for EnumVal { id, value } in values {
+            let at = std::slice::from_ref(id);
+            if !seen.insert(id.raw) {
+                self.error(
+                    ErrorKind::DuplicateEnumValue {
+                        parent: parent.raw.to_string(),
+                        value: id.raw.to_string(),
+                    },
+                    at,
+                );
+            }
+            let constant = value.unwrap_or_else(|| previous.map_or(first, |p| p.saturating_add(1)));
+            let ascending = match previous {
+                Some(previous) => constant > previous,
+                None => true,
+            };
+            if !ascending {
+                self.error(
+                    ErrorKind::EnumValuesNotAscending {
+                        parent: parent.raw.to_string(),
+                        value: id.raw.to_string(),
+                        constant,
+                    },
+                    at,
+                );
+            }
+            let wide = i128::from(constant);
+            if wide < min || wide > max {
+                let parent = parent.raw.to_string();
+                let value = id.raw.to_string();
+                let base_type = format!("{}", quote::ToTokens::to_token_stream(base_type));
+                let kind = if bit_flags {
+                    ErrorKind::BitFlagOutOfRange {
+                        parent,
+                        value,
+                        bit: constant,
+                        base_type,
+                    }
+                } else {
+                    ErrorKind::EnumValueOutOfRange {
+                        parent,
+                        value,
+                        constant,
+                        base_type,
+                    }
+                };
+                self.error(kind, at);
+            }
+            previous = Some(constant);
+        }
+    }
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use super::*;
+    use crate::parser::schema_decl;
+
+    fn messages(input: &str) -> Vec<String> {
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let symbols = SymbolTable::new(&schemas);
+        validate(&schemas, &symbols)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_schema() {
+        let input = "\
+namespace foo;
+enum Color : ubyte { Red, Green = 3, Blue }
+struct Vec3 { x: float; y: float; z: float; }
+struct Pixel { position: Vec3; color: Color; }
+table A { pixels: [Pixel]; name: string; }
+union U { A }
+rpc_service S { Get(A) : foo.A; }
+root_type A;";
+        assert_eq!(messages(input), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let input = "\
+table A { x: int; x: float; }
+enum A : int { X, Y, X }
+namespace foo;
+table A { x: int; }";
+        assert_eq!(
+            messages(input),
+            vec![
+                "field x of A is defined more than once",
+                "type A is defined more than once",
+                "value X of A is defined more than once",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_types() {
+        let input = "\
+namespace foo;
+table A { x: [bar.B]; }
+union U { A, C }
+rpc_service S { Get(A) : D; }
+root_type E;";
+        assert_eq!(
+            messages(input),
+            vec![
+                "unknown type bar.B",
+                "unknown type C",
+                "unknown type D",
+                "unknown type E",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_values() {
+        let input = "\
+enum A : byte { X = 127, Y }
+enum B : ubyte { X = 2, Y = 1, Z = -1 }
+enum C : float { X }
+union U { V = 0 }
+table V { x: int; }";
+        assert_eq!(
+            messages(input),
+            vec![
+                "value Y of A is 128, which doesn't fit in i8",
+                "value Y of B is 1, which isn't greater than the value before it",
+                "value Z of B is -1, which isn't greater than the value before it",
+                "value Z of B is -1, which doesn't fit in u8",
+                "the underlying type of C must be an integer type",
+                "value V of U is 0, which doesn't fit in u8",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bit_flags() {
+        let input = "\
+enum A : ubyte (bit_flags) { X, Y, Z = 8 }
+enum B : byte (bit_flags) { X }
+enum C : ulong (bit_flags) { X = 63 }
+table T {
+  a: A = 3;
+  b: A = 4;
+  c: A = Y;
+  d: A = W;
+}";
+        assert_eq!(
+            messages(input),
+            vec![
+                "value Z of A is bit 8, which doesn't fit in u8",
+                "the underlying type of bit flags B must be an unsigned integer type",
+                "the default value of field b of T isn't a valid A",
+                "the default value of field d of T isn't a valid A",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_not_tables() {
+        let input = "\
+struct A { x: int; }
+table T { x: int; }
+struct B { t: T; s: string; v: [int]; a: A; f: [A:2]; g: [[int:2]:2]; }
+table C { f: [int:2]; }
+union U { A }
+rpc_service S { Get(A) : T; }
+root_type A;";
+        assert_eq!(
+            messages(input),
+            vec![
+                "field t of struct B must be a scalar, an enum or a struct",
+                "field s of struct B must be a scalar, an enum or a struct",
+                "field v of struct B must be a scalar, an enum or a struct",
+                "field g of struct B must be a scalar, an enum or a struct",
+                "field f of table C is a fixed-length array, which only structs can have",
+                "variant A of union U is not a table",
+                "A is used as a request or response type of S.Get, but isn't a table",
+                "root type A is not a table",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recursive_structs() {
+        let input = "\
+struct A { a: A; }
+struct B { x: int; c: [C:2]; }
+struct C { y: short; b: B; }
+struct D { a: A; b: B; }
+table T { a: A; }";
+        assert_eq!(
+            messages(input),
+            vec![
+                "field a of struct A contains A itself, so it would be infinitely large",
+                "field c of struct B contains B itself, so it would be infinitely large",
+                "field b of struct C contains C itself, so it would be infinitely large",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vector_of_unions() {
+        let input = "\
+table X { x: int; }
+union U { X }
+table T { u: U; us: [U]; xs: [X]; }";
+        assert_eq!(
+            messages(input),
+            vec!["field us of table T is a vector of unions, which isn't supported"]
+        );
+    }
+
+    #[test]
+    fn test_defaults() {
+        let input = "\
+enum Color : byte { Red = 1, Green }
+enum Level : byte { Low, High }
+struct S { x: int = 1; }
+table T {
+  a: int = 2;
+  b: int = null;
+  c: Level;
+  d: Level = 1;
+  e: string = 3;
+  f: ubyte = 256;
+  g: bool = 1.5;
+  h: Color;
+  i: Color = 2;
+  j: int (required);
+  k: string (required);
+  l: Color = Green;
+  m: Color = Blue;
+  n: int = Green;
+}";
+        assert_eq!(
+            messages(input),
+            vec![
+                "field x of S can't have a default value, only scalar and enum fields of tables can",
+                "field e of T can't have a default value, only scalar and enum fields of tables can",
+                "the default value of field f of T isn't a valid u8",
+                "the default value of field g of T isn't a valid bool",
+                "the default value of field h of T isn't a valid Color",
+                "field j of T is a scalar, so it can't be required",
+                "the default value of field m of T isn't a valid Color",
+                "the default value of field n of T isn't a valid i32",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_ids() {
+        let input = "\
+table T { a: int; }
+union U { T }
+table A { x: int (id: 1); y: int; }
+table B { x: int (id: -1); y: int (id: \"z\"); }
+table C { x: int (id: 0); u: U (id: 0); }
+table D { x: int (id: 1); u: U (id: 2); }
+table E { x: int (id: 0); y: int (id: 2); }
+table F { x: int (id: 2); u: U (id: 1); z: string (id: \"3\"); }";
+        assert_eq!(
+            messages(input),
+            vec![
+                "either all fields of A must have an id, or none of them",
+                "the id of field x of B must be a non-negative integer",
+                "the id of field y of B must be a non-negative integer",
+                "field u of C is a union, which also takes the id before its own, so its id can't be 0",
+                "id 1 of field u of D is already taken by another field",
+                "the ids of the fields of D must be contiguous from 0, but 0 is missing",
+                "the ids of the fields of E must be contiguous from 0, but 1 is missing",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keys() {
+        let input = "\
+struct S { x: int; }
+enum E : byte { A }
+table A { a: int (key); b: string (key); }
+table B { s: S (key); }
+table C { e: E (key); v: [int]; }
+table F { v: [int] (key); }
+table D { name: string (key); }";
+        assert_eq!(
+            messages(input),
+            vec![
+                "only one field of A can be its key",
+                "field s of B can't be a key, only scalars, enums and strings can",
+                "field v of F can't be a key, only scalars, enums and strings can",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_span() {
+        let input = "\
+namespace foo;
+table A { x: bar.B; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let symbols = SymbolTable::new(&schemas);
+        let errors = validate(&schemas, &symbols);
+        let span = errors[0].span(input).unwrap();
+        assert_eq!(&input[span.start..span.end], "bar.B");
+        assert_eq!(errors[0].span("bar.B"), None);
+
+        let input = "\
+struct A { x: int; a: A; }
+union U { T }
+table T { us: [U]; }";
+        let (_, schema) = schema_decl(input).unwrap();
+        let schemas = [schema];
+        let symbols = SymbolTable::new(&schemas);
+        let spans = validate(&schemas, &symbols)
+            .iter()
+            .map(|error| error.span(input).unwrap())
+            .map(|span| &input[span.start..span.end])
+            .collect::<Vec<_>>();
+        assert_eq!(spans, vec!["a", "us"]);
+    }
+}